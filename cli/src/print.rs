@@ -121,7 +121,14 @@ impl<'a, W: Write> Print<W> {
             Payload::Rollback => self.writeln("Rollback completed")?,
             Payload::StartTransaction => self.writeln("Transaction started")?,
             Payload::Insert(n) => affected(*n, Row, "inserted")?,
+            Payload::Upsert { inserted, updated } => {
+                let rows = |n: usize| format!("{n} {Row}{}", if n > 1 { "s" } else { "" });
+                let payload = format!("{} inserted, {} updated", rows(*inserted), rows(*updated));
+
+                self.writeln(payload)?
+            }
             Payload::Delete(n) => affected(*n, Row, "deleted")?,
+            Payload::Truncate => self.writeln("Table truncated")?,
             Payload::Update(n) => affected(*n, Row, "updated")?,
             Payload::ShowVariable(PayloadVariable::Version(v)) => self.writeln(format!("v{v}"))?,
             Payload::ShowVariable(PayloadVariable::Tables(names)) => {
@@ -400,7 +407,15 @@ mod tests {
         test!(Payload::Insert(0), "0 row inserted");
         test!(Payload::Insert(1), "1 row inserted");
         test!(Payload::Insert(7), "7 rows inserted");
+        test!(
+            Payload::Upsert {
+                inserted: 2,
+                updated: 1
+            },
+            "2 rows inserted, 1 row updated"
+        );
         test!(Payload::Delete(300), "300 rows deleted");
+        test!(Payload::Truncate, "Table truncated");
         test!(Payload::Update(123), "123 rows updated");
         test!(
             Payload::ShowVariable(PayloadVariable::Version("11.6.1989".to_owned())),
@@ -60,6 +60,10 @@ pub enum Statement {
         columns: Vec<String>,
         /// A SQL query that specifies what to insert
         source: Query,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
+        /// RETURNING, empty when omitted
+        returning: Vec<SelectItem>,
     },
     /// UPDATE
     Update {
@@ -69,6 +73,8 @@ pub enum Statement {
         assignments: Vec<Assignment>,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING, empty when omitted
+        returning: Vec<SelectItem>,
     },
     /// DELETE
     Delete {
@@ -76,6 +82,13 @@ pub enum Statement {
         table_name: String,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING, empty when omitted
+        returning: Vec<SelectItem>,
+    },
+    /// TRUNCATE TABLE
+    Truncate {
+        /// One or more tables to empty.
+        table_names: Vec<String>,
     },
     /// CREATE TABLE
     CreateTable {
@@ -87,6 +100,8 @@ pub enum Statement {
         source: Option<Box<Query>>,
         engine: Option<String>,
         foreign_keys: Vec<ForeignKey>,
+        /// Table constraint `PRIMARY KEY (a, b)` spanning more than one column
+        composite_primary_key: Option<Vec<String>>,
         comment: Option<String>,
     },
     /// CREATE FUNCTION
@@ -147,6 +162,26 @@ pub struct Assignment {
     pub value: Expr,
 }
 
+/// `ON CONFLICT` clause of `INSERT`, a conflict is a row whose primary key already exists
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OnConflict {
+    /// Conflict target columns, empty when omitted
+    pub target: Vec<String>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnConflictAction {
+    /// DO NOTHING
+    DoNothing,
+    /// DO UPDATE SET, `excluded` refers to the row proposed for insertion
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        /// WHERE
+        selection: Option<Expr>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
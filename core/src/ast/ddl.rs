@@ -6,8 +6,11 @@ use {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AlterTableOperation {
-    /// `ADD [ COLUMN ] <column_def>`
-    AddColumn { column_def: ColumnDef },
+    /// `ADD [ COLUMN ] [ IF NOT EXISTS ] <column_def>`
+    AddColumn {
+        column_def: ColumnDef,
+        if_not_exists: bool,
+    },
     /// `DROP [ COLUMN ] [ IF EXISTS ] <column_name> [ CASCADE ]`
     DropColumn {
         column_name: String,
@@ -50,9 +53,13 @@ pub struct OperateFunctionArg {
 impl ToSql for AlterTableOperation {
     fn to_sql(&self) -> String {
         match self {
-            AlterTableOperation::AddColumn { column_def } => {
-                format!("ADD COLUMN {}", column_def.to_sql())
-            }
+            AlterTableOperation::AddColumn {
+                column_def,
+                if_not_exists,
+            } => match if_not_exists {
+                true => format!("ADD COLUMN IF NOT EXISTS {}", column_def.to_sql()),
+                false => format!("ADD COLUMN {}", column_def.to_sql()),
+            },
             AlterTableOperation::DropColumn {
                 column_name,
                 if_exists,
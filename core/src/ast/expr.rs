@@ -3,6 +3,7 @@ use {
         Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, Query, ToSql,
         ToSqlUnquoted, UnaryOperator,
     },
+    crate::data::Value,
     serde::{Deserialize, Serialize},
 };
 
@@ -35,11 +36,14 @@ pub enum Expr {
         expr: Box<Expr>,
         negated: bool,
         pattern: Box<Expr>,
+        /// Character given by `ESCAPE`, backslash is used when omitted.
+        escape: Option<char>,
     },
     ILike {
         expr: Box<Expr>,
         negated: bool,
         pattern: Box<Expr>,
+        escape: Option<char>,
     },
     BinaryOp {
         left: Box<Expr>,
@@ -80,6 +84,10 @@ pub enum Expr {
     Array {
         elem: Vec<Expr>,
     },
+    /// `?` placeholder, bound by [`Glue::execute_with_params`](crate::prelude::Glue::execute_with_params)
+    Placeholder,
+    /// Value bound to a placeholder
+    Value(Box<Value>),
 }
 
 impl ToSql for Expr {
@@ -151,26 +159,30 @@ impl Expr {
                 expr,
                 negated,
                 pattern,
+                escape,
             } => {
                 let expr = expr.to_sql_with(quoted);
                 let pattern = pattern.to_sql_with(quoted);
+                let escape = escape_to_sql(*escape);
 
                 match negated {
-                    true => format!("{expr} NOT LIKE {pattern}"),
-                    false => format!("{expr} LIKE {pattern}"),
+                    true => format!("{expr} NOT LIKE {pattern}{escape}"),
+                    false => format!("{expr} LIKE {pattern}{escape}"),
                 }
             }
             Expr::ILike {
                 expr,
                 negated,
                 pattern,
+                escape,
             } => {
                 let expr = expr.to_sql_with(quoted);
                 let pattern = pattern.to_sql_with(quoted);
+                let escape = escape_to_sql(*escape);
 
                 match negated {
-                    true => format!("{expr} NOT ILIKE {pattern}"),
-                    false => format!("{expr} ILIKE {pattern}"),
+                    true => format!("{expr} NOT ILIKE {pattern}{escape}"),
+                    false => format!("{expr} ILIKE {pattern}{escape}"),
                 }
             }
             Expr::UnaryOp { op, expr } => match op {
@@ -267,10 +279,25 @@ impl Expr {
                     None => format!("INTERVAL {expr} {leading_field}"),
                 }
             }
+            Expr::Placeholder => "?".to_owned(),
+            Expr::Value(value) => match value.get_type() {
+                Some(data_type) => format!(
+                    "CAST('{}' AS {data_type})",
+                    String::from(value.as_ref()).replace('\'', "''")
+                ),
+                None => "NULL".to_owned(),
+            },
         }
     }
 }
 
+/// ` ESCAPE '...'` clause of LIKE and ILIKE, empty when the escape is omitted
+fn escape_to_sql(escape: Option<char>) -> String {
+    escape
+        .map(|escape| format!(" ESCAPE '{}'", escape.to_string().replace('\'', "''")))
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -279,6 +306,7 @@ mod tests {
             AstLiteral, BinaryOperator, DataType, DateTimeField, Expr, Query, Select, SelectItem,
             SetExpr, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, UnaryOperator,
         },
+        crate::data::Value,
         bigdecimal::BigDecimal,
         regex::Regex,
         std::str::FromStr,
@@ -375,6 +403,7 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 negated: false,
                 pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("%abc".to_owned()))),
+                escape: None,
             }
             .to_sql()
         );
@@ -385,6 +414,29 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 negated: true,
                 pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("%abc".to_owned()))),
+                escape: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""id" LIKE '100#%' ESCAPE '#'"#,
+            Expr::Like {
+                expr: Box::new(Expr::Identifier("id".to_owned())),
+                negated: false,
+                pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("100#%".to_owned()))),
+                escape: Some('#'),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""id" NOT ILIKE 'a''%' ESCAPE ''''"#,
+            Expr::ILike {
+                expr: Box::new(Expr::Identifier("id".to_owned())),
+                negated: true,
+                pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("a'%".to_owned()))),
+                escape: Some('\''),
             }
             .to_sql()
         );
@@ -395,6 +447,7 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 negated: false,
                 pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("%abc_".to_owned()))),
+                escape: None,
             }
             .to_sql()
         );
@@ -405,6 +458,7 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 negated: true,
                 pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("%abc_".to_owned()))),
+                escape: None,
             }
             .to_sql()
         );
@@ -453,6 +507,7 @@ mod tests {
                             },
                             joins: Vec::new(),
                         },
+                        without_from: false,
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
@@ -482,6 +537,7 @@ mod tests {
                             },
                             joins: Vec::new(),
                         },
+                        without_from: false,
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
@@ -510,6 +566,7 @@ mod tests {
                             },
                             joins: Vec::new(),
                         },
+                        without_from: false,
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
@@ -538,6 +595,7 @@ mod tests {
                             },
                             joins: Vec::new(),
                         },
+                        without_from: false,
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
@@ -565,6 +623,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
@@ -709,5 +768,16 @@ mod tests {
             }
             .to_sql()
         );
+
+        assert_eq!("?", Expr::Placeholder.to_sql());
+        assert_eq!(
+            "CAST('1' AS FLOAT)",
+            Expr::Value(Box::new(Value::F64(1.0))).to_sql()
+        );
+        assert_eq!(
+            "CAST('it''s' AS TEXT)",
+            Expr::Value(Box::new(Value::Str("it's".to_owned()))).to_sql()
+        );
+        assert_eq!("NULL", Expr::Value(Box::new(Value::Null)).to_sql());
     }
 }
@@ -66,7 +66,10 @@ pub enum Function {
         expr2: Expr,
     },
     Rand(Option<Expr>),
-    Round(Expr),
+    Round {
+        expr: Expr,
+        precision: Option<Expr>,
+    },
     Floor(Expr),
     Trim {
         expr: Expr,
@@ -301,7 +304,10 @@ impl ToSql for Function {
                 Some(v) => format!("RAND({})", v.to_sql()),
                 None => "RAND()".to_owned(),
             },
-            Function::Round(e) => format!("ROUND({})", e.to_sql()),
+            Function::Round { expr, precision } => match precision {
+                None => format!("ROUND({})", expr.to_sql()),
+                Some(precision) => format!("ROUND({}, {})", expr.to_sql(), precision.to_sql()),
+            },
             Function::Floor(e) => format!("FLOOR({})", e.to_sql()),
             Function::Trim {
                 expr,
@@ -845,9 +851,19 @@ mod tests {
 
         assert_eq!(
             r#"ROUND("num")"#,
-            &Expr::Function(Box::new(Function::Round(Expr::Identifier(
-                "num".to_owned()
-            ))))
+            &Expr::Function(Box::new(Function::Round {
+                expr: Expr::Identifier("num".to_owned()),
+                precision: None,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ROUND("num", 2)"#,
+            &Expr::Function(Box::new(Function::Round {
+                expr: Expr::Identifier("num".to_owned()),
+                precision: Some(Expr::Literal(AstLiteral::Number(BigDecimal::from(2)))),
+            }))
             .to_sql()
         );
 
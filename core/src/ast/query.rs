@@ -18,6 +18,22 @@ pub struct Query {
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    /// `left { UNION | INTERSECT | EXCEPT } [ALL] right`
+    SetOperation {
+        op: SetOperator,
+        /// `ALL` keeps duplicate rows
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -25,6 +41,8 @@ pub struct Select {
     pub distinct: bool,
     pub projection: Vec<SelectItem>,
     pub from: TableWithJoins,
+    /// A select without `FROM` reads a single row `SERIES(1)` named `Series` as its `from`
+    pub without_from: bool,
     /// WHERE
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
@@ -115,6 +133,8 @@ pub enum JoinExecutor {
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
+    RightOuter(JoinConstraint),
+    FullOuter(JoinConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -127,6 +147,8 @@ pub enum JoinConstraint {
 pub struct OrderByExpr {
     pub expr: Expr,
     pub asc: Option<bool>,
+    /// `NULLS FIRST` or `NULLS LAST`, NULLs sort as the largest value when omitted
+    pub nulls_first: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -212,6 +234,20 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                _,
+            ) => format!(
+                "{} {op}{} {}",
+                left.to_sql_with(quoted),
+                if *all { " ALL" } else { "" },
+                right.to_sql_with(quoted)
+            ),
         }
     }
 }
@@ -239,6 +275,7 @@ impl Select {
             distinct,
             projection,
             from,
+            without_from: _,
             selection,
             group_by,
             having,
@@ -446,6 +483,8 @@ impl Join {
         let (join_operator, join_constraint) = match join_operator {
             JoinOperator::Inner(join_constraint) => ("INNER JOIN", join_constraint),
             JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER JOIN", join_constraint),
+            JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER JOIN", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER JOIN", join_constraint),
         };
 
         let (join_constraint, join_executor) = match quoted {
@@ -544,16 +583,25 @@ impl ToSqlUnquoted for OrderByExpr {
 
 impl OrderByExpr {
     fn to_sql_with(&self, quoted: bool) -> String {
-        let OrderByExpr { expr, asc } = self;
+        let OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        } = self;
         let expr = match quoted {
             true => expr.to_sql(),
             false => expr.to_sql_unquoted(),
         };
-
-        match asc {
+        let expr = match asc {
             Some(true) => format!("{expr} ASC"),
             Some(false) => format!("{expr} DESC"),
             None => expr,
+        };
+
+        match nulls_first {
+            Some(true) => format!("{expr} NULLS FIRST"),
+            Some(false) => format!("{expr} NULLS LAST"),
+            None => expr,
         }
     }
 }
@@ -597,8 +645,8 @@ mod tests {
         crate::{
             ast::{
                 AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, SetOperator,
+                TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
             },
             parse_sql::parse_expr,
             translate::translate_expr,
@@ -618,6 +666,7 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual =
             r#"SELECT * FROM "FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
@@ -636,6 +685,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -657,6 +707,7 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual = "SELECT * FROM FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
@@ -674,6 +725,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -715,6 +767,7 @@ mod tests {
                     join_executor: JoinExecutor::NestedLoop,
                 }],
             },
+            without_from: false,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -737,6 +790,36 @@ mod tests {
         ]))
         .to_sql();
         assert_eq!(actual, expected);
+
+        let number = |n: &str| {
+            vec![vec![Expr::Literal(AstLiteral::Number(
+                BigDecimal::from_str(n).unwrap(),
+            ))]]
+        };
+        let actual = "VALUES (1) UNION ALL VALUES (2)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: Box::new(SetExpr::Values(Values(number("1")))),
+            right: Box::new(SetExpr::Values(Values(number("2")))),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "VALUES (1) INTERSECT VALUES (2) EXCEPT ALL VALUES (3)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: true,
+            left: Box::new(SetExpr::SetOperation {
+                op: SetOperator::Intersect,
+                all: false,
+                left: Box::new(SetExpr::Values(Values(number("1")))),
+                right: Box::new(SetExpr::Values(Values(number("2")))),
+            }),
+            right: Box::new(SetExpr::Values(Values(number("3")))),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -764,6 +847,7 @@ mod tests {
                     join_executor: JoinExecutor::NestedLoop,
                 }],
             },
+            without_from: false,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -800,6 +884,20 @@ mod tests {
         ]))
         .to_sql_unquoted();
         assert_eq!(actual, expected);
+
+        let actual = "VALUES ('glue') UNION VALUES ('sql')".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: false,
+            left: Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::QuotedString("glue".to_owned()),
+            )]]))),
+            right: Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::QuotedString("sql".to_owned()),
+            )]]))),
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -820,6 +918,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: None,
             group_by: vec![Expr::Identifier("name".to_owned())],
             having: Some(Expr::BinaryOp {
@@ -843,6 +942,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("name".to_owned())),
                 op: BinaryOperator::Eq,
@@ -872,6 +972,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: None,
             group_by: vec![Expr::Identifier("name".to_owned())],
             having: Some(Expr::BinaryOp {
@@ -895,6 +996,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("name".to_owned())),
                 op: BinaryOperator::Eq,
@@ -1005,6 +1107,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
@@ -1072,6 +1175,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
@@ -1213,6 +1317,34 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"RIGHT OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::RightOuter(JoinConstraint::On(expr(
+                r#""PlayerItem"."user_id" = "Player"."id""#,
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"FULL OUTER JOIN "PlayerItem""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::None),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -1307,6 +1439,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1315,6 +1448,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1323,6 +1457,25 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""foo" DESC NULLS LAST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: Some(false),
+            nulls_first: Some(false),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""foo" NULLS FIRST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: None,
+            nulls_first: Some(true),
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1334,6 +1487,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1342,6 +1496,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1350,6 +1505,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
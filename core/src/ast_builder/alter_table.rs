@@ -21,6 +21,15 @@ impl AlterTableNode {
         AddColumnNode {
             table_node: self,
             column_def: column.into(),
+            if_not_exists: false,
+        }
+    }
+
+    pub fn add_column_if_not_exists<T: Into<ColumnDefNode>>(self, column: T) -> AddColumnNode {
+        AddColumnNode {
+            table_node: self,
+            column_def: column.into(),
+            if_not_exists: true,
         }
     }
 
@@ -59,6 +68,7 @@ impl AlterTableNode {
 pub struct AddColumnNode {
     table_node: AlterTableNode,
     column_def: ColumnDefNode,
+    if_not_exists: bool,
 }
 
 impl Build for AddColumnNode {
@@ -66,6 +76,7 @@ impl Build for AddColumnNode {
         let table_name = self.table_node.table_name;
         let operation = AlterTableOperation::AddColumn {
             column_def: self.column_def.try_into()?,
+            if_not_exists: self.if_not_exists,
         };
         Ok(Statement::AlterTable {
             name: table_name,
@@ -145,6 +156,13 @@ mod tests {
         let expected = "ALTER TABLE Foo ADD COLUMN opt BOOLEAN NULL";
         test(actual, expected);
 
+        let actual = table("Foo")
+            .alter_table()
+            .add_column_if_not_exists("opt BOOLEAN NULL")
+            .build();
+        let expected = "ALTER TABLE Foo ADD COLUMN IF NOT EXISTS opt BOOLEAN NULL";
+        test(actual, expected);
+
         let actual = table("Foo").alter_table().drop_column("col_name").build();
         let expected = "ALTER TABLE Foo DROP COLUMN col_name";
         test(actual, expected);
@@ -155,6 +155,7 @@ impl<'a> TryFrom<ExprNode<'a>> for Expr {
                     expr,
                     negated,
                     pattern,
+                    escape: None,
                 })
             }
             ExprNode::ILike {
@@ -169,6 +170,7 @@ impl<'a> TryFrom<ExprNode<'a>> for Expr {
                     expr,
                     negated,
                     pattern,
+                    escape: None,
                 })
             }
             ExprNode::BinaryOp { left, op, right } => {
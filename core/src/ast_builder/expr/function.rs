@@ -18,7 +18,10 @@ pub enum FunctionNode<'a> {
     },
     Ceil(ExprNode<'a>),
     Rand(Option<ExprNode<'a>>),
-    Round(ExprNode<'a>),
+    Round {
+        expr: ExprNode<'a>,
+        precision: Option<ExprNode<'a>>,
+    },
     Floor(ExprNode<'a>),
     Asin(ExprNode<'a>),
     Acos(ExprNode<'a>),
@@ -200,7 +203,11 @@ impl<'a> TryFrom<FunctionNode<'a>> for Function {
             FunctionNode::Rand(expr_node) => Ok(Function::Rand(
                 expr_node.map(TryInto::try_into).transpose()?,
             )),
-            FunctionNode::Round(expr_node) => expr_node.try_into().map(Function::Round),
+            FunctionNode::Round { expr, precision } => {
+                let expr = expr.try_into()?;
+                let precision = precision.map(TryInto::try_into).transpose()?;
+                Ok(Function::Round { expr, precision })
+            }
             FunctionNode::Floor(expr_node) => expr_node.try_into().map(Function::Floor),
             FunctionNode::Asin(expr_node) => expr_node.try_into().map(Function::Asin),
             FunctionNode::Acos(expr_node) => expr_node.try_into().map(Function::Acos),
@@ -619,7 +626,10 @@ pub fn rand(expr: Option<ExprNode>) -> ExprNode {
     ExprNode::Function(Box::new(FunctionNode::Rand(expr)))
 }
 pub fn round<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Function(Box::new(FunctionNode::Round(expr.into())))
+    ExprNode::Function(Box::new(FunctionNode::Round {
+        expr: expr.into(),
+        precision: None,
+    }))
 }
 pub fn coalesce<'a, T: Into<ExprList<'a>>>(expr: T) -> ExprNode<'a> {
     ExprNode::Function(Box::new(FunctionNode::Coalesce(expr.into())))
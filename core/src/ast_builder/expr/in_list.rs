@@ -162,6 +162,7 @@ mod test {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -184,6 +184,7 @@ mod test {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -227,6 +227,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: Some(expr("PlayerItem.amount > 10").try_into().unwrap()),
                 group_by: Vec::new(),
                 having: None,
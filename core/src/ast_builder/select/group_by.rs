@@ -213,6 +213,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: vec![col("PlayerItem.category").try_into().unwrap()],
                 having: None,
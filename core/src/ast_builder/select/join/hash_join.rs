@@ -186,6 +186,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -236,6 +237,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -285,6 +287,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -308,6 +311,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
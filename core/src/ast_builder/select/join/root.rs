@@ -600,6 +600,7 @@ mod tests {
                     },
                     joins: vec![join, other_join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -264,6 +264,7 @@ mod tests {
                     },
                     joins: vec![join],
                 },
+                without_from: false,
                 selection: None,
                 group_by: Vec::new(),
                 having: None,
@@ -18,6 +18,7 @@ use {
 pub struct SelectNode<'a> {
     table_node: TableFactorNode<'a>,
     distinct: bool,
+    without_from: bool,
 }
 
 impl<'a> SelectNode<'a> {
@@ -25,6 +26,7 @@ impl<'a> SelectNode<'a> {
         Self {
             table_node,
             distinct: false,
+            without_from: false,
         }
     }
 
@@ -132,6 +134,7 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
             distinct: self.distinct,
             projection: vec![SelectItem::Wildcard],
             from,
+            without_from: self.without_from,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -142,6 +145,7 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
 pub fn select<'a>() -> SelectNode<'a> {
     SelectNode {
         distinct: false,
+        without_from: true,
         table_node: TableFactorNode {
             table_name: "Series".to_owned(),
             table_type: TableType::Series(Expr::Literal(AstLiteral::Number(1.into())).into()),
@@ -20,7 +20,7 @@ pub use {
     point::Point,
     row::{Row, RowError},
     schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
-    string_ext::{StringExt, StringExtError},
+    string_ext::{LikePattern, StringExt, StringExtError},
     table::{TableError, get_alias, get_index},
     value::{BTreeMapJsonExt, ConvertError, NumericBinaryOperator, Value, ValueError},
 };
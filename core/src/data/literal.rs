@@ -257,9 +257,15 @@ impl<'a> Literal<'a> {
         }
     }
 
-    pub fn like(&self, other: &Literal<'a>, case_sensitive: bool) -> Result<Self> {
+    pub fn like(
+        &self,
+        other: &Literal<'a>,
+        escape: Option<char>,
+        case_sensitive: bool,
+    ) -> Result<Self> {
         match (self, other) {
-            (Text(l), Text(r)) => l.like(r, case_sensitive).map(Boolean),
+            (Text(l), Text(r)) => l.like(r, escape, case_sensitive).map(Boolean),
+            (Null, _) | (_, Null) => Ok(Null),
             _ => Err(LiteralError::LikeOnNonString {
                 base: format!("{self:?}"),
                 pattern: format!("{other:?}"),
@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::{ColumnDef, Expr, ForeignKey, OrderByExpr, Statement, ToSql},
+        ast::{ColumnDef, Expr, ForeignKey, Function, OrderByExpr, Statement, ToSql},
         prelude::{parse, translate},
         result::Result,
     },
@@ -27,6 +27,34 @@ pub struct SchemaIndex {
     pub created: NaiveDateTime,
 }
 
+impl SchemaIndex {
+    /// Renames the column the index expression refers to, used by `ALTER TABLE RENAME COLUMN`
+    pub fn rename_column(&mut self, old_column_name: &str, new_column_name: &str) {
+        fn rename(expr: &mut Expr, old_column_name: &str, new_column_name: &str) {
+            match expr {
+                Expr::Identifier(ident) if ident == old_column_name => {
+                    new_column_name.clone_into(ident);
+                }
+                Expr::Nested(expr) | Expr::UnaryOp { expr, .. } => {
+                    rename(expr, old_column_name, new_column_name);
+                }
+                Expr::BinaryOp { left, right, .. } => {
+                    rename(left, old_column_name, new_column_name);
+                    rename(right, old_column_name, new_column_name);
+                }
+                Expr::Function(func) => {
+                    if let Function::Cast { expr, .. } = func.as_mut() {
+                        rename(expr, old_column_name, new_column_name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        rename(&mut self.expr, old_column_name, new_column_name);
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Schema {
     pub table_name: String,
@@ -34,6 +62,8 @@ pub struct Schema {
     pub indexes: Vec<SchemaIndex>,
     pub engine: Option<String>,
     pub foreign_keys: Vec<ForeignKey>,
+    /// Primary key over several columns, a single column primary key is kept in its `ColumnDef`
+    pub composite_primary_key: Option<Vec<String>>,
     pub comment: Option<String>,
 }
 
@@ -45,14 +75,25 @@ impl Schema {
             indexes,
             engine,
             foreign_keys,
+            composite_primary_key,
             comment,
         } = self;
 
         let columns = column_defs.as_ref().map(|column_defs| {
+            let primary_key = composite_primary_key.as_ref().map(|column_names| {
+                let column_names = column_names
+                    .iter()
+                    .map(|column_name| format!(r#""{column_name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("PRIMARY KEY ({column_names})")
+            });
             let foreign_keys = foreign_keys.iter().map(ToSql::to_sql);
             let body = column_defs
                 .iter()
                 .map(ToSql::to_sql)
+                .chain(primary_key)
                 .chain(foreign_keys)
                 .collect::<Vec<_>>()
                 .join(", ");
@@ -100,7 +141,7 @@ impl Schema {
                 match create_index {
                     Statement::CreateIndex {
                         name,
-                        column: OrderByExpr { expr, asc },
+                        column: OrderByExpr { expr, asc, .. },
                         ..
                     } => {
                         let order = asc
@@ -130,6 +171,7 @@ impl Schema {
                 columns,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
                 ..
             } => Ok(Schema {
@@ -138,6 +180,7 @@ impl Schema {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
@@ -170,6 +213,7 @@ mod tests {
             indexes,
             engine,
             foreign_keys,
+            composite_primary_key,
             comment,
         } = actual;
 
@@ -179,6 +223,7 @@ mod tests {
             indexes: indexes_e,
             engine: engine_e,
             foreign_keys: foreign_keys_e,
+            composite_primary_key: composite_primary_key_e,
             comment: comment_e,
         } = expected;
 
@@ -186,6 +231,7 @@ mod tests {
         assert_eq!(column_defs, column_defs_e);
         assert_eq!(engine, engine_e);
         assert_eq!(foreign_keys, foreign_keys_e);
+        assert_eq!(composite_primary_key, composite_primary_key_e);
         assert_eq!(comment, comment_e);
         indexes
             .into_iter()
@@ -234,6 +280,7 @@ mod tests {
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         };
 
@@ -249,6 +296,7 @@ mod tests {
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         };
         let ddl = r#"CREATE TABLE "Test";"#;
@@ -273,6 +321,7 @@ mod tests {
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         };
 
@@ -283,6 +332,47 @@ mod tests {
         assert_schema(actual, schema);
     }
 
+    #[test]
+    fn table_composite_primary() {
+        let column_def = |name: &str| ColumnDef {
+            name: name.to_owned(),
+            data_type: DataType::Int,
+            nullable: false,
+            default: None,
+            unique: None,
+            comment: None,
+        };
+        let schema = Schema {
+            table_name: "Enrollment".to_owned(),
+            column_defs: Some(vec![column_def("student_id"), column_def("course_id")]),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            composite_primary_key: Some(vec!["student_id".to_owned(), "course_id".to_owned()]),
+            comment: None,
+        };
+
+        let ddl = r#"CREATE TABLE "Enrollment" ("student_id" INT NOT NULL, "course_id" INT NOT NULL, PRIMARY KEY ("student_id", "course_id"));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+
+        let ddl = r#"CREATE TABLE "Enrollment" ("student_id" INT, "course_id" INT NOT NULL, PRIMARY KEY ("student_id", "course_id"));"#;
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_eq!(
+            actual.composite_primary_key,
+            Some(vec!["student_id".to_owned(), "course_id".to_owned()])
+        );
+        assert!(
+            actual
+                .column_defs
+                .unwrap()
+                .iter()
+                .all(|column_def| !column_def.nullable)
+        );
+    }
+
     #[test]
     fn invalid_ddl() {
         // Only Statement::CreateTable is supported
@@ -329,6 +419,7 @@ mod tests {
             ],
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
@@ -375,6 +466,7 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
             }],
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
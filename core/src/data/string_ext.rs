@@ -1,34 +1,259 @@
-use {crate::result::Result, regex::Regex, serde::Serialize, thiserror::Error};
+use {crate::result::Result, serde::Serialize, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum StringExtError {
-    #[error("unreachable literal unary operation")]
-    UnreachablePatternParsing,
+    #[error("LIKE pattern must not end with escape character: {0}")]
+    PatternEndsWithEscape(String),
 }
 
 pub trait StringExt {
-    fn like(&self, pattern: &str, case_sensitive: bool) -> Result<bool>;
+    /// Matches the string against a LIKE `pattern`, `escape` replaces the default backslash.
+    fn like(&self, pattern: &str, escape: Option<char>, case_sensitive: bool) -> Result<bool>;
 }
 
+/// Default character which makes the following `%`, `_` or itself match literally in a LIKE
+/// pattern, `ESCAPE` replaces it.
+const ESCAPE: char = '\\';
+
 impl StringExt for str {
-    fn like(&self, pattern: &str, case_sensitive: bool) -> Result<bool> {
-        let (match_string, match_pattern) = match case_sensitive {
-            true => (self.to_owned(), pattern.to_owned()),
-            false => {
-                let lowercase_string = self.to_lowercase();
-                let lowercase_pattern = pattern.to_lowercase();
-
-                (lowercase_string, lowercase_pattern)
+    fn like(&self, pattern: &str, escape: Option<char>, case_sensitive: bool) -> Result<bool> {
+        LikePattern::new(pattern, escape, case_sensitive).map(|pattern| pattern.matches(self))
+    }
+}
+
+/// Tokenized LIKE or ILIKE pattern, which matches strings without being tokenized again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LikePattern {
+    tokens: Vec<Token>,
+    case_sensitive: bool,
+}
+
+impl LikePattern {
+    pub fn new(pattern: &str, escape: Option<char>, case_sensitive: bool) -> Result<Self> {
+        let tokens = tokenize(pattern, escape.unwrap_or(ESCAPE), case_sensitive)?;
+
+        Ok(Self {
+            tokens,
+            case_sensitive,
+        })
+    }
+
+    pub fn matches(&self, string: &str) -> bool {
+        let string: Vec<char> = match self.case_sensitive {
+            true => string.chars().collect(),
+            false => string.chars().map(fold_case).collect(),
+        };
+
+        like(&string, &self.tokens)
+    }
+}
+
+/// Folds the case of a single character for ILIKE.
+///
+/// Unlike `str::to_lowercase`, each character is folded on its own so the final sigma `ς`
+/// matches `σ`, and characters whose lowercase form takes more than one character are
+/// kept as they are so that `_` still matches exactly one character of the input.
+fn fold_case(c: char) -> char {
+    let mut lowercase = c.to_lowercase();
+
+    match (lowercase.next(), lowercase.next()) {
+        (Some('ς'), None) => 'σ',
+        (Some(lower), None) => lower,
+        _ => c,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Token {
+    /// `%`, any sequence of characters
+    Any,
+    /// `_`, exactly one character
+    One,
+    Char(char),
+}
+
+/// The escape character is compared with the pattern as written, only the literal characters
+/// are folded for ILIKE.
+fn tokenize(pattern: &str, escape: char, case_sensitive: bool) -> Result<Vec<Token>> {
+    let fold = |c| match case_sensitive {
+        true => c,
+        false => fold_case(c),
+    };
+    let mut chars = pattern.chars();
+    let mut tokens = Vec::new();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c == escape => chars
+                .next()
+                .map(|c| Token::Char(fold(c)))
+                .ok_or_else(|| StringExtError::PatternEndsWithEscape(pattern.to_owned()))?,
+            '%' => Token::Any,
+            '_' => Token::One,
+            c => Token::Char(fold(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Matches `string` against a tokenized LIKE `pattern` without building a regex for every row.
+///
+/// On a mismatch the matcher backtracks to the most recent `%` and lets it absorb one
+/// more character, which is enough because any earlier `%` could only absorb less.
+fn like(string: &[char], pattern: &[Token]) -> bool {
+    let (mut s, mut p) = (0, 0);
+    let mut backtrack = None;
+
+    while s < string.len() {
+        match pattern.get(p) {
+            Some(Token::Any) => {
+                backtrack = Some((p, s));
+                p += 1;
             }
+            Some(Token::One) => {
+                s += 1;
+                p += 1;
+            }
+            Some(Token::Char(c)) if *c == string[s] => {
+                s += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((percent, absorbed)) => {
+                    backtrack = Some((percent, absorbed + 1));
+                    p = percent + 1;
+                    s = absorbed + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|token| *token == Token::Any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LikePattern, StringExt, StringExtError};
+
+    #[test]
+    fn like() {
+        let test = |string: &str, pattern: &str, expected: bool| {
+            assert_eq!(
+                string.like(pattern, None, true),
+                Ok(expected),
+                "{string} LIKE {pattern}"
+            );
+        };
+
+        test("abc", "abc", true);
+        test("abc", "ab", false);
+        test("", "", true);
+        test("", "%", true);
+        test("", "_", false);
+        test("abc", "%", true);
+        test("abc", "a%", true);
+        test("abc", "%c", true);
+        test("abc", "%b%", true);
+        test("abc", "_b_", true);
+        test("abc", "__", false);
+        test("abc", "a%%c", true);
+        test("aXbXc", "%X%X%", true);
+        test("aXbc", "%X%X%", false);
+        test("mississippi", "%iss%ppi", true);
+        test("mississippi", "m%ss_p%", true);
+        test("a.c", "a.c", true);
+        test("abc", "a.c", false);
+        test("aaa", "a*", false);
+        test("a*", "a*", true);
+        test("a\nb", "a_b", true);
+        test("日本語", "_本%", true);
+
+        // patterns are anchored at both ends
+        test("abc", "b", false);
+        test("abc", "a", false);
+        test("abc", "c", false);
+        test("abc", "b%", false);
+        test("abc", "%b", false);
+        test("abc", "%b%", true);
+
+        // escaped wildcards and escape characters match literally
+        test("100%", "100\\%", true);
+        test("1000", "100\\%", false);
+        test("100% sure", "100\\%%", true);
+        test("a_c", "a\\_c", true);
+        test("abc", "a\\_c", false);
+        test("a\\c", "a\\\\c", true);
+        test("abc", "\\a\\b\\c", true);
+
+        assert_eq!(
+            "abc".like("abc\\", None, true),
+            Err(StringExtError::PatternEndsWithEscape("abc\\".to_owned()).into())
+        );
+
+        // ESCAPE replaces the backslash, which then matches literally
+        let escaped =
+            |string: &str, pattern: &str, escape: char| string.like(pattern, Some(escape), true);
+        assert_eq!(escaped("100%", "100#%", '#'), Ok(true));
+        assert_eq!(escaped("1000", "100#%", '#'), Ok(false));
+        assert_eq!(escaped("a_c", "a#_c", '#'), Ok(true));
+        assert_eq!(escaped("a#c", "a##c", '#'), Ok(true));
+        assert_eq!(escaped("a\\c", "a\\c", '#'), Ok(true));
+        assert_eq!(escaped("a\\bc", "a\\_c", '#'), Ok(true));
+        assert_eq!(
+            escaped("100", "100#", '#'),
+            Err(StringExtError::PatternEndsWithEscape("100#".to_owned()).into())
+        );
+
+        assert_eq!("ABC".like("a%", None, true), Ok(false));
+        assert_eq!("ABC".like("a%", None, false), Ok(true));
+        assert_eq!("abc".like("_B_", None, false), Ok(true));
+    }
+
+    #[test]
+    fn ilike() {
+        let test = |string: &str, pattern: &str, expected: bool| {
+            assert_eq!(
+                string.like(pattern, None, false),
+                Ok(expected),
+                "{string} ILIKE {pattern}"
+            );
         };
 
-        Ok(Regex::new(&format!(
-            "^{}$",
-            regex::escape(match_pattern.as_str())
-                .replace('%', ".*")
-                .replace('_', ".")
-        ))
-        .map_err(|_| StringExtError::UnreachablePatternParsing)?
-        .is_match(match_string.as_str()))
+        test("John Smith", "%smith%", true);
+        test("JOHN SMITH", "%Smith", true);
+        test("Smithson", "%smith", false);
+        test("ÄRGER", "ärger", true);
+        test("Straße", "STRA_E", true);
+        test("ΟΔΟΣ", "οδος", true);
+        test("οδος", "ΟΔΟ_", true);
+        test("ΣΊΣΥΦΟΣ", "σίσυφος", true);
+        test("İstanbul", "_stanbul", true);
+        test("ǅemal", "ǆ%", true);
+        test("ABC", "a.c", false);
+
+        // the escape is not case folded, only the escaped character is
+        assert_eq!("A_C".like("ax_c", Some('x'), false), Ok(true));
+        assert_eq!("A_C".like("aX_C", Some('x'), false), Ok(false));
+        assert_eq!("AXBC".like("ax_c", Some('X'), false), Ok(true));
+        assert_eq!("ABC".like("ax_c", Some('X'), false), Ok(false));
+    }
+
+    #[test]
+    fn like_pattern() {
+        let pattern = LikePattern::new("A%", None, true).unwrap();
+        assert!(pattern.matches("Ab"));
+        assert!(!pattern.matches("ab"));
+
+        let pattern = LikePattern::new("A%", None, false).unwrap();
+        assert!(pattern.matches("ab"));
+
+        assert_eq!(
+            LikePattern::new("abc\\", None, true),
+            Err(StringExtError::PatternEndsWithEscape("abc\\".to_owned()).into())
+        );
     }
 }
@@ -766,11 +766,12 @@ impl Value {
         }
     }
 
-    pub fn like(&self, other: &Value, case_sensitive: bool) -> Result<Value> {
+    pub fn like(&self, other: &Value, escape: Option<char>, case_sensitive: bool) -> Result<Value> {
         use Value::*;
 
         match (self, other) {
-            (Str(a), Str(b)) => a.like(b, case_sensitive).map(Bool),
+            (Str(a), Str(b)) => a.like(b, escape, case_sensitive).map(Bool),
+            (Null, _) | (_, Null) => Ok(Null),
             _ => Err(ValueError::LikeOnNonString {
                 base: self.clone(),
                 pattern: other.clone(),
@@ -1698,6 +1699,10 @@ mod tests {
             I8(5).divide(&I8(0)),
             Err(ValueError::DivisorShouldNotBeZero.into())
         );
+        assert_eq!(
+            I64(5).divide(&F64(0.0)),
+            Err(ValueError::DivisorShouldNotBeZero.into())
+        );
 
         test!(divide I8(6),    I8(2)    => I8(3));
         test!(divide I8(6),    I16(2)    => I8(3));
@@ -1820,6 +1825,10 @@ mod tests {
             I8(5).modulo(&I8(0)),
             Err(ValueError::DivisorShouldNotBeZero.into())
         );
+        assert_eq!(
+            I64(5).modulo(&F32(0.0)),
+            Err(ValueError::DivisorShouldNotBeZero.into())
+        );
 
         test!(modulo I64(6),    I8(4)    => I64(2));
         test!(modulo I64(6),    I16(4)    => I64(2));
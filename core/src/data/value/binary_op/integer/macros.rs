@@ -13,7 +13,7 @@ macro_rules! impl_interval_method {
 }
 
 macro_rules! impl_method {
-    ($lhs_variant: ident, $lhs_primitive: ident, $lhs: ident, $method: ident, $op: ident, $rhs: ident) => {{
+    ($lhs_variant: ident, $lhs_primitive: ident, $lhs: ident, $method: ident, $float_method: ident, $op: ident, $rhs: ident) => {{
         match *$rhs {
             I8(rhs) => $lhs
                 .$method($lhs_primitive::try_from($rhs)?)
@@ -115,26 +115,9 @@ macro_rules! impl_method {
                     }
                     .into()
                 }),
-            F32(rhs) => $lhs
-                .$method($lhs_primitive::try_from($rhs)?)
-                .ok_or_else(|| {
-                    ValueError::BinaryOperationOverflow {
-                        lhs: $lhs_variant($lhs),
-                        rhs: F32(rhs),
-                        operator: $op,
-                    }
-                    .into()
-                }),
-            F64(rhs) => $lhs
-                .$method($lhs_primitive::try_from($rhs)?)
-                .ok_or_else(|| {
-                    ValueError::BinaryOperationOverflow {
-                        lhs: $lhs_variant($lhs),
-                        rhs: F64(rhs),
-                        operator: $op,
-                    }
-                    .into()
-                }),
+            // an integer mixed with a float is computed as the float type
+            F32(_) => return ($lhs as f32).$float_method($rhs),
+            F64(_) => return ($lhs as f64).$float_method($rhs),
             Decimal(rhs) => $lhs
                 .$method($lhs_primitive::try_from($rhs)?)
                 .ok_or_else(|| {
@@ -176,27 +159,67 @@ macro_rules! impl_try_binary_op {
 
             fn try_add(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_add, Add, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_add,
+                    try_add,
+                    Add,
+                    rhs
+                )
             }
 
             fn try_subtract(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_sub, Subtract, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_sub,
+                    try_subtract,
+                    Subtract,
+                    rhs
+                )
             }
 
             fn try_multiply(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_mul, Multiply, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_mul,
+                    try_multiply,
+                    Multiply,
+                    rhs
+                )
             }
 
             fn try_divide(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_div, Divide, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_div,
+                    try_divide,
+                    Divide,
+                    rhs
+                )
             }
 
             fn try_modulo(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_rem, Modulo, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_rem,
+                    try_modulo,
+                    Modulo,
+                    rhs
+                )
             }
         }
     };
@@ -239,11 +262,11 @@ macro_rules! generate_binary_op_tests {
                 );
                 assert_eq!(
                     $primitive::MAX.try_add(&F32(1.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(1.0_f32), Add)
+                    Ok(F32($primitive::MAX as f32 + 1.0_f32))
                 );
                 assert_eq!(
                     $primitive::MAX.try_add(&F64(1.0)),
-                    overflow_err($variant($primitive::MAX), F64(1.0), Add)
+                    Ok(F64($primitive::MAX as f64 + 1.0))
                 );
                 assert_eq!(
                     $primitive::MAX.try_add(&I8(1)),
@@ -299,11 +322,11 @@ macro_rules! generate_binary_op_tests {
                 );
                 assert_eq!(
                     $primitive::MIN.try_subtract(&F32(1.0_f32)),
-                    overflow_err($variant($primitive::MIN), F32(1.0_f32), Subtract)
+                    Ok(F32($primitive::MIN as f32 - 1.0_f32))
                 );
                 assert_eq!(
                     $primitive::MIN.try_subtract(&F64(1.0)),
-                    overflow_err($variant($primitive::MIN), F64(1.0), Subtract)
+                    Ok(F64($primitive::MIN as f64 - 1.0))
                 );
                 assert_eq!(
                     $primitive::MIN.try_subtract(&I8(1)),
@@ -359,11 +382,11 @@ macro_rules! generate_binary_op_tests {
                 );
                 assert_eq!(
                     $primitive::MAX.try_multiply(&F32(2.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(2.0_f32), Multiply)
+                    Ok(F32($primitive::MAX as f32 * 2.0_f32))
                 );
                 assert_eq!(
                     $primitive::MAX.try_multiply(&F64(2.0)),
-                    overflow_err($variant($primitive::MAX), F64(2.0), Multiply)
+                    Ok(F64($primitive::MAX as f64 * 2.0))
                 );
                 assert_eq!(
                     $primitive::MAX.try_multiply(&I8(2)),
@@ -409,17 +432,19 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn div_overflow() {
+                // a zero divisor is rejected by `Value::divide` before reaching `try_divide`,
+                // which divides by a float zero as IEEE 754 does
                 assert_eq!(
                     $primitive::MAX.try_divide(&Decimal(Decimal::from(0))),
                     overflow_err($variant($primitive::MAX), Decimal(Decimal::from(0)), Divide)
                 );
                 assert_eq!(
                     $primitive::MAX.try_divide(&F32(0.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(0.0_f32), Divide)
+                    Ok(F32($primitive::MAX as f32 / 0.0_f32))
                 );
                 assert_eq!(
                     $primitive::MAX.try_divide(&F64(0.0)),
-                    overflow_err($variant($primitive::MAX), F64(0.0), Divide)
+                    Ok(F64($primitive::MAX as f64 / 0.0))
                 );
                 assert_eq!(
                     $primitive::MAX.try_divide(&I8(0)),
@@ -471,11 +496,11 @@ macro_rules! generate_binary_op_tests {
                 );
                 assert_eq!(
                     $primitive::MAX.try_modulo(&F32(0.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(0.0_f32), Modulo)
+                    Ok(F32($primitive::MAX as f32 % 0.0_f32))
                 );
                 assert_eq!(
                     $primitive::MAX.try_modulo(&F64(0.0)),
-                    overflow_err($variant($primitive::MAX), F64(0.0), Modulo)
+                    Ok(F64($primitive::MAX as f64 % 0.0))
                 );
                 assert_eq!(
                     $primitive::MAX.try_modulo(&I8(0)),
@@ -524,8 +549,9 @@ macro_rules! generate_binary_op_tests {
                 let base: $primitive = 1;
 
                 assert_eq!(base.try_add(&Decimal(Decimal::ONE)), Ok($variant(2)));
-                assert_eq!(base.try_add(&F32(1.0_f32)), Ok($variant(2)));
-                assert_eq!(base.try_add(&F64(1.0)), Ok($variant(2)));
+                assert_eq!(base.try_add(&F32(1.0_f32)), Ok(F32(2.0_f32)));
+                assert_eq!(base.try_add(&F64(1.0)), Ok(F64(2.0)));
+                assert_eq!(base.try_add(&F64(0.5)), Ok(F64(1.5)));
                 assert_eq!(base.try_add(&I8(1)), Ok($variant(2)));
                 assert_eq!(base.try_add(&I16(1)), Ok($variant(2)));
                 assert_eq!(base.try_add(&I32(1)), Ok($variant(2)));
@@ -553,8 +579,8 @@ macro_rules! generate_binary_op_tests {
                 let base: $primitive = 1;
 
                 assert_eq!(base.try_subtract(&Decimal(Decimal::ONE)), Ok($variant(0)));
-                assert_eq!(base.try_subtract(&F32(1.0_f32)), Ok($variant(0)));
-                assert_eq!(base.try_subtract(&F64(1.0)), Ok($variant(0)));
+                assert_eq!(base.try_subtract(&F32(1.0_f32)), Ok(F32(0.0_f32)));
+                assert_eq!(base.try_subtract(&F64(1.0)), Ok(F64(0.0)));
                 assert_eq!(base.try_subtract(&I8(1)), Ok($variant(0)));
                 assert_eq!(base.try_subtract(&I16(1)), Ok($variant(0)));
                 assert_eq!(base.try_subtract(&I32(1)), Ok($variant(0)));
@@ -582,8 +608,8 @@ macro_rules! generate_binary_op_tests {
                 let base: $primitive = 3;
 
                 assert_eq!(base.try_multiply(&Decimal(Decimal::TWO)), Ok($variant(6)));
-                assert_eq!(base.try_multiply(&F32(2.0_f32)), Ok($variant(6)));
-                assert_eq!(base.try_multiply(&F64(2.0)), Ok($variant(6)));
+                assert_eq!(base.try_multiply(&F32(2.0_f32)), Ok(F32(6.0_f32)));
+                assert_eq!(base.try_multiply(&F64(2.0)), Ok(F64(6.0)));
                 assert_eq!(base.try_multiply(&I8(2)), Ok($variant(6)));
                 assert_eq!(base.try_multiply(&I16(2)), Ok($variant(6)));
                 assert_eq!(base.try_multiply(&I32(2)), Ok($variant(6)));
@@ -611,8 +637,9 @@ macro_rules! generate_binary_op_tests {
                 let base: $primitive = 6;
 
                 assert_eq!(base.try_divide(&Decimal(Decimal::TWO)), Ok($variant(3)));
-                assert_eq!(base.try_divide(&F32(2.0_f32)), Ok($variant(3)));
-                assert_eq!(base.try_divide(&F64(2.0)), Ok($variant(3)));
+                assert_eq!(base.try_divide(&F32(2.0_f32)), Ok(F32(3.0_f32)));
+                assert_eq!(base.try_divide(&F64(2.0)), Ok(F64(3.0)));
+                assert_eq!(base.try_divide(&F64(4.0)), Ok(F64(1.5)));
                 assert_eq!(base.try_divide(&I8(2)), Ok($variant(3)));
                 assert_eq!(base.try_divide(&I16(2)), Ok($variant(3)));
                 assert_eq!(base.try_divide(&I32(2)), Ok($variant(3)));
@@ -640,8 +667,8 @@ macro_rules! generate_binary_op_tests {
                 let base: $primitive = 9;
 
                 assert_eq!(base.try_modulo(&Decimal(Decimal::ONE)), Ok($variant(0)));
-                assert_eq!(base.try_modulo(&F32(1.0_f32)), Ok($variant(0)));
-                assert_eq!(base.try_modulo(&F64(1.0)), Ok($variant(0)));
+                assert_eq!(base.try_modulo(&F32(1.0_f32)), Ok(F32(0.0_f32)));
+                assert_eq!(base.try_modulo(&F64(1.0)), Ok(F64(0.0)));
                 assert_eq!(base.try_modulo(&I8(1)), Ok($variant(0)));
                 assert_eq!(base.try_modulo(&I16(1)), Ok($variant(0)));
                 assert_eq!(base.try_modulo(&I32(1)), Ok($variant(0)));
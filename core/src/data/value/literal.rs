@@ -48,39 +48,61 @@ impl TryFrom<Literal<'_>> for Value {
     }
 }
 
+/// Integer values are compared with the exact value of number literals, so `1` is equal to
+/// `1.0` and less than `1.5` even though those cannot be converted to an integer.
+fn eq_integer_with_number<T>(l: T, r: Option<T>, number: &BigDecimal) -> bool
+where
+    T: PartialEq + Into<BigDecimal>,
+{
+    match r {
+        Some(r) => l == r,
+        None => l.into() == *number,
+    }
+}
+
+fn cmp_integer_with_number<T>(l: T, r: Option<T>, number: &BigDecimal) -> Ordering
+where
+    T: Ord + Into<BigDecimal>,
+{
+    match r {
+        Some(r) => l.cmp(&r),
+        None => l.into().cmp(number),
+    }
+}
+
 impl Value {
     pub fn evaluate_eq_with_literal(&self, other: &Literal<'_>) -> Tribool {
         match (self, other) {
             (Value::Bool(l), Literal::Boolean(r)) => Tribool::from(l == r),
             (Value::I8(l), Literal::Number(r)) => {
-                Tribool::from(r.to_i8().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_i8(), r))
             }
             (Value::I16(l), Literal::Number(r)) => {
-                Tribool::from(r.to_i16().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_i16(), r))
             }
             (Value::I32(l), Literal::Number(r)) => {
-                Tribool::from(r.to_i32().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_i32(), r))
             }
             (Value::I64(l), Literal::Number(r)) => {
-                Tribool::from(r.to_i64().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_i64(), r))
             }
             (Value::I128(l), Literal::Number(r)) => {
-                Tribool::from(r.to_i128().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_i128(), r))
             }
             (Value::U8(l), Literal::Number(r)) => {
-                Tribool::from(r.to_u8().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_u8(), r))
             }
             (Value::U16(l), Literal::Number(r)) => {
-                Tribool::from(r.to_u16().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_u16(), r))
             }
             (Value::U32(l), Literal::Number(r)) => {
-                Tribool::from(r.to_u32().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_u32(), r))
             }
             (Value::U64(l), Literal::Number(r)) => {
-                Tribool::from(r.to_u64().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_u64(), r))
             }
             (Value::U128(l), Literal::Number(r)) => {
-                Tribool::from(r.to_u128().map(|r| *l == r).unwrap_or(false))
+                Tribool::from(eq_integer_with_number(*l, r.to_u128(), r))
             }
             (Value::F32(l), Literal::Number(r)) => {
                 Tribool::from(r.to_f32().map(|r| *l == r).unwrap_or(false))
@@ -125,16 +147,20 @@ impl Value {
 
     pub fn evaluate_cmp_with_literal(&self, other: &Literal<'_>) -> Option<Ordering> {
         match (self, other) {
-            (Value::I8(l), Literal::Number(r)) => l.partial_cmp(&r.to_i8()?),
-            (Value::I16(l), Literal::Number(r)) => l.partial_cmp(&r.to_i16()?),
-            (Value::I32(l), Literal::Number(r)) => l.partial_cmp(&r.to_i32()?),
-            (Value::I64(l), Literal::Number(r)) => l.partial_cmp(&r.to_i64()?),
-            (Value::I128(l), Literal::Number(r)) => l.partial_cmp(&r.to_i128()?),
-            (Value::U8(l), Literal::Number(r)) => l.partial_cmp(&r.to_u8()?),
-            (Value::U16(l), Literal::Number(r)) => l.partial_cmp(&r.to_u16()?),
-            (Value::U32(l), Literal::Number(r)) => l.partial_cmp(&r.to_u32()?),
-            (Value::U64(l), Literal::Number(r)) => l.partial_cmp(&r.to_u64()?),
-            (Value::U128(l), Literal::Number(r)) => l.partial_cmp(&r.to_u128()?),
+            (Value::I8(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_i8(), r)),
+            (Value::I16(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_i16(), r)),
+            (Value::I32(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_i32(), r)),
+            (Value::I64(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_i64(), r)),
+            (Value::I128(l), Literal::Number(r)) => {
+                Some(cmp_integer_with_number(*l, r.to_i128(), r))
+            }
+            (Value::U8(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_u8(), r)),
+            (Value::U16(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_u16(), r)),
+            (Value::U32(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_u32(), r)),
+            (Value::U64(l), Literal::Number(r)) => Some(cmp_integer_with_number(*l, r.to_u64(), r)),
+            (Value::U128(l), Literal::Number(r)) => {
+                Some(cmp_integer_with_number(*l, r.to_u128(), r))
+            }
             (Value::F32(l), Literal::Number(r)) => l.partial_cmp(&r.to_f32()?),
             (Value::F64(l), Literal::Number(r)) => l.partial_cmp(&r.to_f64()?),
             (Value::Decimal(l), Literal::Number(r)) => {
@@ -162,6 +188,25 @@ impl Value {
     pub fn try_from_literal(data_type: &DataType, literal: &Literal<'_>) -> Result<Value> {
         match (data_type, literal) {
             (DataType::Boolean, Literal::Boolean(v)) => Ok(Value::Bool(*v)),
+            (
+                DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int
+                | DataType::Int128
+                | DataType::Uint8
+                | DataType::Uint16
+                | DataType::Uint32
+                | DataType::Uint64
+                | DataType::Uint128,
+                Literal::Number(v),
+            ) if !v.is_integer_representation() => {
+                Err(ValueError::IncompatibleLiteralForDataType {
+                    data_type: data_type.clone(),
+                    literal: format!("{literal:?}"),
+                }
+                .into())
+            }
             (DataType::Int8, Literal::Number(v)) => v
                 .to_i8()
                 .map(Value::I8)
@@ -580,6 +625,10 @@ mod tests {
         assert_eq!(True, Value::U32(64).evaluate_eq_with_literal(num!("64")));
         assert_eq!(True, Value::U64(64).evaluate_eq_with_literal(num!("64")));
         assert_eq!(True, Value::U128(64).evaluate_eq_with_literal(num!("64")));
+        assert_eq!(True, Value::I64(3).evaluate_eq_with_literal(num!("3.0")));
+        assert_eq!(False, Value::I64(3).evaluate_eq_with_literal(num!("3.5")));
+        assert_eq!(False, Value::I8(8).evaluate_eq_with_literal(num!("1000")));
+        assert_eq!(False, Value::U8(0).evaluate_eq_with_literal(num!("-1")));
         assert_eq!(
             True,
             Value::F32(7.123).evaluate_eq_with_literal(num!("7.123"))
@@ -672,6 +721,13 @@ mod tests {
         test(Value::U32(10), num(3), Some(Ordering::Greater));
         test(Value::U64(10), num(10), Some(Ordering::Equal));
         test(Value::U128(10), num(10), Some(Ordering::Equal));
+
+        let decimal = |v: &str| Literal::Number(Cow::Owned(BigDecimal::from_str(v).unwrap()));
+        test(Value::I64(2), decimal("2.0"), Some(Ordering::Equal));
+        test(Value::I64(2), decimal("2.5"), Some(Ordering::Less));
+        test(Value::I32(-2), decimal("-2.5"), Some(Ordering::Greater));
+        test(Value::I8(1), num(1000), Some(Ordering::Less));
+        test(Value::U8(0), num(-1), Some(Ordering::Greater));
         test(Value::F32(10.0), num(10), Some(Ordering::Equal));
         test(Value::F64(10.0), num(10), Some(Ordering::Equal));
         test(
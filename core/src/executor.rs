@@ -9,6 +9,7 @@ mod filter;
 mod insert;
 mod join;
 mod limit;
+mod returning;
 mod select;
 mod sort;
 mod update;
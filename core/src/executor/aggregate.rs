@@ -8,8 +8,8 @@ use {
         filter::check_expr,
     },
     crate::{
-        ast::{Expr, SelectItem},
-        data::Key,
+        ast::{Aggregate, AggregateFunction, Expr, SelectItem},
+        data::{Key, Row, Value},
         result::Result,
         store::GStore,
     },
@@ -17,7 +17,8 @@ use {
         future::BoxFuture,
         stream::{self, Stream, StreamExt, TryStreamExt},
     },
-    std::sync::Arc,
+    im::HashMap,
+    std::{borrow::Cow, iter::once, sync::Arc},
 };
 
 #[derive(futures_enum::Stream)]
@@ -26,8 +27,12 @@ enum S<T1, T2> {
     Aggregate(T2),
 }
 
-fn check_aggregate<'a>(fields: &'a [SelectItem], group_by: &'a [Expr]) -> bool {
-    if !group_by.is_empty() {
+fn check_aggregate<'a>(
+    fields: &'a [SelectItem],
+    group_by: &'a [Expr],
+    having: Option<&'a Expr>,
+) -> bool {
+    if !group_by.is_empty() || having.map(check).unwrap_or(false) {
         return true;
     }
 
@@ -45,7 +50,7 @@ pub async fn apply<'a, T: GStore, U: Stream<Item = Result<Arc<RowContext<'a>>>>
     filter_context: Option<Arc<RowContext<'a>>>,
     rows: U,
 ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>> + use<'a, T, U>> {
-    if !check_aggregate(fields, group_by) {
+    if !check_aggregate(fields, group_by, having) {
         let rows = rows.map_ok(|project_context| AggregateContext {
             aggregated: None,
             next: project_context,
@@ -99,13 +104,17 @@ pub async fn apply<'a, T: GStore, U: Stream<Item = Result<Arc<RowContext<'a>>>>
                         }
                     })
                     .await?;
+                let state = match having {
+                    Some(having) => aggregate(state, filter_context, having).await?,
+                    None => state,
+                };
 
                 Ok(state)
             }
         })
         .await?;
 
-    group_by_having(storage, filter_context, having, state)
+    group_by_having(storage, filter_context, fields, group_by, having, state)
         .await
         .map(S::Aggregate)
 }
@@ -113,12 +122,24 @@ pub async fn apply<'a, T: GStore, U: Stream<Item = Result<Arc<RowContext<'a>>>>
 async fn group_by_having<'a, T: GStore>(
     storage: &'a T,
     filter_context: Option<Arc<RowContext<'a>>>,
+    fields: &'a [SelectItem],
+    group_by: &'a [Expr],
     having: Option<&'a Expr>,
     state: State<'a, T>,
 ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>>> {
-    let rows = state
-        .export()
-        .await?
+    let mut rows = state.export().await?;
+
+    if rows.is_empty() && group_by.is_empty() {
+        let next = Row::Vec {
+            columns: Arc::from(Vec::new()),
+            values: Vec::new(),
+        };
+        let next = Arc::new(RowContext::new("", Cow::Owned(next), None));
+
+        rows.push((Some(empty_aggregated(fields, having)), Some(next)));
+    }
+
+    let rows = rows
         .into_iter()
         .filter_map(|(aggregated, next)| next.map(|next| (aggregated, next)));
     let rows = stream::iter(rows)
@@ -138,7 +159,7 @@ async fn group_by_having<'a, T: GStore>(
                         let filter_context = Some(filter_context);
                         let aggr_rc = aggregated.clone().map(Arc::new);
 
-                        check_expr(storage, filter_context, aggr_rc, having)
+                        check_expr(storage, filter_context, aggr_rc, None, having)
                             .await
                             .map(|pass| pass.then_some((aggregated, next)))
                             .transpose()
@@ -161,71 +182,103 @@ where
 {
     Box::pin(async move {
         match expr {
-            Expr::Between {
-                expr, low, high, ..
-            } => {
-                let state = aggregate(state, filter_context.clone(), expr).await?;
-                let state = aggregate(state, filter_context.clone(), low).await?;
-                aggregate(state, filter_context, high).await
-            }
-            Expr::BinaryOp { left, right, .. } => {
-                let state = aggregate(state, filter_context.clone(), left).await?;
-                aggregate(state, filter_context, right).await
+            Expr::Aggregate(aggr_expr) => {
+                state.accumulate(filter_context, aggr_expr.as_ref()).await
             }
-            Expr::UnaryOp { expr, .. } => aggregate(state, filter_context, expr).await,
-            Expr::Nested(expr) => aggregate(state, filter_context, expr).await,
-            Expr::Case {
-                operand,
-                when_then,
-                else_result,
-            } => {
-                let mut state = match operand.as_deref() {
-                    Some(op) => aggregate(state, filter_context.clone(), op).await?,
-                    None => state,
-                };
+            _ => {
+                let mut state = state;
 
-                for (when, then) in when_then {
-                    state = aggregate(state, filter_context.clone(), when).await?;
-                    state = aggregate(state, filter_context.clone(), then).await?;
-                }
-
-                if let Some(else_expr) = else_result.as_deref() {
-                    state = aggregate(state, filter_context.clone(), else_expr).await?;
+                for expr in sub_exprs(expr) {
+                    state = aggregate(state, filter_context.clone(), expr).await?;
                 }
 
                 Ok(state)
             }
-            Expr::Aggregate(aggr_expr) => {
-                state.accumulate(filter_context, aggr_expr.as_ref()).await
-            }
-            _ => Ok(state),
         }
     })
 }
 
+/// Aggregated values of a query without `GROUP BY` which received no rows,
+/// `COUNT` becomes 0 and the others become NULL.
+fn empty_aggregated<'a>(
+    fields: &'a [SelectItem],
+    having: Option<&'a Expr>,
+) -> HashMap<&'a Aggregate, Value> {
+    fn collect<'a>(aggregated: &mut HashMap<&'a Aggregate, Value>, expr: &'a Expr) {
+        match expr {
+            Expr::Aggregate(aggr) => {
+                let value = match aggr.func {
+                    AggregateFunction::Count(_) => Value::I64(0),
+                    _ => Value::Null,
+                };
+
+                aggregated.insert(aggr.as_ref(), value);
+            }
+            _ => sub_exprs(expr)
+                .into_iter()
+                .for_each(|expr| collect(aggregated, expr)),
+        }
+    }
+
+    let mut aggregated = HashMap::new();
+    fields
+        .iter()
+        .filter_map(|field| match field {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            _ => None,
+        })
+        .chain(having)
+        .for_each(|expr| collect(&mut aggregated, expr));
+
+    aggregated
+}
+
 fn check(expr: &Expr) -> bool {
     match expr {
+        Expr::Aggregate(_) => true,
+        _ => sub_exprs(expr).into_iter().any(check),
+    }
+}
+
+/// Sub-expressions of `expr` which can hold an aggregate of this query,
+/// aggregates in a subquery belong to the subquery.
+pub(super) fn sub_exprs(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Placeholder
+        | Expr::Value(_)
+        | Expr::Aggregate(_)
+        | Expr::Subquery(_)
+        | Expr::Exists { .. } => Vec::new(),
+        Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Interval { expr, .. }
+        | Expr::InSubquery { expr, .. } => vec![expr],
+        Expr::BinaryOp { left, right, .. } => vec![left, right],
+        Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+            vec![expr, pattern]
+        }
         Expr::Between {
             expr, low, high, ..
-        } => check(expr) || check(low) || check(high),
-        Expr::BinaryOp { left, right, .. } => check(left) || check(right),
-        Expr::UnaryOp { expr, .. } => check(expr),
-        Expr::Nested(expr) => check(expr),
+        } => vec![expr, low, high],
+        Expr::InList { expr, list, .. } => once(expr.as_ref()).chain(list).collect(),
         Expr::Case {
             operand,
             when_then,
             else_result,
-        } => {
-            operand.as_ref().map(|expr| check(expr)).unwrap_or(false)
-                || when_then
-                    .iter()
-                    .any(|(when, then)| check(when) || check(then))
-                || else_result
-                    .as_ref()
-                    .map(|expr| check(expr))
-                    .unwrap_or(false)
-        }
-        Expr::Aggregate(_) => true,
-        _ => false,
+        } => operand
+            .as_deref()
+            .into_iter()
+            .chain(when_then.iter().flat_map(|(when, then)| [when, then]))
+            .chain(else_result.as_deref())
+            .collect(),
+        Expr::Function(function) => function.as_exprs().collect(),
+        Expr::ArrayIndex { obj, indexes } => once(obj.as_ref()).chain(indexes).collect(),
+        Expr::Array { elem } => elem.iter().collect(),
     }
 }
@@ -1,11 +1,13 @@
 use {
     super::{AlterError, Referencing, validate},
     crate::{
-        ast::{AlterTableOperation, Expr, Function},
+        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, Expr, Function},
         data::{Schema, SchemaIndex},
+        executor::{ValidateError, evaluate_stateless, validate::validate_not_null},
         result::Result,
         store::{GStore, GStoreMut},
     },
+    futures::stream::StreamExt,
 };
 
 pub async fn alter_table<T: GStore + GStoreMut>(
@@ -19,11 +21,29 @@ pub async fn alter_table<T: GStore + GStoreMut>(
     }
     | AlterTableOperation::DropColumn { column_name, .. } = operation
     {
-        if let Some(schema) = storage.fetch_schema(table_name).await? {
+        let schema = storage.fetch_schema(table_name).await?;
+
+        if let Some(schema) = &schema {
+            // a renamed column is renamed in the composite primary key as well
+            if matches!(operation, AlterTableOperation::DropColumn { .. })
+                && schema
+                    .composite_primary_key
+                    .iter()
+                    .flatten()
+                    .any(|primary_key_column| primary_key_column == column_name)
+            {
+                return Err(AlterError::CannotAlterPrimaryKeyColumn {
+                    table_name: table_name.to_owned(),
+                    column_name: column_name.to_owned(),
+                }
+                .into());
+            }
+
             let referencing_foreign_key = schema
                 .foreign_keys
-                .into_iter()
-                .find(|foreign_key| column_name == &foreign_key.referencing_column_name);
+                .iter()
+                .find(|foreign_key| column_name == &foreign_key.referencing_column_name)
+                .cloned();
 
             if let Some(foreign_key) = referencing_foreign_key {
                 return Err(AlterError::CannotAlterReferencingColumn {
@@ -46,12 +66,36 @@ pub async fn alter_table<T: GStore + GStoreMut>(
         if let Some(referencing) = referencing {
             return Err(AlterError::CannotAlterReferencedColumn { referencing }.into());
         }
+
+        // renaming is fine as a column-level primary key is kept in its ColumnDef
+        let dropping_primary_key = matches!(operation, AlterTableOperation::DropColumn { .. })
+            && schema
+                .iter()
+                .flat_map(|schema| schema.column_defs.iter().flatten())
+                .any(|column_def| {
+                    &column_def.name == column_name
+                        && column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+                });
+
+        if dropping_primary_key {
+            return Err(AlterError::CannotAlterPrimaryKeyColumn {
+                table_name: table_name.to_owned(),
+                column_name: column_name.to_owned(),
+            }
+            .into());
+        }
     }
 
     match operation {
         AlterTableOperation::RenameTable {
             table_name: new_table_name,
-        } => storage.rename_schema(table_name, new_table_name).await,
+        } => {
+            if storage.fetch_schema(new_table_name).await?.is_some() {
+                return Err(AlterError::TableAlreadyExists(new_table_name.to_owned()).into());
+            }
+
+            storage.rename_schema(table_name, new_table_name).await
+        }
         AlterTableOperation::RenameColumn {
             old_column_name,
             new_column_name,
@@ -60,9 +104,52 @@ pub async fn alter_table<T: GStore + GStoreMut>(
                 .rename_column(table_name, old_column_name, new_column_name)
                 .await
         }
-        AlterTableOperation::AddColumn { column_def } => {
+        AlterTableOperation::AddColumn {
+            column_def,
+            if_not_exists,
+        } => {
             validate(column_def).await?;
 
+            let schema = storage.fetch_schema(table_name).await?;
+            let column_exists = schema
+                .as_ref()
+                .and_then(|schema| schema.column_defs.as_ref())
+                .is_some_and(|column_defs| {
+                    column_defs
+                        .iter()
+                        .any(|ColumnDef { name, .. }| name == &column_def.name)
+                });
+
+            if *if_not_exists && column_exists {
+                return Ok(());
+            }
+
+            if let ColumnDef {
+                name,
+                data_type,
+                default: Some(default),
+                unique,
+                ..
+            } = column_def
+            {
+                // every existing row receives the same default value
+                let value = evaluate_stateless(None, default)
+                    .await?
+                    .try_into_value(data_type, true)?;
+                validate_not_null(table_name, column_def, &value)?;
+
+                let row_count = match unique {
+                    Some(_) => storage.scan_data(table_name).await?.take(2).count().await,
+                    None => 0,
+                };
+
+                if !value.is_null() && row_count > 1 {
+                    return Err(
+                        ValidateError::DuplicateEntryOnUniqueField(value, name.to_owned()).into(),
+                    );
+                }
+            }
+
             storage.add_column(table_name, column_def).await
         }
         AlterTableOperation::DropColumn {
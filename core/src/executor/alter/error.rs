@@ -43,6 +43,9 @@ pub enum AlterError {
     #[error("duplicate column name: {0}")]
     DuplicateColumnName(String),
 
+    #[error("duplicate table name: {0}")]
+    DuplicateTableName(String),
+
     #[error("duplicate arg name: {0}")]
     DuplicateArgName(String),
 
@@ -88,6 +91,15 @@ pub enum AlterError {
     #[error("cannot drop column '{}.{}' referencing with '{}'", referencing.table_name, referencing.foreign_key.referencing_column_name, referencing)]
     CannotAlterReferencingColumn { referencing: Referencing },
 
+    #[error("primary key column not found: {0}")]
+    PrimaryKeyColumnNotFound(String),
+
+    #[error("cannot alter column '{column_name}' of the primary key of '{table_name}'")]
+    CannotAlterPrimaryKeyColumn {
+        table_name: String,
+        column_name: String,
+    },
+
     #[error("unreachable")]
     Unreachable,
 }
@@ -22,6 +22,7 @@ pub struct CreateTableOptions<'a> {
     pub source: &'a Option<Box<Query>>,
     pub engine: &'a Option<String>,
     pub foreign_keys: &'a Vec<ForeignKey>,
+    pub composite_primary_key: &'a Option<Vec<String>>,
     pub comment: &'a Option<String>,
 }
 
@@ -34,11 +35,12 @@ pub async fn create_table<T: GStore + GStoreMut>(
         source,
         engine,
         foreign_keys,
+        composite_primary_key,
         comment,
     }: CreateTableOptions<'_>,
 ) -> Result<()> {
     let target_columns_defs = match source.as_deref() {
-        Some(Query { body, .. }) => match body {
+        Some(Query { body, .. }) => match leftmost_set_expr(body) {
             SetExpr::Select(select_query) => match &select_query.from.relation {
                 TableFactor::Table { name, .. } => {
                     let schema = storage.fetch_schema(name).await?;
@@ -66,6 +68,9 @@ pub async fn create_table<T: GStore + GStoreMut>(
                     return Err(AlterError::Unreachable.into());
                 }
             },
+            SetExpr::SetOperation { .. } => {
+                return Err(AlterError::Unreachable.into());
+            }
             SetExpr::Values(Values(values_list)) => {
                 let first_len = values_list[0].len();
                 let mut column_types = vec![None; first_len];
@@ -119,6 +124,22 @@ pub async fn create_table<T: GStore + GStoreMut>(
         }
     }
 
+    for column_name in composite_primary_key.iter().flatten() {
+        let column_def = target_columns_defs
+            .iter()
+            .flatten()
+            .find(|column_def| &column_def.name == column_name)
+            .ok_or_else(|| AlterError::PrimaryKeyColumnNotFound(column_name.to_owned()))?;
+
+        if matches!(column_def.data_type, DataType::Float | DataType::Map) {
+            return Err(AlterError::UnsupportedDataTypeForUniqueColumn(
+                column_name.to_owned(),
+                column_def.data_type.clone(),
+            )
+            .into());
+        }
+    }
+
     for foreign_key in foreign_keys {
         let ForeignKey {
             referencing_column_name,
@@ -186,11 +207,15 @@ pub async fn create_table<T: GStore + GStoreMut>(
             indexes: vec![],
             engine: engine.clone(),
             foreign_keys: foreign_keys.clone(),
+            composite_primary_key: composite_primary_key.clone(),
             comment: comment.clone(),
         };
 
         storage.insert_schema(&schema).await?;
-    } else if !if_not_exists {
+    } else if if_not_exists {
+        // the existing table is kept as it is, CTAS rows are not appended to it
+        return Ok(());
+    } else {
         return Err(AlterError::TableAlreadyExists(target_table_name.to_owned()).into());
     }
 
@@ -217,21 +242,24 @@ pub async fn drop_table<T: GStore + GStoreMut>(
     if_exists: bool,
     cascade: bool,
 ) -> Result<usize> {
-    let mut n = 0;
+    // every listed table is checked before any table is dropped
+    let mut existing = Vec::with_capacity(table_names.len());
 
-    for table_name in table_names {
-        let schema = storage.fetch_schema(table_name).await?;
+    for (i, table_name) in table_names.iter().enumerate() {
+        if table_names[..i].contains(table_name) {
+            return Err(AlterError::DuplicateTableName(table_name.to_owned()).into());
+        }
 
-        match (schema, if_exists) {
-            (None, true) => {
-                continue;
-            }
-            (None, false) => {
-                return Err(AlterError::TableNotFound(table_name.to_owned()).into());
-            }
-            _ => {}
+        match storage.fetch_schema(table_name).await? {
+            Some(_) => existing.push(table_name),
+            None if if_exists => continue,
+            None => return Err(AlterError::TableNotFound(table_name.to_owned()).into()),
         }
+    }
+
+    let mut n = 0;
 
+    for table_name in existing {
         let referencings = storage.fetch_referencings(table_name).await?;
 
         if !referencings.is_empty() && !cascade {
@@ -281,6 +309,14 @@ impl fmt::Display for Referencing {
     }
 }
 
+/// Columns of a set operation are named after its leftmost query.
+fn leftmost_set_expr(set_expr: &SetExpr) -> &SetExpr {
+    match set_expr {
+        SetExpr::SetOperation { left, .. } => leftmost_set_expr(left),
+        _ => set_expr,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, crate::ast::ReferentialAction};
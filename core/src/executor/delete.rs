@@ -1,10 +1,11 @@
 use {
     super::{
-        Payload, Referencing,
+        ExecuteError, Payload, Referencing,
         fetch::{fetch, fetch_columns},
+        returning::project_returning,
     },
     crate::{
-        ast::{BinaryOperator, Expr, ForeignKey, ReferentialAction},
+        ast::{BinaryOperator, Expr, ForeignKey, ReferentialAction, SelectItem},
         result::{Error, Result},
         store::{GStore, GStoreMut},
     },
@@ -27,10 +28,11 @@ pub async fn delete<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
     selection: &Option<Expr>,
+    returning: &[SelectItem],
 ) -> Result<Payload> {
     let columns = fetch_columns(storage, table_name).await?.map(Arc::from);
     let referencings = storage.fetch_referencings(table_name).await?;
-    let keys = fetch(storage, table_name, columns, selection.as_ref())
+    let rows = fetch(storage, table_name, columns, selection.as_ref())
         .await?
         .into_stream()
         .then(|item| async {
@@ -71,14 +73,56 @@ pub async fn delete<T: GStore + GStoreMut>(
                 }
             }
 
-            Ok::<_, Error>(key)
+            Ok::<_, Error>((key, row))
         })
         .try_collect::<Vec<_>>()
         .await?;
+    let (keys, rows): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
     let num_keys = keys.len();
+    let returned = match returning.is_empty() {
+        true => None,
+        false => Some(project_returning(storage, table_name, returning, rows).await?),
+    };
 
-    storage
-        .delete_data(table_name, keys)
-        .await
-        .map(|_| Payload::Delete(num_keys))
+    storage.delete_data(table_name, keys).await?;
+
+    Ok(returned.unwrap_or(Payload::Delete(num_keys)))
+}
+
+pub async fn truncate<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_names: &[String],
+) -> Result<Payload> {
+    for table_name in table_names {
+        if storage.fetch_schema(table_name).await?.is_none() {
+            return Err(ExecuteError::TableNotFound(table_name.to_owned()).into());
+        }
+
+        let referencing = storage
+            .fetch_referencings(table_name)
+            .await?
+            .into_iter()
+            .find(|Referencing { table_name, .. }| !table_names.contains(table_name));
+
+        if let Some(Referencing {
+            table_name: referencing_table_name,
+            foreign_key:
+                ForeignKey {
+                    referencing_column_name,
+                    ..
+                },
+        }) = referencing
+        {
+            return Err(DeleteError::ReferencingColumnExists(format!(
+                "{referencing_table_name}.{referencing_column_name}"
+            ))
+            .into());
+        }
+    }
+
+    for table_name in table_names {
+        storage.truncate_data(table_name).await?;
+    }
+
+    Ok(Payload::Truncate)
 }
@@ -5,10 +5,10 @@ mod function;
 
 use {
     self::function::BreakCase,
-    super::{context::RowContext, select::select},
+    super::{aggregate::sub_exprs, context::RowContext, select::select},
     crate::{
-        ast::{Aggregate, Expr, Function},
-        data::{CustomFunction, Interval, Literal, Row, Value},
+        ast::{Aggregate, AstLiteral, Expr, Function},
+        data::{CustomFunction, Interval, LikePattern, Literal, Row, Value},
         mock::MockStorage,
         result::{Error, Result},
         store::GStore,
@@ -16,11 +16,12 @@ use {
     async_recursion::async_recursion,
     chrono::prelude::Utc,
     futures::{
-        future::{ready, try_join_all},
+        future::try_join_all,
         stream::{self, StreamExt, TryStreamExt},
     },
     im::HashMap,
-    std::{borrow::Cow, ops::ControlFlow, sync::Arc},
+    std::{borrow::Cow, ops::ControlFlow, pin::pin, sync::Arc},
+    utils::Tribool,
 };
 
 pub use {error::EvaluateError, evaluated::Evaluated};
@@ -36,7 +37,24 @@ where
     'c: 'a,
     T: GStore,
 {
-    evaluate_inner(Some(storage), context, aggregated, expr).await
+    evaluate_inner(Some(storage), context, aggregated, None, expr).await
+}
+
+/// Evaluates `expr` with its LIKE and ILIKE patterns tokenized ahead by
+/// `compile_like_patterns`, for an `expr` which is evaluated against many rows.
+pub async fn evaluate_with_like_patterns<'a, 'b, 'c, T>(
+    storage: &'a T,
+    context: Option<Arc<RowContext<'b>>>,
+    aggregated: Option<Arc<HashMap<&'c Aggregate, Value>>>,
+    like_patterns: Option<Arc<LikePatterns<'c>>>,
+    expr: &'a Expr,
+) -> Result<Evaluated<'a>>
+where
+    'b: 'a,
+    'c: 'a,
+    T: GStore,
+{
+    evaluate_inner(Some(storage), context, aggregated, like_patterns, expr).await
 }
 
 pub async fn evaluate_stateless<'a, 'b: 'a>(
@@ -46,7 +64,45 @@ pub async fn evaluate_stateless<'a, 'b: 'a>(
     let context = context.map(Arc::new);
     let storage: Option<&MockStorage> = None;
 
-    evaluate_inner(storage, context, None, expr).await
+    evaluate_inner(storage, context, None, None, expr).await
+}
+
+/// Tokenized patterns of LIKE and ILIKE expressions, keyed by the expression.
+pub type LikePatterns<'a> = HashMap<&'a Expr, LikePattern>;
+
+/// Tokenizes the LIKE and ILIKE patterns of `expr` which are string literals, so that they
+/// are not tokenized again for every row.
+///
+/// A pattern which fails to tokenize is left out, evaluating it reports the error. Patterns
+/// in a subquery are left to the subquery.
+pub fn compile_like_patterns(expr: &Expr) -> LikePatterns<'_> {
+    let like_patterns = sub_exprs(expr)
+        .into_iter()
+        .map(compile_like_patterns)
+        .fold(LikePatterns::new(), LikePatterns::union);
+
+    let (pattern, escape, case_sensitive) = match expr {
+        Expr::Like {
+            pattern, escape, ..
+        } => (pattern, *escape, true),
+        Expr::ILike {
+            pattern, escape, ..
+        } => (pattern, *escape, false),
+        _ => return like_patterns,
+    };
+    let pattern = match pattern.as_ref() {
+        Expr::Literal(AstLiteral::QuotedString(pattern)) => pattern,
+        Expr::Value(value) => match value.as_ref() {
+            Value::Str(pattern) => pattern,
+            _ => return like_patterns,
+        },
+        _ => return like_patterns,
+    };
+
+    match LikePattern::new(pattern, escape, case_sensitive) {
+        Ok(compiled) => like_patterns.update(expr, compiled),
+        Err(_) => like_patterns,
+    }
 }
 
 #[async_recursion]
@@ -54,6 +110,7 @@ async fn evaluate_inner<'a, 'b, 'c, T>(
     storage: Option<&'a T>,
     context: Option<Arc<RowContext<'b>>>,
     aggregated: Option<Arc<HashMap<&'c Aggregate, Value>>>,
+    like_patterns: Option<Arc<LikePatterns<'c>>>,
     expr: &'a Expr,
 ) -> Result<Evaluated<'a>>
 where
@@ -64,8 +121,15 @@ where
     let eval = |expr| {
         let context = context.as_ref().map(Arc::clone);
         let aggregated = aggregated.as_ref().map(Arc::clone);
+        let like_patterns = like_patterns.as_ref().map(Arc::clone);
 
-        evaluate_inner(storage, context, aggregated, expr)
+        evaluate_inner(storage, context, aggregated, like_patterns, expr)
+    };
+    // `expr` is the LIKE or ILIKE expression itself, which keys its tokenized pattern
+    let like_pattern = || {
+        like_patterns
+            .as_ref()
+            .and_then(|like_patterns| like_patterns.get(expr))
     };
 
     match expr {
@@ -73,6 +137,8 @@ where
         Expr::TypedString { data_type, value } => {
             expr::typed_string(data_type, Cow::Borrowed(value))
         }
+        Expr::Value(value) => Ok(Evaluated::Value(value.as_ref().clone())),
+        Expr::Placeholder => Err(EvaluateError::UnboundPlaceholder.into()),
         Expr::Identifier(ident) => {
             let context = context
                 .ok_or_else(|| EvaluateError::ContextRequiredForIdentEvaluation(expr.clone()))?;
@@ -158,8 +224,9 @@ where
         Expr::Function(func) => {
             let context = context.as_ref().map(Arc::clone);
             let aggregated = aggregated.as_ref().map(Arc::clone);
+            let like_patterns = like_patterns.as_ref().map(Arc::clone);
 
-            evaluate_function(storage, context, aggregated, func).await
+            evaluate_function(storage, context, aggregated, like_patterns, func).await
         }
         Expr::InList {
             expr,
@@ -173,12 +240,21 @@ where
                 return Ok(target);
             }
 
-            let matched = try_join_all(list.iter().map(eval))
-                .await?
-                .into_iter()
-                .any(|v| v.evaluate_eq(&target).is_true());
+            let mut unknown = false;
+            for evaluated in try_join_all(list.iter().map(eval)).await? {
+                match expr::eq(&target, &evaluated)? {
+                    Tribool::True => return Ok(Evaluated::Value(Value::Bool(!negated))),
+                    Tribool::False => {}
+                    Tribool::Null => unknown = true,
+                }
+            }
+
+            let value = match unknown {
+                true => Value::Null,
+                false => Value::Bool(negated),
+            };
 
-            Ok(Evaluated::Value(Value::Bool(matched ^ negated)))
+            Ok(Evaluated::Value(value))
         }
         Expr::InSubquery {
             expr: target_expr,
@@ -189,10 +265,13 @@ where
                 storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
             let target = eval(target_expr).await?;
 
-            select(storage, subquery, context)
+            let rows = select(storage, subquery, context)
                 .await?
-                .map(|row| {
+                .map(|row| -> Result<_> {
                     let value = match row? {
+                        Row::Vec { values, .. } if values.len() > 1 => {
+                            return Err(EvaluateError::MoreThanOneColumnReturned.into());
+                        }
                         Row::Vec { values, .. } => values,
                         Row::Map(_) => {
                             return Err(EvaluateError::SchemalessProjectionForInSubQuery.into());
@@ -203,13 +282,25 @@ where
                     .unwrap_or(Value::Null);
 
                     Ok(Evaluated::Value(value))
-                })
-                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target).is_true()))
-                .try_next()
-                .await
-                .map(|v| v.is_some() ^ negated)
-                .map(Value::Bool)
-                .map(Evaluated::Value)
+                });
+            let mut rows = pin!(rows);
+
+            // no match with a NULL compared on the way is unknown, not false
+            let mut unknown = false;
+            while let Some(evaluated) = rows.try_next().await? {
+                match expr::eq(&target, &evaluated)? {
+                    Tribool::True => return Ok(Evaluated::Value(Value::Bool(!negated))),
+                    Tribool::False => {}
+                    Tribool::Null => unknown = true,
+                }
+            }
+
+            let value = match unknown {
+                true => Value::Null,
+                false => Value::Bool(*negated),
+            };
+
+            Ok(Evaluated::Value(value))
         }
         Expr::Between {
             expr,
@@ -227,10 +318,12 @@ where
             expr,
             negated,
             pattern,
+            escape,
         } => {
+            let compiled = like_pattern();
             let target = eval(expr).await?;
             let pattern = eval(pattern).await?;
-            let evaluated = target.like(pattern, true)?;
+            let evaluated = target.like(pattern, *escape, true, compiled)?;
 
             Ok(match negated {
                 true => {
@@ -244,10 +337,12 @@ where
             expr,
             negated,
             pattern,
+            escape,
         } => {
+            let compiled = like_pattern();
             let target = eval(expr).await?;
             let pattern = eval(pattern).await?;
-            let evaluated = target.like(pattern, false)?;
+            let evaluated = target.like(pattern, *escape, false, compiled)?;
 
             Ok(match negated {
                 true => {
@@ -285,14 +380,19 @@ where
             else_result,
         } => {
             let operand = match operand {
-                Some(op) => eval(op).await?,
-                None => Evaluated::Value(Value::Bool(true)),
+                Some(op) => Some(eval(op).await?),
+                None => None,
             };
 
             for (when, then) in when_then.iter() {
                 let when = eval(when).await?;
+                let matched = match &operand {
+                    Some(operand) => expr::eq(operand, &when)?.is_true(),
+                    None if when.is_null() => false,
+                    None => bool::try_from(when)?,
+                };
 
-                if when.evaluate_eq(&operand).is_true() {
+                if matched {
                     return eval(then).await;
                 }
             }
@@ -335,6 +435,7 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
     storage: Option<&'a T>,
     context: Option<Arc<RowContext<'b>>>,
     aggregated: Option<Arc<HashMap<&'c Aggregate, Value>>>,
+    like_patterns: Option<Arc<LikePatterns<'c>>>,
     func: &'b Function,
 ) -> Result<Evaluated<'a>> {
     use function as f;
@@ -342,8 +443,9 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
     let eval = |expr| {
         let context = context.as_ref().map(Arc::clone);
         let aggregated = aggregated.as_ref().map(Arc::clone);
+        let like_patterns = like_patterns.as_ref().map(Arc::clone);
 
-        evaluate_inner(storage, context, aggregated, expr)
+        evaluate_inner(storage, context, aggregated, like_patterns, expr)
     };
 
     let name = func.to_string();
@@ -399,14 +501,21 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
                     Some(Arc::new(context))
                 })?;
 
-            return evaluate_inner(storage, context, None, body).await;
+            return evaluate_inner(storage, context, None, None, body).await;
         }
         Function::ConcatWs { separator, exprs } => {
             let separator = eval(separator).await?;
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             f::concat_ws(name, separator, exprs)
         }
-        Function::IfNull { expr, then } => f::ifnull(eval(expr).await?, eval(then).await?),
+        Function::IfNull { expr, then } => {
+            let expr = eval(expr).await?;
+
+            return match expr.is_null() {
+                true => eval(then).await,
+                false => Ok(expr),
+            };
+        }
         Function::NullIf { expr1, expr2 } => f::nullif(eval(expr1).await?, eval(expr2).await?),
         Function::Lower(expr) => f::lower(name, eval(expr).await?),
         Function::Initcap(expr) => f::initcap(name, eval(expr).await?),
@@ -514,7 +623,15 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
 
             f::rand(name, expr)
         }
-        Function::Round(expr) => f::round(name, eval(expr).await?),
+        Function::Round { expr, precision } => {
+            let expr = eval(expr).await?;
+            let precision = match precision {
+                Some(precision) => Some(eval(precision).await?),
+                None => None,
+            };
+
+            f::round(name, expr, precision)
+        }
         Function::Floor(expr) => f::floor(name, eval(expr).await?),
         Function::Radians(expr) => f::radians(name, eval(expr).await?),
         Function::Degrees(expr) => f::degrees(name, eval(expr).await?),
@@ -648,8 +765,25 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             f::extract(field, expr)
         }
         Function::Coalesce(exprs) => {
-            let exprs = stream::iter(exprs).then(eval).try_collect().await?;
-            return f::coalesce(exprs);
+            if exprs.is_empty() {
+                return Err((EvaluateError::FunctionRequiresMoreArguments {
+                    function_name: "COALESCE".to_owned(),
+                    required_minimum: 1,
+                    found: exprs.len(),
+                })
+                .into());
+            }
+
+            // arguments after the first non-NULL one are never evaluated
+            for expr in exprs {
+                let value = Value::try_from(eval(expr).await?)?;
+
+                if !value.is_null() {
+                    return Ok(Evaluated::Value(value));
+                }
+            }
+
+            return Ok(Evaluated::Value(Value::Null));
         }
 
         // --- list ---
@@ -132,6 +132,20 @@ pub enum EvaluateError {
         right: String,
     },
 
+    #[error("cannot compare a number with a string: {} {} {}", .left, .op.to_sql(), .right)]
+    NumberComparedWithString {
+        left: String,
+        op: BinaryOperator,
+        right: String,
+    },
+
+    #[error("cannot compare a string with a non-string value: {} {} {}", .left, .op.to_sql(), .right)]
+    StringComparedWithNonString {
+        left: String,
+        op: BinaryOperator,
+        right: String,
+    },
+
     #[error("unsupported evaluate string unary plus: {0}")]
     UnsupportedUnaryPlus(String),
 
@@ -182,8 +196,14 @@ pub enum EvaluateError {
     #[error("GCD or LCM calculation overflowed on trying to get the absolute value of {0}")]
     GcdLcmOverflow(i64),
 
+    #[error("ABS overflowed on trying to get the absolute value of {0}")]
+    AbsOverflow(String),
+
     #[error("failed to convert Value to u32: {0}")]
     I64ToU32ConversionFailure(String),
+
+    #[error("placeholder is not bound to a value")]
+    UnboundPlaceholder,
 }
 
 fn error_serialize<S>(error: &chrono::format::ParseError, serializer: S) -> Result<S::Ok, S::Error>
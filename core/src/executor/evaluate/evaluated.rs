@@ -2,7 +2,7 @@ use {
     super::error::EvaluateError,
     crate::{
         ast::{BinaryOperator, DataType, TrimWhereField},
-        data::{Key, Literal, Value, value::BTreeMapJsonExt},
+        data::{Key, LikePattern, Literal, Value, value::BTreeMapJsonExt},
         result::{Error, Result},
     },
     std::{borrow::Cow, cmp::Ordering, collections::BTreeMap, ops::Range},
@@ -125,6 +125,15 @@ pub fn exceptional_int_val_to_eval<'a>(name: String, v: Value) -> Result<Evaluat
     }
 }
 
+/// Values which are written as strings, so a string is compared with them by being parsed
+/// into their type.
+pub(super) fn is_parsed_from_text(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Date(_) | Value::Timestamp(_) | Value::Time(_) | Value::Uuid(_) | Value::Inet(_)
+    )
+}
+
 impl From<Tribool> for Evaluated<'_> {
     fn from(x: Tribool) -> Self {
         Evaluated::Value(Value::from(x))
@@ -137,6 +146,12 @@ impl<'a> Evaluated<'a> {
             (Evaluated::Literal(a), Evaluated::Literal(b)) => a.evaluate_eq(b),
             (Evaluated::Literal(b), Evaluated::Value(a))
             | (Evaluated::Value(a), Evaluated::Literal(b)) => a.evaluate_eq_with_literal(b),
+            (Evaluated::Value(a), Evaluated::Value(Value::Str(b)))
+            | (Evaluated::Value(Value::Str(b)), Evaluated::Value(a))
+                if is_parsed_from_text(a) =>
+            {
+                a.evaluate_eq_with_literal(&Literal::Text(Cow::Borrowed(b)))
+            }
             (Evaluated::Value(a), Evaluated::Value(b)) => a.evaluate_eq(b),
             (Evaluated::Literal(a), Evaluated::StrSlice { source, range })
             | (Evaluated::StrSlice { source, range }, Evaluated::Literal(a)) => {
@@ -165,6 +180,12 @@ impl<'a> Evaluated<'a> {
                 r.evaluate_cmp_with_literal(l).map(|o| o.reverse())
             }
             (Evaluated::Value(l), Evaluated::Literal(r)) => l.evaluate_cmp_with_literal(r),
+            (Evaluated::Value(l), Evaluated::Value(Value::Str(r))) if is_parsed_from_text(l) => {
+                l.evaluate_cmp_with_literal(&Literal::Text(Cow::Borrowed(r)))
+            }
+            (Evaluated::Value(Value::Str(l)), Evaluated::Value(r)) if is_parsed_from_text(r) => r
+                .evaluate_cmp_with_literal(&Literal::Text(Cow::Borrowed(l)))
+                .map(|o| o.reverse()),
             (Evaluated::Value(l), Evaluated::Value(r)) => l.evaluate_cmp(r),
             (Evaluated::Literal(l), Evaluated::StrSlice { source, range }) => {
                 let r = Literal::Text(Cow::Borrowed(&source[range.clone()]));
@@ -384,27 +405,58 @@ impl<'a> Evaluated<'a> {
         Ok(evaluated)
     }
 
-    pub fn like(&self, other: Evaluated<'a>, case_sensitive: bool) -> Result<Evaluated<'a>> {
+    /// Matches against the LIKE pattern `other`, `compiled` is `other` already tokenized.
+    pub fn like(
+        &self,
+        other: Evaluated<'a>,
+        escape: Option<char>,
+        case_sensitive: bool,
+        compiled: Option<&LikePattern>,
+    ) -> Result<Evaluated<'a>> {
+        if let Some(compiled) = compiled {
+            match self {
+                Evaluated::Literal(Literal::Text(l)) => {
+                    return Ok(Evaluated::Literal(Literal::Boolean(compiled.matches(l))));
+                }
+                Evaluated::Value(Value::Str(l)) => {
+                    return Ok(Evaluated::Value(Value::Bool(compiled.matches(l))));
+                }
+                Evaluated::StrSlice { source, range } => {
+                    return Ok(Evaluated::Value(Value::Bool(
+                        compiled.matches(&source[range.clone()]),
+                    )));
+                }
+                _ => {}
+            }
+        }
+
         let evaluated = match (self, other) {
             (Evaluated::Literal(l), Evaluated::Literal(r)) => {
-                Evaluated::Literal(l.like(&r, case_sensitive)?)
+                Evaluated::Literal(l.like(&r, escape, case_sensitive)?)
             }
             (Evaluated::Literal(l), Evaluated::Value(r)) => {
-                Evaluated::Value((Value::try_from(l)?).like(&r, case_sensitive)?)
+                Evaluated::Value((Value::try_from(l)?).like(&r, escape, case_sensitive)?)
             }
             (Evaluated::Value(l), Evaluated::Literal(r)) => {
-                Evaluated::Value(l.like(&Value::try_from(r)?, case_sensitive)?)
+                Evaluated::Value(l.like(&Value::try_from(r)?, escape, case_sensitive)?)
             }
             (Evaluated::Value(l), Evaluated::Value(r)) => {
-                Evaluated::Value(l.like(&r, case_sensitive)?)
+                Evaluated::Value(l.like(&r, escape, case_sensitive)?)
+            }
+            (Evaluated::Literal(l), Evaluated::StrSlice { source, range }) => {
+                Evaluated::Value(Value::try_from(l)?.like(
+                    &Value::Str(source[range].to_owned()),
+                    escape,
+                    case_sensitive,
+                )?)
+            }
+            (Evaluated::StrSlice { source, range }, Evaluated::Literal(r)) => {
+                Evaluated::Value(Value::Str(source[range.clone()].to_owned()).like(
+                    &Value::try_from(r)?,
+                    escape,
+                    case_sensitive,
+                )?)
             }
-            (Evaluated::Literal(l), Evaluated::StrSlice { source, range }) => Evaluated::Value(
-                Value::try_from(l)?.like(&Value::Str(source[range].to_owned()), case_sensitive)?,
-            ),
-            (Evaluated::StrSlice { source, range }, Evaluated::Literal(r)) => Evaluated::Value(
-                Value::Str(source[range.clone()].to_owned())
-                    .like(&Value::try_from(r)?, case_sensitive)?,
-            ),
             (
                 Evaluated::StrSlice {
                     source: a,
@@ -414,15 +466,20 @@ impl<'a> Evaluated<'a> {
                     source: b,
                     range: br,
                 },
-            ) => Evaluated::Value(
-                Value::Str(a[ar.clone()].to_owned())
-                    .like(&Value::Str(b[br].to_owned()), case_sensitive)?,
-            ),
+            ) => Evaluated::Value(Value::Str(a[ar.clone()].to_owned()).like(
+                &Value::Str(b[br].to_owned()),
+                escape,
+                case_sensitive,
+            )?),
             (Evaluated::StrSlice { source, range }, Evaluated::Value(r)) => Evaluated::Value(
-                Value::Str(source[range.clone()].to_owned()).like(&r, case_sensitive)?,
+                Value::Str(source[range.clone()].to_owned()).like(&r, escape, case_sensitive)?,
             ),
             (Evaluated::Value(l), Evaluated::StrSlice { source, range }) => {
-                Evaluated::Value(l.like(&Value::Str(source[range].to_owned()), case_sensitive)?)
+                Evaluated::Value(l.like(
+                    &Value::Str(source[range].to_owned()),
+                    escape,
+                    case_sensitive,
+                )?)
             }
         };
 
@@ -1,11 +1,12 @@
 use {
-    super::{EvaluateError, Evaluated},
+    super::{EvaluateError, Evaluated, evaluated::is_parsed_from_text},
     crate::{
         ast::{AstLiteral, BinaryOperator, DataType, UnaryOperator},
         data::{Literal, Value},
         result::Result,
     },
     std::{borrow::Cow, cmp::Ordering},
+    utils::Tribool,
 };
 
 pub fn literal(ast_literal: &AstLiteral) -> Result<Evaluated<'_>> {
@@ -39,10 +40,26 @@ pub fn binary_op<'a>(
         }};
     }
 
-    if l.is_null() || r.is_null() {
+    macro_rules! logic {
+        (l $op: tt r) => {{
+            let v = tribool(l)? $op tribool(r)?;
+
+            Ok(Evaluated::from(v))
+        }};
+    }
+
+    let logical = matches!(op, BinaryOperator::And | BinaryOperator::Or);
+    if !logical && (l.is_null() || r.is_null()) {
         return Ok(Evaluated::Value(Value::Null));
     }
 
+    if matches!(
+        op,
+        BinaryOperator::Lt | BinaryOperator::LtEq | BinaryOperator::Gt | BinaryOperator::GtEq
+    ) {
+        validate_comparable(&l, op, &r)?;
+    }
+
     match op {
         BinaryOperator::Plus => l.add(&r),
         BinaryOperator::Minus => l.subtract(&r),
@@ -50,8 +67,8 @@ pub fn binary_op<'a>(
         BinaryOperator::Divide => l.divide(&r),
         BinaryOperator::Modulo => l.modulo(&r),
         BinaryOperator::StringConcat => l.concat(r),
-        BinaryOperator::Eq => Ok(Evaluated::from(l.evaluate_eq(&r))),
-        BinaryOperator::NotEq => Ok(Evaluated::from(!l.evaluate_eq(&r))),
+        BinaryOperator::Eq => eq(&l, &r).map(Evaluated::from),
+        BinaryOperator::NotEq => eq(&l, &r).map(|v| Evaluated::from(!v)),
         BinaryOperator::Lt => cmp!(l.evaluate_cmp(&r) == Some(Ordering::Less)),
         BinaryOperator::LtEq => cmp!(matches!(
             l.evaluate_cmp(&r),
@@ -62,8 +79,8 @@ pub fn binary_op<'a>(
             l.evaluate_cmp(&r),
             Some(Ordering::Greater) | Some(Ordering::Equal)
         )),
-        BinaryOperator::And => cond!(l && r),
-        BinaryOperator::Or => cond!(l || r),
+        BinaryOperator::And => logic!(l & r),
+        BinaryOperator::Or => logic!(l | r),
         BinaryOperator::Xor => cond!(l ^ r),
         BinaryOperator::BitwiseAnd => l.bitwise_and(&r),
         BinaryOperator::BitwiseShiftLeft => l.bitwise_shift_left(&r),
@@ -71,6 +88,85 @@ pub fn binary_op<'a>(
     }
 }
 
+/// Equality shared by `=`, `IN`, the operand of a simple `CASE` and `NULLIF`, operands which
+/// cannot be compared fail instead of being silently unequal.
+pub fn eq(l: &Evaluated<'_>, r: &Evaluated<'_>) -> Result<Tribool> {
+    if l.is_null() || r.is_null() {
+        return Ok(Tribool::Null);
+    }
+
+    validate_comparable(l, &BinaryOperator::Eq, r)?;
+
+    Ok(l.evaluate_eq(r))
+}
+
+/// Numbers are compared with each other across integer and float types, and strings with
+/// strings. A string is also compared with the date, time, UUID and INET values it is parsed
+/// into, whether it is a literal, a column or a bound param, any other pair with a string would
+/// otherwise silently compare as unequal.
+fn validate_comparable(l: &Evaluated<'_>, op: &BinaryOperator, r: &Evaluated<'_>) -> Result<()> {
+    fn is_number(v: &Evaluated<'_>) -> bool {
+        matches!(
+            v,
+            Evaluated::Literal(Literal::Number(_))
+                | Evaluated::Value(
+                    Value::I8(_)
+                        | Value::I16(_)
+                        | Value::I32(_)
+                        | Value::I64(_)
+                        | Value::I128(_)
+                        | Value::U8(_)
+                        | Value::U16(_)
+                        | Value::U32(_)
+                        | Value::U64(_)
+                        | Value::U128(_)
+                        | Value::F32(_)
+                        | Value::F64(_)
+                        | Value::Decimal(_)
+                )
+        )
+    }
+
+    fn is_string(v: &Evaluated<'_>) -> bool {
+        matches!(
+            v,
+            Evaluated::Literal(Literal::Text(_))
+                | Evaluated::StrSlice { .. }
+                | Evaluated::Value(Value::Str(_))
+        )
+    }
+
+    let other = match (is_string(l), is_string(r)) {
+        (true, false) => r,
+        (false, true) => l,
+        _ => return Ok(()),
+    };
+
+    if !matches!(other, Evaluated::Value(value) if is_parsed_from_text(value)) {
+        let describe = |v: &Evaluated<'_>| Value::try_from(v.clone()).map(|v| format!("{v:?}"));
+        let (left, op, right) = (describe(l)?, op.clone(), describe(r)?);
+
+        return Err(if is_number(other) {
+            EvaluateError::NumberComparedWithString { left, op, right }
+        } else {
+            EvaluateError::StringComparedWithNonString { left, op, right }
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Operands of `AND` and `OR` follow three-valued logic, `NULL` is an unknown boolean which
+/// may still lead to a known result, e.g. `NULL AND FALSE` is `FALSE`.
+fn tribool(v: Evaluated<'_>) -> Result<Tribool> {
+    if v.is_null() {
+        return Ok(Tribool::Null);
+    }
+
+    bool::try_from(v).map(Tribool::from)
+}
+
 pub fn unary_op<'a>(op: &UnaryOperator, v: Evaluated<'a>) -> Result<Evaluated<'a>> {
     match op {
         UnaryOperator::Plus => v.unary_plus(),
@@ -91,8 +187,16 @@ pub fn between<'a>(
         return Ok(Evaluated::Value(Value::Null));
     }
 
-    let v = low.evaluate_cmp(&target) != Some(Ordering::Greater)
-        && target.evaluate_cmp(&high) != Some(Ordering::Greater);
+    validate_comparable(&target, &BinaryOperator::GtEq, &low)?;
+    validate_comparable(&target, &BinaryOperator::LtEq, &high)?;
+
+    let v = matches!(
+        target.evaluate_cmp(&low),
+        Some(Ordering::Greater) | Some(Ordering::Equal)
+    ) && matches!(
+        target.evaluate_cmp(&high),
+        Some(Ordering::Less) | Some(Ordering::Equal)
+    );
     let v = negated ^ v;
 
     Ok(Evaluated::Value(Value::Bool(v)))
@@ -1,14 +1,14 @@
 use {
-    super::{EvaluateError, Evaluated},
+    super::{EvaluateError, Evaluated, expr},
     crate::{
         ast::DateTimeField,
-        data::{Key, Point, Value},
+        data::{ConvertError, Key, Point, Value},
         result::{Error, Result},
     },
     chrono::{Datelike, Duration, Months},
     md5::{Digest, Md5},
     rand::{Rng, SeedableRng, rngs::StdRng},
-    std::ops::ControlFlow::{self as StdControlFlow, Break, Continue},
+    std::ops::ControlFlow::{Break, Continue},
     uuid::Uuid,
 };
 
@@ -135,6 +135,35 @@ fn eval_to_float(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<f64> {
     }
 }
 
+/// Rounds a number to an integral value, keeping its type so integers come back unchanged
+fn eval_rounded<'a>(
+    name: &str,
+    evaluated: Evaluated<'_>,
+    round: fn(f64) -> f64,
+) -> ControlFlow<Evaluated<'a>> {
+    let value = match evaluated.try_into().break_if_null()? {
+        value @ (Value::I8(_)
+        | Value::I16(_)
+        | Value::I32(_)
+        | Value::I64(_)
+        | Value::I128(_)
+        | Value::U8(_)
+        | Value::U16(_)
+        | Value::U32(_)
+        | Value::U64(_)
+        | Value::U128(_)) => value,
+        Value::F32(v) => Value::F32(round(v.into()) as f32),
+        Value::F64(v) => Value::F64(round(v)),
+        _ => {
+            return Break(BreakCase::Err(
+                EvaluateError::FunctionRequiresFloatValue(name.to_owned()).into(),
+            ));
+        }
+    };
+
+    Continue(Evaluated::Value(value))
+}
+
 fn eval_to_point(name: &str, evaluated: Evaluated<'_>) -> ControlFlow<Point> {
     match evaluated.try_into().break_if_null()? {
         Value::Point(v) => Continue(v),
@@ -368,31 +397,34 @@ pub fn hex<'a>(name: String, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>>
 // --- float ---
 
 pub fn abs<'a>(name: String, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
-    let value = match n.try_into().break_if_null()? {
-        Value::I8(v) => Value::I8(v.abs()),
-        Value::I32(v) => Value::I32(v.abs()),
-        Value::I64(v) => Value::I64(v.abs()),
-        Value::I128(v) => Value::I128(v.abs()),
-        Value::Decimal(v) => Value::Decimal(v.abs()),
-        Value::F32(v) => Value::F32(v.abs()),
-        Value::F64(v) => Value::F64(v.abs()),
+    let value: Value = n.try_into().break_if_null()?;
+    let abs = match &value {
+        Value::I8(v) => v.checked_abs().map(Value::I8),
+        Value::I16(v) => v.checked_abs().map(Value::I16),
+        Value::I32(v) => v.checked_abs().map(Value::I32),
+        Value::I64(v) => v.checked_abs().map(Value::I64),
+        Value::I128(v) => v.checked_abs().map(Value::I128),
+        Value::U8(_) | Value::U16(_) | Value::U32(_) | Value::U64(_) | Value::U128(_) => {
+            Some(value.clone())
+        }
+        Value::Decimal(v) => Some(Value::Decimal(v.abs())),
+        Value::F32(v) => Some(Value::F32(v.abs())),
+        Value::F64(v) => Some(Value::F64(v.abs())),
         _ => {
             return Err(EvaluateError::FunctionRequiresFloatValue(name).into()).into_control_flow();
         }
     };
 
-    Continue(Evaluated::Value(value))
-}
-
-pub fn ifnull<'a>(expr: Evaluated<'a>, then: Evaluated<'a>) -> ControlFlow<Evaluated<'a>> {
-    Continue(match expr.is_null() {
-        true => then,
-        false => expr,
-    })
+    match abs {
+        Some(abs) => Continue(Evaluated::Value(abs)),
+        None => Err(EvaluateError::AbsOverflow(String::from(&value)).into()).into_control_flow(),
+    }
 }
 
 pub fn nullif<'a>(expr1: Evaluated<'a>, expr2: Evaluated<'a>) -> ControlFlow<Evaluated<'a>> {
-    Continue(match expr1 == expr2 {
+    let eq = expr::eq(&expr1, &expr2).into_control_flow()?;
+
+    Continue(match eq.is_true() {
         true => Evaluated::Value(Value::Null),
         false => expr1,
     })
@@ -426,7 +458,7 @@ pub fn power<'a>(
 }
 
 pub fn ceil<'a>(name: String, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
-    eval_to_float(&name, n).map(|n| Evaluated::Value(Value::F64(n.ceil())))
+    eval_rounded(&name, n, f64::ceil)
 }
 
 pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> ControlFlow<Evaluated<'a>> {
@@ -438,12 +470,95 @@ pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> ControlFlow<Evalua
     Continue(Evaluated::Value(Value::F64(seed)))
 }
 
-pub fn round<'a>(name: String, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
-    eval_to_float(&name, n).map(|n| Evaluated::Value(Value::F64(n.round())))
+pub fn round<'a>(
+    name: String,
+    n: Evaluated<'_>,
+    precision: Option<Evaluated<'_>>,
+) -> ControlFlow<Evaluated<'a>> {
+    let Some(precision) = precision else {
+        return eval_rounded(&name, n, f64::round);
+    };
+
+    let value: Value = n.try_into().break_if_null()?;
+    let precision = eval_to_int(&name, precision)?;
+
+    // f64 has no digits to round beyond this range, so the factor stays finite
+    let factor = 10_f64.powi(precision.clamp(-308, 308) as i32);
+    let round = |n: f64| {
+        let rounded = (n * factor).round() / factor;
+
+        if rounded.is_finite() { rounded } else { n }
+    };
+
+    let rounded = match value {
+        Value::F32(v) => Value::F32(round(v.into()) as f32),
+        Value::F64(v) => Value::F64(round(v)),
+        Value::I8(_)
+        | Value::I16(_)
+        | Value::I32(_)
+        | Value::I64(_)
+        | Value::I128(_)
+        | Value::U8(_)
+        | Value::U16(_)
+        | Value::U32(_)
+        | Value::U64(_)
+        | Value::U128(_) => match value.get_type() {
+            // integers only change when rounded to the left of the decimal point
+            Some(data_type) if precision < 0 => {
+                let digits = u32::try_from(precision.unsigned_abs()).unwrap_or(u32::MAX);
+                let rounded = match value {
+                    Value::U128(v) => round_u128(v, digits).map(Value::U128),
+                    _ => i128::try_from(&value)
+                        .ok()
+                        .and_then(|v| round_i128(v, digits))
+                        .map(Value::I128),
+                };
+
+                match rounded {
+                    Some(rounded) => rounded.cast(&data_type),
+                    None => Err(ConvertError { value, data_type }.into()),
+                }
+                .into_control_flow()?
+            }
+            _ => value,
+        },
+        _ => {
+            return Err(EvaluateError::FunctionRequiresFloatValue(name).into()).into_control_flow();
+        }
+    };
+
+    Continue(Evaluated::Value(rounded))
+}
+
+/// Rounds half away from zero to a multiple of `10^digits`, `None` on overflow.
+fn round_i128(n: i128, digits: u32) -> Option<i128> {
+    let Some(factor) = 10_i128.checked_pow(digits) else {
+        return Some(0);
+    };
+    let rem = n % factor;
+
+    if rem.unsigned_abs() * 2 >= factor.unsigned_abs() {
+        (n - rem).checked_add(rem.signum() * factor)
+    } else {
+        Some(n - rem)
+    }
+}
+
+fn round_u128(n: u128, digits: u32) -> Option<u128> {
+    let Some(factor) = 10_u128.checked_pow(digits) else {
+        return Some(0);
+    };
+    let rem = n % factor;
+
+    if rem * 2 >= factor {
+        (n - rem).checked_add(factor)
+    } else {
+        Some(n - rem)
+    }
 }
 
 pub fn floor<'a>(name: String, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
-    eval_to_float(&name, n).map(|n| Evaluated::Value(Value::F64(n.floor())))
+    eval_rounded(&name, n, f64::floor)
 }
 
 pub fn radians<'a>(name: String, n: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
@@ -983,31 +1098,6 @@ pub fn length<'a>(name: String, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a
     .into_control_flow()
 }
 
-pub fn coalesce<'a>(exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'a>> {
-    if exprs.is_empty() {
-        return Err((EvaluateError::FunctionRequiresMoreArguments {
-            function_name: "COALESCE".to_owned(),
-            required_minimum: 1,
-            found: exprs.len(),
-        })
-        .into());
-    }
-
-    let control_flow = exprs.into_iter().map(|expr| expr.try_into()).try_for_each(
-        |item: Result<Value>| match item {
-            Ok(value) if value.is_null() => StdControlFlow::Continue(()),
-            Ok(value) => StdControlFlow::Break(Ok(value)),
-            Err(err) => StdControlFlow::Break(Err(err)),
-        },
-    );
-
-    match control_flow {
-        StdControlFlow::Break(Ok(value)) => Ok(Evaluated::Value(value)),
-        StdControlFlow::Break(Err(err)) => Err(err),
-        StdControlFlow::Continue(()) => Ok(Evaluated::Value(Value::Null)),
-    }
-}
-
 pub fn entries<'a>(name: String, expr: Evaluated<'_>) -> ControlFlow<Evaluated<'a>> {
     match expr.try_into().break_if_null()? {
         Value::Map(expr) => {
@@ -4,9 +4,10 @@ use {
             CreateTableOptions, alter_table, create_index, create_table, delete_function,
             drop_table, insert_function,
         },
-        delete::delete,
+        delete::{delete, truncate},
         fetch::fetch,
         insert::insert,
+        returning::project_returning,
         select::{select, select_with_labels},
         update::Update,
         validate::{ColumnValidation, validate_unique},
@@ -42,12 +43,18 @@ pub enum Payload {
     ShowColumns(Vec<(String, DataType)>),
     Create,
     Insert(usize),
+    /// `INSERT ... ON CONFLICT`, counts the inserted rows and the existing rows updated instead
+    Upsert {
+        inserted: usize,
+        updated: usize,
+    },
     Select {
         labels: Vec<String>,
         rows: Vec<Vec<Value>>,
     },
     SelectMap(Vec<BTreeMap<String, Value>>),
     Delete(usize),
+    Truncate,
     Update(usize),
     DropTable(usize),
     DropFunction,
@@ -142,6 +149,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
             source,
             engine,
             foreign_keys,
+            composite_primary_key,
             comment,
         } => {
             let options = CreateTableOptions {
@@ -151,6 +159,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 source,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
 
@@ -192,17 +201,29 @@ async fn execute_inner<T: GStore + GStoreMut>(
             table_name,
             columns,
             source,
-        } => insert(storage, table_name, columns, source)
+            on_conflict,
+            returning,
+        } => {
+            insert(
+                storage,
+                table_name,
+                columns,
+                source,
+                on_conflict.as_ref(),
+                returning,
+            )
             .await
-            .map(Payload::Insert),
+        }
         Statement::Update {
             table_name,
             selection,
             assignments,
+            returning,
         } => {
             let Schema {
                 column_defs,
                 foreign_keys,
+                composite_primary_key,
                 ..
             } = storage
                 .fetch_schema(table_name)
@@ -232,7 +253,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
 
                     let foreign_keys = Arc::clone(&foreign_keys);
                     async move {
-                        let row = update.apply(row, foreign_keys.as_ref()).await?;
+                        let row = update.apply(row, foreign_keys.as_ref(), None).await?;
 
                         Ok((key, row))
                     }
@@ -241,31 +262,51 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 .await?;
 
             if let Some(column_defs) = column_defs {
-                let column_validation =
-                    ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update);
+                let updated_keys = rows.iter().map(|(key, _)| key.clone()).collect();
+                let column_validation = ColumnValidation::SpecifiedColumns(
+                    &column_defs,
+                    columns_to_update,
+                    updated_keys,
+                );
                 let rows = rows.iter().filter_map(|(_, row)| match row {
                     Row::Vec { values, .. } => Some(values.as_slice()),
                     Row::Map(_) => None,
                 });
 
-                validate_unique(storage, table_name, column_validation, rows).await?;
+                validate_unique(
+                    storage,
+                    table_name,
+                    column_validation,
+                    composite_primary_key.as_deref(),
+                    rows,
+                )
+                .await?;
             }
 
             let num_rows = rows.len();
+            let returned = match returning.is_empty() {
+                true => None,
+                false => {
+                    let rows = rows.iter().map(|(_, row)| row.clone()).collect();
+
+                    Some(project_returning(storage, table_name, returning, rows).await?)
+                }
+            };
             let rows = rows
                 .into_iter()
                 .map(|(key, row)| (key, row.into()))
                 .collect();
 
-            storage
-                .insert_data(table_name, rows)
-                .await
-                .map(|_| Payload::Update(num_rows))
+            storage.insert_data(table_name, rows).await?;
+
+            Ok(returned.unwrap_or(Payload::Update(num_rows)))
         }
         Statement::Delete {
             table_name,
             selection,
-        } => delete(storage, table_name, selection).await,
+            returning,
+        } => delete(storage, table_name, selection, returning).await,
+        Statement::Truncate { table_names } => truncate(storage, table_names).await,
 
         //- Selection
         Statement::Query(query) => {
@@ -313,6 +354,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: Some(Expr::BinaryOp {
                         left: Box::new(Expr::Identifier("TABLE_NAME".to_owned())),
                         op: BinaryOperator::Eq,
@@ -360,6 +402,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                             },
                             joins: Vec::new(),
                         },
+                        without_from: false,
                         selection: None,
                         group_by: Vec::new(),
                         having: None,
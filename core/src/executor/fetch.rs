@@ -1,5 +1,9 @@
 use {
-    super::{context::RowContext, evaluate::evaluate_stateless, filter::check_expr},
+    super::{
+        context::RowContext,
+        evaluate::{compile_like_patterns, evaluate_stateless},
+        filter::check_expr,
+    },
     crate::{
         ast::{
             ColumnDef, ColumnUniqueOption, Dictionary, Expr, IndexItem, Join, Query, Select,
@@ -49,6 +53,7 @@ pub async fn fetch<'a, T: GStore>(
     where_clause: Option<&'a Expr>,
 ) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
     let columns = columns.unwrap_or_else(|| Arc::from([]));
+    let like_patterns = where_clause.map(compile_like_patterns).map(Arc::new);
     let rows = storage
         .scan_data(table_name)
         .await?
@@ -61,6 +66,8 @@ pub async fn fetch<'a, T: GStore>(
                 DataRow::Map(values) => Row::Map(values),
             };
 
+            let like_patterns = like_patterns.as_ref().map(Arc::clone);
+
             async move {
                 let expr = match where_clause {
                     None => {
@@ -71,7 +78,7 @@ pub async fn fetch<'a, T: GStore>(
 
                 let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
 
-                check_expr(storage, Some(Arc::new(context)), None, expr)
+                check_expr(storage, Some(Arc::new(context)), None, like_patterns, expr)
                     .await
                     .map(|pass| pass.then_some((key, row)))
             }
@@ -525,6 +532,23 @@ where
 
                 Ok(Some(labels))
             }
+            SetExpr::SetOperation { left, .. } => {
+                // a set operation is labeled by its leftmost query
+                let table_factor = TableFactor::Derived {
+                    subquery: Query {
+                        body: left.as_ref().clone(),
+                        order_by: Vec::new(),
+                        limit: None,
+                        offset: None,
+                    },
+                    alias: TableAlias {
+                        name: name.to_owned(),
+                        columns: alias_columns.to_owned(),
+                    },
+                };
+
+                fetch_relation_columns(storage, &table_factor).await
+            }
         },
     }
 }
@@ -1,5 +1,8 @@
 use {
-    super::{context::RowContext, evaluate::evaluate},
+    super::{
+        context::RowContext,
+        evaluate::{LikePatterns, compile_like_patterns, evaluate_with_like_patterns},
+    },
     crate::{
         ast::{Aggregate, Expr},
         data::Value,
@@ -15,6 +18,7 @@ pub struct Filter<'a, T: GStore> {
     where_clause: Option<&'a Expr>,
     context: Option<Arc<RowContext<'a>>>,
     aggregated: Option<Arc<HashMap<&'a Aggregate, Value>>>,
+    like_patterns: Option<Arc<LikePatterns<'a>>>,
 }
 
 impl<'a, T: GStore> Filter<'a, T> {
@@ -24,11 +28,14 @@ impl<'a, T: GStore> Filter<'a, T> {
         context: Option<Arc<RowContext<'a>>>,
         aggregated: Option<Arc<HashMap<&'a Aggregate, Value>>>,
     ) -> Self {
+        let like_patterns = where_clause.map(compile_like_patterns).map(Arc::new);
+
         Self {
             storage,
             where_clause,
             context,
             aggregated,
+            like_patterns,
         }
     }
 
@@ -43,21 +50,28 @@ impl<'a, T: GStore> Filter<'a, T> {
                 };
                 let context = Some(context);
                 let aggregated = self.aggregated.as_ref().map(Arc::clone);
+                let like_patterns = self.like_patterns.as_ref().map(Arc::clone);
 
-                check_expr(self.storage, context, aggregated, expr).await
+                check_expr(self.storage, context, aggregated, like_patterns, expr).await
             }
             None => Ok(true),
         }
     }
 }
 
-pub async fn check_expr<'a, T: GStore>(
+pub async fn check_expr<'a, 'b, 'c, T>(
     storage: &'a T,
-    context: Option<Arc<RowContext<'a>>>,
-    aggregated: Option<Arc<HashMap<&'a Aggregate, Value>>>,
+    context: Option<Arc<RowContext<'b>>>,
+    aggregated: Option<Arc<HashMap<&'c Aggregate, Value>>>,
+    like_patterns: Option<Arc<LikePatterns<'c>>>,
     expr: &'a Expr,
-) -> Result<bool> {
-    evaluate(storage, context, aggregated, expr)
+) -> Result<bool>
+where
+    'b: 'a,
+    'c: 'a,
+    T: GStore,
+{
+    evaluate_with_like_patterns(storage, context, aggregated, like_patterns, expr)
         .await
         .map(|evaluated| {
             if evaluated.is_null() {
@@ -1,18 +1,34 @@
 use {
     super::{
+        Payload,
+        context::RowContext,
+        filter::check_expr,
+        returning::project_returning,
         select::select,
-        validate::{ColumnValidation, validate_unique},
+        update::Update,
+        validate::{
+            ColumnValidation, ValidateError, name_type_mismatch, try_into_column_value,
+            validate_not_null, validate_unique,
+        },
     },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, ForeignKey, Query, SetExpr, Values},
+        ast::{
+            ColumnDef, ColumnUniqueOption, Expr, ForeignKey, OnConflict, OnConflictAction, Query,
+            SelectItem, SetExpr, Values,
+        },
         data::{Key, Row, Schema, Value},
         executor::{evaluate::evaluate_stateless, limit::Limit},
-        result::Result,
+        result::{Error, Result},
         store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{fmt::Debug, sync::Arc},
+    std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+        sync::Arc,
+    },
     thiserror::Error as ThisError,
 };
 
@@ -27,11 +43,11 @@ pub enum InsertError {
     #[error("wrong column name: {0}")]
     WrongColumnName(String),
 
-    #[error("column and values not matched")]
-    ColumnAndValuesNotMatched,
+    #[error("column and values not matched: {expected} columns listed, {found} values given")]
+    ColumnAndValuesNotMatched { expected: usize, found: usize },
 
-    #[error("literals have more values than target columns")]
-    TooManyValues,
+    #[error("literals have more values than target columns: {expected} columns, {found} values")]
+    TooManyValues { expected: usize, found: usize },
 
     #[error("only single value accepted for schemaless row insert")]
     OnlySingleValueAcceptedForSchemalessRow,
@@ -50,8 +66,17 @@ pub enum InsertError {
 
     #[error("unreachable referencing column name: {0}")]
     ConflictReferencingColumnName(String),
+
+    #[error("ON CONFLICT without a target requires a primary key: {0}")]
+    OnConflictWithoutPrimaryKey(String),
+
+    #[error("ON CONFLICT target must be the primary key or a unique column: {0}")]
+    ConflictTargetNotUnique(String),
 }
 
+/// Table alias of the row proposed for insertion in `ON CONFLICT DO UPDATE`
+const EXCLUDED: &str = "excluded";
+
 enum RowsData {
     Append(Vec<DataRow>),
     Insert(Vec<(Key, DataRow)>),
@@ -62,16 +87,30 @@ pub async fn insert<T: GStore + GStoreMut>(
     table_name: &str,
     columns: &[String],
     source: &Query,
-) -> Result<usize> {
+    on_conflict: Option<&OnConflict>,
+    returning: &[SelectItem],
+) -> Result<Payload> {
+    let schema = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
+
+    if let Some(on_conflict) = on_conflict {
+        return upsert(storage, schema, columns, source, on_conflict, returning).await;
+    }
+
     let Schema {
         column_defs,
         foreign_keys,
+        composite_primary_key,
         ..
-    } = storage
-        .fetch_schema(table_name)
-        .await?
-        .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
+    } = schema;
 
+    let labels: Arc<[String]> = column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.to_owned())
+        .collect();
     let rows = match column_defs {
         Some(column_defs) => {
             fetch_vec_rows(
@@ -81,30 +120,363 @@ pub async fn insert<T: GStore + GStoreMut>(
                 columns,
                 source,
                 foreign_keys,
+                composite_primary_key,
             )
             .await
         }
         None => fetch_map_rows(storage, source).await.map(RowsData::Append),
     }?;
 
-    match rows {
+    let returned = match returning.is_empty() {
+        true => None,
+        false => {
+            let data_rows = match &rows {
+                RowsData::Append(rows) => rows.iter().collect::<Vec<_>>(),
+                RowsData::Insert(rows) => rows.iter().map(|(_, row)| row).collect(),
+            };
+            let rows = data_rows
+                .into_iter()
+                .map(|data_row| match data_row.clone() {
+                    DataRow::Vec(values) => Row::Vec {
+                        columns: Arc::clone(&labels),
+                        values,
+                    },
+                    DataRow::Map(values) => Row::Map(values),
+                })
+                .collect();
+
+            Some(project_returning(storage, table_name, returning, rows).await?)
+        }
+    };
+
+    let num_rows = match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
 
-            storage
-                .append_data(table_name, rows)
-                .await
-                .map(|_| num_rows)
+            storage.append_data(table_name, rows).await?;
+
+            num_rows
         }
         RowsData::Insert(rows) => {
             let num_rows = rows.len();
 
-            storage
-                .insert_data(table_name, rows)
-                .await
-                .map(|_| num_rows)
+            storage.insert_data(table_name, rows).await?;
+
+            num_rows
+        }
+    };
+
+    Ok(returned.unwrap_or(Payload::Insert(num_rows)))
+}
+
+/// Inserts the rows of `source` like [`insert`], except for the rows conflicting with an
+/// existing row on the `ON CONFLICT` target, which are skipped or used to update the existing
+/// row by the `ON CONFLICT` action.
+async fn upsert<T: GStore + GStoreMut>(
+    storage: &mut T,
+    schema: Schema,
+    columns: &[String],
+    source: &Query,
+    on_conflict: &OnConflict,
+    returning: &[SelectItem],
+) -> Result<Payload> {
+    let Schema {
+        table_name,
+        column_defs,
+        foreign_keys,
+        composite_primary_key,
+        ..
+    } = schema;
+    let OnConflict { target, action } = on_conflict;
+
+    let column_defs: Arc<[ColumnDef]> = column_defs
+        .ok_or_else(|| InsertError::OnConflictWithoutPrimaryKey(table_name.clone()))?
+        .into();
+    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
+        unique == &Some(ColumnUniqueOption { is_primary: true })
+    });
+    let conflict_columns =
+        find_conflict_columns(&column_defs, composite_primary_key.as_deref(), target).ok_or_else(
+            || match target.is_empty() {
+                true => InsertError::OnConflictWithoutPrimaryKey(table_name.clone()),
+                false => InsertError::ConflictTargetNotUnique(target.join(", ")),
+            },
+        )?;
+    // rows are stored by their single column primary key, otherwise the existing rows are
+    // looked up by scanning the table
+    let by_primary_key = primary_key.is_some_and(|i| conflict_columns == [i]);
+
+    let labels: Arc<[String]> = column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect();
+    let update = match action {
+        OnConflictAction::DoNothing => None,
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => {
+            let update = Update::new(&*storage, &table_name, assignments, Some(&column_defs))?;
+
+            Some((update, selection.as_ref()))
+        }
+    };
+
+    let rows = fetch_vec_values(&*storage, &table_name, &column_defs, columns, source).await?;
+    let mut existing_rows = match by_primary_key {
+        true => HashMap::new(),
+        false => {
+            let stored = storage
+                .scan_data(&table_name)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let mut existing_rows = HashMap::new();
+            for (key, data_row) in stored {
+                let DataRow::Vec(values) = &data_row else {
+                    return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+                };
+                let conflict_key = find_conflict_key(values, &conflict_columns)?;
+
+                if !conflict_key.contains(&Key::None) {
+                    existing_rows.insert(conflict_key, (key, data_row));
+                }
+            }
+
+            existing_rows
+        }
+    };
+    let mut proposed_keys = HashSet::new();
+    // inserted and updated rows in the order of the source, which RETURNING follows
+    let mut upserted = Vec::new();
+    let mut replaced_keys = Vec::new();
+
+    for values in rows {
+        let conflict_key = find_conflict_key(&values, &conflict_columns)?;
+
+        // NULL never conflicts
+        if conflict_key.contains(&Key::None) {
+            upserted.push(Upserted::Inserted(values));
+            continue;
+        }
+
+        if !proposed_keys.insert(conflict_key.clone()) {
+            // the key was proposed by an earlier row of this statement
+            match update {
+                None => continue,
+                Some(_) => {
+                    let error = match (by_primary_key, conflict_columns.as_slice()) {
+                        (true, _) => {
+                            ValidateError::DuplicateEntryOnPrimaryKeyField(conflict_key[0].clone())
+                        }
+                        (false, [i]) => ValidateError::DuplicateEntryOnUniqueField(
+                            values[*i].clone(),
+                            column_defs[*i].name.to_owned(),
+                        ),
+                        (false, _) => ValidateError::DuplicateEntryOnCompositePrimaryKey(
+                            conflict_columns
+                                .iter()
+                                .map(|&i| values[i].clone())
+                                .collect(),
+                            conflict_columns
+                                .iter()
+                                .map(|&i| column_defs[i].name.to_owned())
+                                .collect(),
+                        ),
+                    };
+
+                    return Err(error.into());
+                }
+            }
+        }
+
+        let existing = match by_primary_key {
+            true => {
+                let key = conflict_key[0].clone();
+
+                storage
+                    .fetch_data(&table_name, &key)
+                    .await?
+                    .map(|data_row| (key, data_row))
+            }
+            false => existing_rows.remove(&conflict_key),
+        };
+        let Some((key, existing)) = existing else {
+            upserted.push(Upserted::Inserted(values));
+            continue;
+        };
+        let Some((update, selection)) = &update else {
+            continue;
+        };
+
+        let existing = match existing {
+            DataRow::Vec(values) => Row::Vec {
+                columns: Arc::clone(&labels),
+                values,
+            },
+            DataRow::Map(values) => Row::Map(values),
+        };
+        let excluded = Row::Vec {
+            columns: Arc::clone(&labels),
+            values,
+        };
+        let excluded = Arc::new(RowContext::new(EXCLUDED, Cow::Owned(excluded), None));
+
+        if let Some(selection) = selection {
+            let context = RowContext::new(
+                &table_name,
+                Cow::Borrowed(&existing),
+                Some(Arc::clone(&excluded)),
+            );
+
+            if !check_expr(&*storage, Some(Arc::new(context)), None, None, selection).await? {
+                continue;
+            }
+        }
+
+        let row = update
+            .apply(existing, &foreign_keys, Some(excluded))
+            .await?;
+
+        upserted.push(Upserted::Updated(key, row.try_into_vec()?));
+        replaced_keys.push(conflict_key);
+    }
+
+    // inserted and updated rows are checked in a single pass, so they can't conflict with
+    // each other, while the stored values of the updated rows are left out
+    let replaced_keys = replaced_keys.into_iter().collect();
+    validate_unique(
+        &*storage,
+        &table_name,
+        ColumnValidation::Upsert(&column_defs, &conflict_columns, replaced_keys),
+        composite_primary_key.as_deref(),
+        upserted.iter().map(Upserted::values),
+    )
+    .await?;
+
+    let inserted = upserted.iter().filter_map(|upserted| match upserted {
+        Upserted::Inserted(values) => Some(values.as_slice()),
+        Upserted::Updated(..) => None,
+    });
+    validate_foreign_key(&*storage, &column_defs, foreign_keys, inserted).await?;
+
+    let returned = match returning.is_empty() {
+        true => None,
+        false => {
+            let rows = upserted
+                .iter()
+                .map(|upserted| Row::Vec {
+                    columns: Arc::clone(&labels),
+                    values: upserted.values().to_vec(),
+                })
+                .collect();
+
+            Some(project_returning(&*storage, &table_name, returning, rows).await?)
+        }
+    };
+
+    let mut inserted = Vec::new();
+    let mut updated = Vec::new();
+    for upserted in upserted {
+        match upserted {
+            Upserted::Inserted(values) => inserted.push(values),
+            // updated rows keep the key they are stored by
+            Upserted::Updated(key, values) => updated.push((key, values.into())),
         }
     }
+
+    let (num_inserted, num_updated) = (inserted.len(), updated.len());
+
+    match primary_key {
+        Some(i) => {
+            let rows = inserted
+                .into_iter()
+                .map(|values| Key::try_from(&values[i]).map(|key| (key, values.into())))
+                .chain(updated.into_iter().map(Ok))
+                .collect::<Result<Vec<_>>>()?;
+
+            storage.insert_data(&table_name, rows).await?;
+        }
+        None => {
+            let inserted = inserted.into_iter().map(Into::into).collect();
+
+            storage.append_data(&table_name, inserted).await?;
+            storage.insert_data(&table_name, updated).await?;
+        }
+    }
+
+    Ok(returned.unwrap_or(Payload::Upsert {
+        inserted: num_inserted,
+        updated: num_updated,
+    }))
+}
+
+/// Row of an upsert, either inserted or updated in place of the stored row of the key
+enum Upserted {
+    Inserted(Vec<Value>),
+    Updated(Key, Vec<Value>),
+}
+
+impl Upserted {
+    fn values(&self) -> &[Value] {
+        match self {
+            Upserted::Inserted(values) | Upserted::Updated(_, values) => values,
+        }
+    }
+}
+
+/// Resolves the `ON CONFLICT` target to the column indexes of the primary key, a single
+/// column or a composite one in any order, or of a single unique column.
+/// No target stands for the primary key.
+fn find_conflict_columns(
+    column_defs: &[ColumnDef],
+    composite_primary_key: Option<&[String]>,
+    target: &[String],
+) -> Option<Vec<usize>> {
+    let position = |column_name: &String| {
+        column_defs
+            .iter()
+            .position(|column_def| &column_def.name == column_name)
+    };
+    let primary_key = match composite_primary_key {
+        Some(column_names) => column_names.iter().map(position).collect(),
+        None => column_defs
+            .iter()
+            .position(|ColumnDef { unique, .. }| {
+                unique == &Some(ColumnUniqueOption { is_primary: true })
+            })
+            .map(|i| vec![i]),
+    };
+
+    if target.is_empty() {
+        return primary_key;
+    }
+
+    let target = target.iter().map(position).collect::<Option<Vec<_>>>()?;
+
+    match (primary_key, target.as_slice()) {
+        (Some(primary_key), _)
+            if primary_key.len() == target.len()
+                && primary_key.iter().all(|i| target.contains(i)) =>
+        {
+            Some(primary_key)
+        }
+        (_, [i]) if column_defs[*i].unique.is_some() => Some(vec![*i]),
+        _ => None,
+    }
+}
+
+fn find_conflict_key(values: &[Value], conflict_columns: &[usize]) -> Result<Vec<Key>> {
+    conflict_columns
+        .iter()
+        .map(|&i| {
+            values
+                .get(i)
+                .ok_or_else(|| ValidateError::ConflictOnStorageColumnIndex(i).into())
+                .and_then(Key::try_from)
+        })
+        .collect()
 }
 
 async fn fetch_vec_rows<T: GStore>(
@@ -114,15 +486,61 @@ async fn fetch_vec_rows<T: GStore>(
     columns: &[String],
     source: &Query,
     foreign_keys: Vec<ForeignKey>,
+    composite_primary_key: Option<Vec<String>>,
 ) -> Result<RowsData> {
+    let column_defs = Arc::from(column_defs);
+    let column_validation = ColumnValidation::All(&column_defs);
+    let rows = fetch_vec_values(storage, table_name, &column_defs, columns, source).await?;
+
+    validate_unique(
+        storage,
+        table_name,
+        column_validation,
+        composite_primary_key.as_deref(),
+        rows.iter().map(|values| values.as_slice()),
+    )
+    .await?;
+
+    validate_foreign_key(
+        storage,
+        &column_defs,
+        foreign_keys,
+        rows.iter().map(|values| values.as_slice()),
+    )
+    .await?;
+
+    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
+        unique == &Some(ColumnUniqueOption { is_primary: true })
+    });
+
+    match primary_key {
+        Some(i) => rows
+            .into_iter()
+            .filter_map(|values| {
+                values
+                    .get(i)
+                    .map(Key::try_from)
+                    .map(|result| result.map(|key| (key, values.into())))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(RowsData::Insert),
+        None => Ok(RowsData::Append(rows.into_iter().map(Into::into).collect())),
+    }
+}
+
+async fn fetch_vec_values<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &Arc<[ColumnDef]>,
+    columns: &[String],
+    source: &Query,
+) -> Result<Vec<Vec<Value>>> {
     let labels = Arc::from(
         column_defs
             .iter()
             .map(|column_def| column_def.name.to_owned())
             .collect::<Vec<_>>(),
     );
-    let column_defs = Arc::from(column_defs);
-    let column_validation = ColumnValidation::All(&column_defs);
 
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
@@ -130,17 +548,28 @@ async fn fetch_vec_rows<T: GStore>(
         Select(I2),
     }
 
-    let rows = match &source.body {
+    match &source.body {
         SetExpr::Values(Values(values_list)) => {
             let limit = Limit::new(source.limit.as_ref(), source.offset.as_ref()).await?;
-            let rows = stream::iter(values_list).then(|values| {
-                let column_defs = Arc::clone(&column_defs);
+            let multi_row = values_list.len() > 1;
+            let rows = stream::iter(values_list.iter().enumerate()).then(move |(index, values)| {
+                let column_defs = Arc::clone(column_defs);
                 let labels = Arc::clone(&labels);
 
                 async move {
+                    let values = fill_values(table_name, &column_defs, columns, values)
+                        .await
+                        .map_err(|error| match multi_row {
+                            true => Error::Tuple {
+                                index,
+                                error: Box::new(error),
+                            },
+                            false => error,
+                        })?;
+
                     Ok(Row::Vec {
                         columns: labels,
-                        values: fill_values(&column_defs, columns, values).await?,
+                        values,
                     })
                 }
             });
@@ -149,67 +578,29 @@ async fn fetch_vec_rows<T: GStore>(
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
-                let values = row?.try_into_vec()?;
-
-                column_defs
-                    .iter()
-                    .zip(values.iter())
-                    .try_for_each(|(column_def, value)| {
-                        let ColumnDef {
-                            data_type,
-                            nullable,
-                            ..
-                        } = column_def;
-
-                        value.validate_type(data_type)?;
-                        value.validate_null(*nullable)
-                    })?;
-
-                Ok(values)
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
+            let rows = select(storage, source, None).await?.and_then(|row| {
+                let column_defs = Arc::clone(column_defs);
+
+                async move {
+                    let values = row.try_into_vec()?;
+
+                    fill_selected_values(table_name, &column_defs, columns, values).await
+                }
             });
 
             Rows::Select(rows)
         }
     }
-    .try_collect::<Vec<Vec<Value>>>()
-    .await?;
-
-    validate_unique(
-        storage,
-        table_name,
-        column_validation,
-        rows.iter().map(|values| values.as_slice()),
-    )
-    .await?;
-
-    validate_foreign_key(storage, &column_defs, foreign_keys, &rows).await?;
-
-    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
-        unique == &Some(ColumnUniqueOption { is_primary: true })
-    });
-
-    match primary_key {
-        Some(i) => rows
-            .into_iter()
-            .filter_map(|values| {
-                values
-                    .get(i)
-                    .map(Key::try_from)
-                    .map(|result| result.map(|key| (key, values.into())))
-            })
-            .collect::<Result<Vec<_>>>()
-            .map(RowsData::Insert),
-        None => Ok(RowsData::Append(rows.into_iter().map(Into::into).collect())),
-    }
+    .try_collect()
+    .await
 }
 
 async fn validate_foreign_key<T: GStore>(
     storage: &T,
     column_defs: &Arc<[ColumnDef]>,
     foreign_keys: Vec<ForeignKey>,
-    rows: &[Vec<Value>],
+    rows: impl Iterator<Item = &[Value]> + Clone,
 ) -> Result<()> {
     for foreign_key in foreign_keys {
         let ForeignKey {
@@ -227,7 +618,7 @@ async fn validate_foreign_key<T: GStore>(
                 InsertError::ConflictReferencingColumnName(referencing_column_name.to_owned())
             })?;
 
-        for row in rows.iter() {
+        for row in rows.clone() {
             let value =
                 row.get(target_index.0)
                     .ok_or(InsertError::ConflictReferencingColumnName(
@@ -282,7 +673,7 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
             let rows = select(storage, source, None).await?.map(|row| {
                 let row = row?;
 
@@ -306,15 +697,25 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
     Ok(rows)
 }
 
-async fn fill_values(
+/// Validates the target `columns` of an INSERT given `num_values` values for each row,
+/// and finds the position of the value for each column of the table.
+fn find_value_indexes(
     column_defs: &[ColumnDef],
     columns: &[String],
-    values: &[Expr],
-) -> Result<Vec<Value>> {
-    if !columns.is_empty() && values.len() != columns.len() {
-        return Err(InsertError::ColumnAndValuesNotMatched.into());
-    } else if values.len() > column_defs.len() {
-        return Err(InsertError::TooManyValues.into());
+    num_values: usize,
+) -> Result<Vec<Option<usize>>> {
+    if !columns.is_empty() && num_values != columns.len() {
+        return Err(InsertError::ColumnAndValuesNotMatched {
+            expected: columns.len(),
+            found: num_values,
+        }
+        .into());
+    } else if num_values > column_defs.len() {
+        return Err(InsertError::TooManyValues {
+            expected: column_defs.len(),
+            found: num_values,
+        }
+        .into());
     }
 
     if let Some(wrong_column_name) = columns.iter().find(|column_name| {
@@ -325,45 +726,53 @@ async fn fill_values(
         return Err(InsertError::WrongColumnName(wrong_column_name.to_owned()).into());
     }
 
-    #[derive(iter_enum::Iterator)]
-    enum Columns<I1, I2> {
-        All(I1),
-        Specified(I2),
-    }
+    let indexes = column_defs
+        .iter()
+        .enumerate()
+        .map(|(i, ColumnDef { name, .. })| match columns.is_empty() {
+            true => (i < num_values).then_some(i),
+            false => columns.iter().position(|column_name| column_name == name),
+        })
+        .collect();
 
-    let columns = if columns.is_empty() {
-        Columns::All(column_defs.iter().map(|ColumnDef { name, .. }| name))
-    } else {
-        Columns::Specified(columns.iter())
-    };
+    Ok(indexes)
+}
 
-    let column_name_value_list = columns.zip(values.iter()).collect::<Vec<(_, _)>>();
-
-    let values = stream::iter(column_defs)
-        .then(|column_def| {
-            let column_name_value_list = &column_name_value_list;
-
-            async move {
-                let ColumnDef {
-                    name: def_name,
-                    data_type,
-                    nullable,
-                    ..
-                } = column_def;
-
-                let value = column_name_value_list
-                    .iter()
-                    .find(|(name, _)| name == &def_name)
-                    .map(|(_, value)| value);
-
-                match (value, &column_def.default, nullable) {
-                    (Some(&expr), _, _) | (None, Some(expr), _) => evaluate_stateless(None, expr)
-                        .await?
-                        .try_into_value(data_type, *nullable),
-                    (None, None, true) => Ok(Value::Null),
-                    (None, None, false) => {
-                        Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into())
-                    }
+async fn fill_values(
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    values: &[Expr],
+) -> Result<Vec<Value>> {
+    let indexes = find_value_indexes(column_defs, columns, values.len())?;
+
+    let values = stream::iter(column_defs.iter().zip(indexes))
+        .then(|(column_def, index)| async move {
+            let ColumnDef {
+                name: def_name,
+                data_type,
+                nullable,
+                ..
+            } = column_def;
+
+            let value = index.map(|i| &values[i]);
+
+            let name_type_mismatch = |error| name_type_mismatch(table_name, column_def, error);
+
+            match (value, &column_def.default, nullable) {
+                (Some(expr), _, _) | (None, Some(expr), _) => {
+                    let evaluated = evaluate_stateless(None, expr).await?;
+                    let value = try_into_column_value(table_name, column_def, evaluated)?;
+                    validate_not_null(table_name, column_def, &value)?;
+
+                    value
+                        .validate_type(data_type)
+                        .map(|()| value)
+                        .map_err(name_type_mismatch)
+                }
+                (None, None, true) => Ok(Value::Null),
+                (None, None, false) => {
+                    Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into())
                 }
             }
         })
@@ -372,3 +781,49 @@ async fn fill_values(
 
     Ok(values)
 }
+
+async fn fill_selected_values(
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    mut values: Vec<Value>,
+) -> Result<Vec<Value>> {
+    let indexes = find_value_indexes(column_defs, columns, values.len())?;
+    let mut filled = Vec::with_capacity(column_defs.len());
+
+    for (column_def, index) in column_defs.iter().zip(indexes) {
+        let ColumnDef {
+            name: def_name,
+            data_type,
+            nullable,
+            ..
+        } = column_def;
+
+        let name_type_mismatch = |error| name_type_mismatch(table_name, column_def, error);
+        let value = index.map(|i| std::mem::replace(&mut values[i], Value::Null));
+        let value = match (value, &column_def.default, nullable) {
+            (Some(value), _, _) => {
+                value.validate_type(data_type).map_err(name_type_mismatch)?;
+                validate_not_null(table_name, column_def, &value)?;
+
+                value
+            }
+            (None, Some(expr), _) => {
+                let evaluated = evaluate_stateless(None, expr).await?;
+                let value = try_into_column_value(table_name, column_def, evaluated)?;
+                validate_not_null(table_name, column_def, &value)?;
+                value.validate_type(data_type).map_err(name_type_mismatch)?;
+
+                value
+            }
+            (None, None, true) => Value::Null,
+            (None, None, false) => {
+                return Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into());
+            }
+        };
+
+        filled.push(value);
+    }
+
+    Ok(filled)
+}
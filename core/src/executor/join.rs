@@ -26,6 +26,7 @@ use {
 
 pub struct Join<'a, T: GStore> {
     storage: &'a T,
+    relation: &'a TableFactor,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Arc<RowContext<'a>>>,
 }
@@ -36,11 +37,13 @@ type Joined<'a> = Pin<Box<dyn Stream<Item = Result<JoinItem<'a>>> + Send + 'a>>;
 impl<'a, T: GStore> Join<'a, T> {
     pub fn new(
         storage: &'a T,
+        relation: &'a TableFactor,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Arc<RowContext<'a>>>,
     ) -> Self {
         Self {
             storage,
+            relation,
             join_clauses,
             filter_context,
         }
@@ -52,20 +55,35 @@ impl<'a, T: GStore> Join<'a, T> {
     ) -> Result<Joined<'a>> {
         let init_rows: Joined = Box::pin(rows.map(|row| row.map(Arc::new)));
 
-        stream::iter(self.join_clauses)
+        stream::iter(self.join_clauses.iter().enumerate())
             .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
+            .try_fold(init_rows, |rows, (i, join_clause)| {
                 let filter_context = self.filter_context.as_ref().map(Arc::clone);
+                let left_relations = (self.relation, &self.join_clauses[..i]);
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
+                async move {
+                    join(
+                        self.storage,
+                        filter_context,
+                        left_relations,
+                        join_clause,
+                        rows,
+                    )
+                    .await
+                }
             })
             .await
     }
 }
 
+/// Relations joined before the current join clause, used to build the NULL
+/// padded left side of unmatched right rows.
+type LeftRelations<'a> = (&'a TableFactor, &'a [AstJoin]);
+
 async fn join<'a, T: GStore>(
     storage: &'a T,
     filter_context: Option<Arc<RowContext<'a>>>,
+    left_relations: LeftRelations<'a>,
     ast_join: &'a AstJoin,
     left_rows: impl Stream<Item = Result<JoinItem<'a>>> + Send + 'a,
 ) -> Result<Joined<'a>> {
@@ -75,6 +93,39 @@ async fn join<'a, T: GStore>(
         join_executor,
     } = ast_join;
 
+    let (join_operator, join_constraint) = match join_operator {
+        AstJoinOperator::Inner(join_constraint) => (JoinOperator::Inner, join_constraint),
+        AstJoinOperator::LeftOuter(join_constraint) => (JoinOperator::LeftOuter, join_constraint),
+        AstJoinOperator::RightOuter(join_constraint) => {
+            return join_right(
+                storage,
+                filter_context,
+                left_relations,
+                relation,
+                join_constraint,
+                false,
+                left_rows,
+            )
+            .await;
+        }
+        AstJoinOperator::FullOuter(join_constraint) => {
+            return join_right(
+                storage,
+                filter_context,
+                left_relations,
+                relation,
+                join_constraint,
+                true,
+                left_rows,
+            )
+            .await;
+        }
+    };
+    let where_clause = match join_constraint {
+        JoinConstraint::On(where_clause) => Some(where_clause),
+        JoinConstraint::None => None,
+    };
+
     let table_alias = get_alias(relation);
     let join_executor = JoinExecutor::new(
         storage,
@@ -85,48 +136,32 @@ async fn join<'a, T: GStore>(
     .await
     .map(Arc::new)?;
 
-    let (join_operator, where_clause) = match join_operator {
-        AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
-        AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::Inner, Some(where_clause))
-        }
-        AstJoinOperator::LeftOuter(JoinConstraint::None) => (JoinOperator::LeftOuter, None),
-        AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::LeftOuter, Some(where_clause))
-        }
-    };
-
     let columns = fetch_relation_columns(storage, relation)
         .await?
         .map(Arc::from);
     let rows = left_rows.and_then(move |project_context| {
-        let init_context = {
-            let init_row = match columns.as_ref() {
-                Some(columns) => Row::Vec {
-                    columns: Arc::clone(columns),
-                    values: columns.iter().map(|_| Value::Null).collect(),
-                },
-                None => Row::Map(BTreeMap::new()),
-            };
-
-            Arc::new(RowContext::new(
-                table_alias,
-                Cow::Owned(init_row),
-                Some(Arc::clone(&project_context)),
-            ))
-        };
-        let filter_context = filter_context.as_ref().map(Arc::clone);
+        let init_context = Arc::new(RowContext::new(
+            table_alias,
+            Cow::Owned(null_row(columns.as_ref())),
+            Some(Arc::clone(&project_context)),
+        ));
+        let outer_context = filter_context.as_ref().map(Arc::clone);
         let join_executor = Arc::clone(&join_executor);
 
         async move {
-            let filter_context = match filter_context {
-                Some(filter_context) => Arc::new(RowContext::concat(
+            let filter_context = match &outer_context {
+                Some(outer_context) => Arc::new(RowContext::concat(
                     Arc::clone(&project_context),
-                    Arc::clone(&filter_context),
+                    Arc::clone(outer_context),
                 )),
                 None => Arc::clone(&project_context),
             };
             let filter_context = Some(filter_context);
+            // a derived table is not LATERAL, so it can't see the rows it is joined with
+            let relation_context = match relation {
+                TableFactor::Derived { .. } => &outer_context,
+                _ => &filter_context,
+            };
 
             #[derive(futures_enum::Stream)]
             enum Rows<I1, I2, I3> {
@@ -136,7 +171,7 @@ async fn join<'a, T: GStore>(
             }
             let rows = match join_executor.as_ref() {
                 JoinExecutor::NestedLoop => {
-                    let rows = fetch_relation_rows(storage, relation, &filter_context)
+                    let rows = fetch_relation_rows(storage, relation, relation_context)
                         .await?
                         .and_then(|row| future::ok(Cow::Owned(row)))
                         .try_filter_map(move |row| {
@@ -211,6 +246,127 @@ async fn join<'a, T: GStore>(
     Ok(Box::pin(rows.try_flatten()))
 }
 
+/// RIGHT and FULL OUTER JOIN, right rows without any matching left row are
+/// emitted last with NULL left columns. FULL OUTER JOIN also keeps unmatched
+/// left rows with NULL right columns.
+async fn join_right<'a, T: GStore>(
+    storage: &'a T,
+    filter_context: Option<Arc<RowContext<'a>>>,
+    left_relations: LeftRelations<'a>,
+    relation: &'a TableFactor,
+    join_constraint: &'a JoinConstraint,
+    keep_unmatched_left: bool,
+    left_rows: impl Stream<Item = Result<JoinItem<'a>>> + Send + 'a,
+) -> Result<Joined<'a>> {
+    let table_alias = get_alias(relation);
+    let where_clause = match join_constraint {
+        JoinConstraint::On(where_clause) => Some(where_clause),
+        JoinConstraint::None => None,
+    };
+
+    let columns = fetch_relation_columns(storage, relation)
+        .await?
+        .map(Arc::from);
+    let right_rows = fetch_relation_rows(storage, relation, &filter_context)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let left_rows = left_rows.try_collect::<Vec<_>>().await?;
+
+    let mut matched = vec![false; right_rows.len()];
+    let mut rows = Vec::new();
+    for project_context in left_rows {
+        let filter_context = match filter_context.as_ref() {
+            Some(filter_context) => Arc::new(RowContext::concat(
+                Arc::clone(&project_context),
+                Arc::clone(filter_context),
+            )),
+            None => Arc::clone(&project_context),
+        };
+
+        let mut found = false;
+        for (i, row) in right_rows.iter().enumerate() {
+            let row = check_where_clause(
+                storage,
+                table_alias,
+                Some(Arc::clone(&filter_context)),
+                Some(Arc::clone(&project_context)),
+                where_clause,
+                Cow::Borrowed(row),
+            )
+            .await?;
+
+            if let Some(row) = row {
+                matched[i] = true;
+                found = true;
+                rows.push(row);
+            }
+        }
+
+        if !found && keep_unmatched_left {
+            rows.push(Arc::new(RowContext::new(
+                table_alias,
+                Cow::Owned(null_row(columns.as_ref())),
+                Some(project_context),
+            )));
+        }
+    }
+
+    let null_context = fetch_null_context(storage, left_relations).await?;
+    let unmatched = right_rows
+        .into_iter()
+        .zip(matched)
+        .filter_map(|(row, matched)| (!matched).then_some(row))
+        .map(|row| {
+            Arc::new(RowContext::new(
+                table_alias,
+                Cow::Owned(row),
+                Some(Arc::clone(&null_context)),
+            ))
+        });
+    rows.extend(unmatched);
+
+    Ok(Box::pin(stream::iter(rows.into_iter().map(Ok))))
+}
+
+async fn fetch_null_context<'a, T: GStore>(
+    storage: &'a T,
+    (relation, joins): LeftRelations<'a>,
+) -> Result<JoinItem<'a>> {
+    let columns = fetch_relation_columns(storage, relation)
+        .await?
+        .map(Arc::from);
+    let mut context = Arc::new(RowContext::new(
+        get_alias(relation),
+        Cow::Owned(null_row(columns.as_ref())),
+        None,
+    ));
+
+    for AstJoin { relation, .. } in joins {
+        let columns = fetch_relation_columns(storage, relation)
+            .await?
+            .map(Arc::from);
+
+        context = Arc::new(RowContext::new(
+            get_alias(relation),
+            Cow::Owned(null_row(columns.as_ref())),
+            Some(context),
+        ));
+    }
+
+    Ok(context)
+}
+
+fn null_row(columns: Option<&Arc<[String]>>) -> Row {
+    match columns {
+        Some(columns) => Row::Vec {
+            columns: Arc::clone(columns),
+            values: columns.iter().map(|_| Value::Null).collect(),
+        },
+        None => Row::Map(BTreeMap::new()),
+    }
+}
+
 #[derive(Copy, Clone)]
 enum JoinOperator {
     Inner,
@@ -263,7 +419,7 @@ impl<'a> JoinExecutor<'a> {
                     }
 
                     match where_clause {
-                        Some(expr) => check_expr(storage, Some(filter_context), None, expr)
+                        Some(expr) => check_expr(storage, Some(filter_context), None, None, expr)
                             .await
                             .map(|pass| pass.then_some((hash_key, row))),
                         None => Ok(Some((hash_key, row))),
@@ -293,7 +449,7 @@ async fn check_where_clause<'a, 'b, T: GStore>(
     let filter_context = Some(Arc::new(filter_context));
 
     match where_clause {
-        Some(expr) => check_expr(storage, filter_context, None, expr).await?,
+        Some(expr) => check_expr(storage, filter_context, None, None, expr).await?,
         None => true,
     }
     .then(|| RowContext::new(table_alias, Cow::Owned(row.into_owned()), project_context))
@@ -0,0 +1,59 @@
+use {
+    super::{Payload, context::RowContext, fetch::fetch_labels, select::Project},
+    crate::{
+        ast::{SelectItem, TableFactor},
+        data::Row,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, StreamExt, TryStreamExt},
+    std::{borrow::Cow, sync::Arc},
+};
+
+/// Projects the rows affected by `INSERT`, `UPDATE` or `DELETE` with the items of its
+/// `RETURNING` clause, as if they were selected from the target table.
+///
+/// Callers project before writing, so a failing `RETURNING` leaves the table untouched and
+/// subqueries see the table as it was before the statement.
+pub async fn project_returning<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    items: &[SelectItem],
+    rows: Vec<Row>,
+) -> Result<Payload> {
+    let relation = TableFactor::Table {
+        name: table_name.to_owned(),
+        alias: None,
+        index: None,
+    };
+    let labels = fetch_labels(storage, &relation, &[], items).await?;
+    let columns: Option<Arc<[String]>> = labels.as_deref().map(Arc::from);
+    let project = Project::new(storage, None, items);
+
+    let rows = stream::iter(rows)
+        .then(|row| {
+            let columns = columns.as_ref().map(Arc::clone);
+            let project = &project;
+
+            async move {
+                let context = RowContext::new(table_name, Cow::Owned(row), None);
+
+                project.apply(None, columns, Arc::new(context)).await
+            }
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    match labels {
+        Some(labels) => rows
+            .into_iter()
+            .map(Row::try_into_vec)
+            .collect::<Result<_>>()
+            .map(|rows| Payload::Select { labels, rows }),
+        None => rows
+            .into_iter()
+            .map(Row::try_into_map)
+            .collect::<Result<_>>()
+            .map(Payload::SelectMap),
+    }
+}
@@ -2,8 +2,8 @@ mod error;
 mod project;
 
 pub use error::SelectError;
+pub(super) use project::Project;
 use {
-    self::project::Project,
     super::{
         aggregate,
         context::{AggregateContext, RowContext},
@@ -15,36 +15,32 @@ use {
         sort::Sort,
     },
     crate::{
-        ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
+        ast::{Expr, OrderByExpr, Query, Select, SetExpr, SetOperator, TableWithJoins, Values},
         data::{Key, Row, Value, get_alias},
         result::Result,
         store::GStore,
     },
     async_recursion::async_recursion,
-    futures::stream::{self, Stream, StreamExt, TryStreamExt},
+    futures::{
+        future,
+        stream::{self, Stream, StreamExt, TryStreamExt},
+    },
     std::{
         borrow::Cow,
-        collections::{BTreeMap, HashSet},
+        collections::{BTreeMap, HashMap, HashSet},
         sync::Arc,
     },
     utils::Vector,
 };
 
-fn apply_distinct(rows: Vec<Row>) -> Vec<Row> {
-    let mut seen = HashSet::new();
-
-    rows.into_iter()
-        .filter(|row| {
-            let key = match row {
-                Row::Vec { values, .. } => values.clone(),
-                Row::Map(map) => {
-                    let sorted_map: BTreeMap<_, _> = map.iter().collect();
-                    sorted_map.into_values().cloned().collect()
-                }
-            };
-            seen.insert(key)
-        })
-        .collect()
+fn distinct_key(row: &Row) -> Vec<Value> {
+    match row {
+        Row::Vec { values, .. } => values.clone(),
+        Row::Map(map) => {
+            let sorted_map: BTreeMap<_, _> = map.iter().collect();
+            sorted_map.into_values().cloned().collect()
+        }
+    }
 }
 
 async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<String>)> {
@@ -93,15 +89,15 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     let sorted = stream::iter(rows.into_iter())
         .then(|row| async move {
             stream::iter(order_by)
-                .then(|OrderByExpr { expr, asc }| {
+                .then(|order_by_expr| {
                     let row = Some(&row);
 
                     async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
+                        evaluate_stateless(row.map(Row::as_context), &order_by_expr.expr)
                             .await
                             .and_then(Value::try_from)
                             .and_then(Key::try_from)
-                            .map(|key| (key, *asc))
+                            .map(|key| (key, order_by_expr))
                     }
                 })
                 .try_collect::<Vec<_>>()
@@ -119,6 +115,104 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     Ok(sorted)
 }
 
+/// Runs both sides of a set operation and combines their rows, rows of the right side are
+/// relabeled with the labels of the left side.
+async fn select_set_operation<'a, T>(
+    storage: &'a T,
+    op: SetOperator,
+    all: bool,
+    left: &SetExpr,
+    right: &SetExpr,
+    filter_context: Option<Arc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)>
+where
+    T: GStore,
+{
+    let select_side = |body: &SetExpr| Query {
+        body: body.clone(),
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    };
+
+    let left = select_side(left);
+    let (labels, left_rows) =
+        select_with_labels(storage, &left, filter_context.as_ref().map(Arc::clone)).await?;
+    let left_rows = left_rows.try_collect::<Vec<_>>().await?;
+
+    let right = select_side(right);
+    let (right_labels, right_rows) = select_with_labels(storage, &right, filter_context).await?;
+    let right_rows = right_rows.try_collect::<Vec<_>>().await?;
+
+    if let (Some(left), Some(right)) = (&labels, &right_labels) {
+        if left.len() != right.len() {
+            return Err(SelectError::SetOperationColumnCountMismatch {
+                operator: op,
+                left: left.len(),
+                right: right.len(),
+            }
+            .into());
+        }
+    }
+
+    let columns: Option<Arc<[String]>> = labels.as_deref().map(Arc::from);
+    let right_rows = right_rows.into_iter().map(|row| match (row, &columns) {
+        (Row::Vec { values, .. }, Some(columns)) => Row::Vec {
+            columns: Arc::clone(columns),
+            values,
+        },
+        (row, _) => row,
+    });
+
+    let left_rows = left_rows.into_iter();
+    let rows = match (op, all) {
+        (SetOperator::Union, true) => left_rows.chain(right_rows).collect(),
+        (SetOperator::Union, false) => {
+            let mut seen = HashSet::new();
+
+            left_rows
+                .chain(right_rows)
+                .filter(|row| seen.insert(distinct_key(row)))
+                .collect()
+        }
+        (SetOperator::Intersect | SetOperator::Except, false) => {
+            let intersect = op == SetOperator::Intersect;
+            let right_keys = right_rows
+                .map(|row| distinct_key(&row))
+                .collect::<HashSet<_>>();
+            let mut seen = HashSet::new();
+
+            left_rows
+                .filter(|row| {
+                    let key = distinct_key(row);
+
+                    right_keys.contains(&key) == intersect && seen.insert(key)
+                })
+                .collect()
+        }
+        (SetOperator::Intersect | SetOperator::Except, true) => {
+            let intersect = op == SetOperator::Intersect;
+            let mut right_counts = HashMap::new();
+            for row in right_rows {
+                *right_counts.entry(distinct_key(&row)).or_insert(0_usize) += 1;
+            }
+
+            left_rows
+                .filter(|row| match right_counts.get_mut(&distinct_key(row)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+
+                        intersect
+                    }
+                    _ => !intersect,
+                })
+                .collect()
+        }
+    };
+
+    Ok((labels, rows))
+}
+
 #[async_recursion]
 pub async fn select_with_labels<'a, T>(
     storage: &'a T,
@@ -132,14 +226,16 @@ where
     T: GStore,
 {
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        SetOperation(S3),
     }
 
     let Select {
         distinct,
         from: table_with_joins,
+        without_from: _,
         selection: where_clause,
         projection,
         group_by,
@@ -155,6 +251,21 @@ where
 
             return Ok((Some(labels), Row::Values(rows)));
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let (labels, rows) =
+                select_set_operation(storage, *op, *all, left, right, filter_context).await?;
+            let rows = sort_stateless(rows, &query.order_by).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            return Ok((labels, Row::SetOperation(rows)));
+        }
     };
 
     let TableWithJoins { relation, joins } = &table_with_joins;
@@ -167,7 +278,12 @@ where
             Ok(RowContext::new(alias, Cow::Owned(row), None))
         });
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Arc::clone));
+    let join = Join::new(
+        storage,
+        relation,
+        joins,
+        filter_context.as_ref().map(Arc::clone),
+    );
     let filter = Arc::new(Filter::new(
         storage,
         where_clause.as_ref(),
@@ -231,10 +347,11 @@ where
     let rows = sort.apply(rows, get_alias(relation)).await?;
 
     let rows: Box<dyn Stream<Item = Result<crate::data::Row>> + Unpin + Send> = if *distinct {
-        let all_rows: Vec<crate::data::Row> = rows.try_collect().await?;
-        let unique_rows = apply_distinct(all_rows);
-        let unique_stream = stream::iter(unique_rows.into_iter().map(Ok));
-        Box::new(limit.apply(unique_stream))
+        // keeps the first seen row of each group, LIMIT can stop the stream early
+        let mut seen = HashSet::new();
+        let rows = rows.try_filter(move |row| future::ready(seen.insert(distinct_key(row))));
+
+        Box::new(limit.apply(rows))
     } else {
         Box::new(limit.apply(rows))
     };
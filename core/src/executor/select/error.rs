@@ -1,7 +1,16 @@
-use {serde::Serialize, std::fmt::Debug, thiserror::Error};
+use {crate::ast::SetOperator, serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error(
+        "each {operator} query must have the same number of columns, but got {left} and {right}"
+    )]
+    SetOperationColumnCountMismatch {
+        operator: SetOperator,
+        left: usize,
+        right: usize,
+    },
 }
@@ -77,7 +77,8 @@ impl<'a, T: GStore> Sort<'a, T> {
                 let order_by = self.order_by;
                 let order_by = order_by
                     .iter()
-                    .map(|OrderByExpr { expr, asc }| -> Result<_> {
+                    .map(|order_by_expr| -> Result<_> {
+                        let OrderByExpr { expr, .. } = order_by_expr;
                         let big_decimal = match expr {
                             Expr::Literal(AstLiteral::Number(n)) => Some(n),
                             Expr::UnaryOp {
@@ -102,9 +103,9 @@ impl<'a, T: GStore> Sort<'a, T> {
                                     SortError::ColumnIndexOutOfRange(index).into()
                                 })?;
 
-                                Ok((SortType::Value(value.clone()), *asc))
+                                Ok((SortType::Value(value.clone()), order_by_expr))
                             }
-                            _ => Ok((SortType::Expr(expr), *asc)),
+                            _ => Ok((SortType::Expr(expr), order_by_expr)),
                         }
                     })
                     .collect::<Result<Vec<_>>>();
@@ -126,7 +127,7 @@ impl<'a, T: GStore> Sort<'a, T> {
 
                     let keys = order_by
                         .map(stream::iter)?
-                        .then(|(sort_type, asc)| {
+                        .then(|(sort_type, order_by_expr)| {
                             let context = Some(Arc::clone(&filter_context));
                             let aggregated = aggregated.as_ref().map(Arc::clone);
 
@@ -140,7 +141,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                                     }
                                 }
                                 .try_into()
-                                .map(|key| (key, asc))
+                                .map(|key| (key, order_by_expr))
                             }
                         })
                         .try_collect::<Vec<_>>()
@@ -152,7 +153,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                     Ok((keys, row))
                 }
             })
-            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, Row)>>()
+            .try_collect::<Vec<(Vec<(Key, &OrderByExpr)>, Row)>>()
             .await
             .map(Vector::from)?
             .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
@@ -163,18 +164,39 @@ impl<'a, T: GStore> Sort<'a, T> {
     }
 }
 
-pub fn sort_by(keys_a: &[(Key, Option<bool>)], keys_b: &[(Key, Option<bool>)]) -> Ordering {
+/// Compares rows by their ORDER BY keys, each key paired with the expression it came from.
+///
+/// NULLs sort as the largest value unless `NULLS FIRST` or `NULLS LAST` is given, so they
+/// come last in ascending order and first in descending order.
+pub fn sort_by(keys_a: &[(Key, &OrderByExpr)], keys_b: &[(Key, &OrderByExpr)]) -> Ordering {
     let pairs = keys_a
         .iter()
-        .map(|(a, _)| a)
         .zip(keys_b.iter())
-        .map(|(a, (b, asc))| (a, b, asc.unwrap_or(true)));
-
-    for (key_a, key_b, asc) in pairs {
-        match (key_a.cmp(key_b), asc) {
-            (Ordering::Equal, _) => continue,
-            (ord, true) => return ord,
-            (ord, false) => return ord.reverse(),
+        .map(|((a, order_by_expr), (b, _))| (a, b, order_by_expr));
+
+    for (
+        key_a,
+        key_b,
+        OrderByExpr {
+            asc, nulls_first, ..
+        },
+    ) in pairs
+    {
+        let asc = asc.unwrap_or(true);
+        let nulls_first = nulls_first.unwrap_or(!asc);
+
+        let ord = match (key_a, key_b) {
+            (Key::None, Key::None) => Ordering::Equal,
+            (Key::None, _) if nulls_first => Ordering::Less,
+            (Key::None, _) => Ordering::Greater,
+            (_, Key::None) if nulls_first => Ordering::Greater,
+            (_, Key::None) => Ordering::Less,
+            (key_a, key_b) if asc => key_a.cmp(key_b),
+            (key_a, key_b) => key_a.cmp(key_b).reverse(),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
         }
     }
 
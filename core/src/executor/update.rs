@@ -2,6 +2,7 @@ use {
     super::{
         context::RowContext,
         evaluate::{Evaluated, evaluate},
+        validate::{name_type_mismatch, try_into_column_value, validate_not_null},
     },
     crate::{
         ast::{Assignment, ColumnDef, ColumnUniqueOption, ForeignKey},
@@ -17,7 +18,7 @@ use {
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum UpdateError {
-    #[error("column not found {0}")]
+    #[error("column not found: {0}")]
     ColumnNotFound(String),
 
     #[error("update on primary key is not supported: {0}")]
@@ -72,8 +73,14 @@ impl<'a, T: GStore> Update<'a, T> {
         })
     }
 
-    pub async fn apply(&self, row: Row, foreign_keys: &[ForeignKey]) -> Result<Row> {
-        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), None);
+    /// Applies the assignments to `row`, `next` is looked up for the columns not found in it.
+    pub async fn apply(
+        &self,
+        row: Row,
+        foreign_keys: &[ForeignKey],
+        next: Option<Arc<RowContext<'_>>>,
+    ) -> Result<Row> {
+        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), next);
         let context = Some(Arc::new(context));
 
         let assignments = stream::iter(self.fields.iter())
@@ -88,28 +95,25 @@ impl<'a, T: GStore> Update<'a, T> {
                     let evaluated = evaluate(self.storage, context, None, value_expr).await?;
                     let value = match self.column_defs {
                         Some(column_defs) => {
-                            let ColumnDef {
-                                data_type,
-                                nullable,
-                                ..
-                            } = column_defs
+                            let column_def = column_defs
                                 .iter()
                                 .find(|column_def| id == &column_def.name)
                                 .ok_or(UpdateError::ConflictOnSchema)?;
+                            let data_type = &column_def.data_type;
+                            let name_type_mismatch =
+                                |error| name_type_mismatch(self.table_name, column_def, error);
 
                             let value = match evaluated {
-                                Evaluated::Literal(v) => Value::try_from_literal(data_type, &v)?,
                                 Evaluated::Value(v) => {
-                                    v.validate_type(data_type)?;
+                                    v.validate_type(data_type).map_err(name_type_mismatch)?;
                                     v
                                 }
-                                Evaluated::StrSlice {
-                                    source: s,
-                                    range: r,
-                                } => Value::Str(s[r].to_owned()),
+                                evaluated => {
+                                    try_into_column_value(self.table_name, column_def, evaluated)?
+                                }
                             };
 
-                            value.validate_null(*nullable)?;
+                            validate_not_null(self.table_name, column_def, &value)?;
                             value
                         }
                         None => evaluated.try_into()?,
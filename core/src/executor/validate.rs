@@ -1,8 +1,9 @@
 use {
+    super::evaluate::Evaluated,
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption},
-        data::{Key, Value},
-        result::Result,
+        ast::{ColumnDef, ColumnUniqueOption, DataType},
+        data::{Key, Literal, Value, ValueError},
+        result::{Error, Result},
         store::{DataRow, Store},
     },
     futures::stream::TryStreamExt,
@@ -26,59 +27,179 @@ pub enum ValidateError {
 
     #[error("duplicate entry '{0:?}' for primary_key field")]
     DuplicateEntryOnPrimaryKeyField(Key),
+
+    #[error(
+        "duplicate entry ({}) for composite primary key ({})",
+        .0.iter().map(|value| format!("'{}'", String::from(value))).collect::<Vec<_>>().join(", "),
+        .1.join(", ")
+    )]
+    DuplicateEntryOnCompositePrimaryKey(Vec<Value>, Vec<String>),
+
+    #[error("null value on not null column '{column_name}' of table '{table_name}'")]
+    NullValueOnNotNullField {
+        table_name: String,
+        column_name: String,
+    },
+
+    #[error(
+        "type mismatch on column '{column_name}' of table '{table_name}': expected {expected}, found '{}'",
+        String::from(.found)
+    )]
+    TypeMismatch {
+        table_name: String,
+        column_name: String,
+        expected: DataType,
+        found: Value,
+    },
+}
+
+/// Names the column of `column_def` when `error` is a value of the wrong type to store in it,
+/// any other error is returned as it is.
+pub fn name_type_mismatch(table_name: &str, column_def: &ColumnDef, error: Error) -> Error {
+    let found = match &error {
+        Error::Value(value_error) => match value_error.as_ref() {
+            ValueError::IncompatibleDataType { value, .. } => value.clone(),
+            _ => return error,
+        },
+        _ => return error,
+    };
+
+    type_mismatch(table_name, column_def, found)
+}
+
+/// Converts `evaluated` into a value of the column of `column_def`, naming the column when a
+/// literal does not fit its type.
+pub fn try_into_column_value(
+    table_name: &str,
+    column_def: &ColumnDef,
+    evaluated: Evaluated<'_>,
+) -> Result<Value> {
+    let literal = match evaluated {
+        Evaluated::Literal(literal) => literal,
+        evaluated => return evaluated.try_into_value(&column_def.data_type, true),
+    };
+
+    Value::try_from_literal(&column_def.data_type, &literal).map_err(|error| match &error {
+        Error::Value(value_error)
+            if matches!(
+                **value_error,
+                ValueError::IncompatibleLiteralForDataType { .. }
+            ) =>
+        {
+            // a number literal is found as a decimal, which keeps its digits as written
+            let found = match &literal {
+                Literal::Number(number) => number.to_string().parse().map(Value::Decimal).ok(),
+                literal => Value::try_from(literal).ok(),
+            };
+
+            found.map_or(error, |found| type_mismatch(table_name, column_def, found))
+        }
+        _ => error,
+    })
+}
+
+fn type_mismatch(table_name: &str, column_def: &ColumnDef, found: Value) -> Error {
+    ValidateError::TypeMismatch {
+        table_name: table_name.to_owned(),
+        column_name: column_def.name.to_owned(),
+        expected: column_def.data_type.clone(),
+        found,
+    }
+    .into()
+}
+
+/// Fails when `value` would leave the `NOT NULL` column of `column_def` NULL.
+pub fn validate_not_null(table_name: &str, column_def: &ColumnDef, value: &Value) -> Result<()> {
+    if !column_def.nullable && value.is_null() {
+        return Err(ValidateError::NullValueOnNotNullField {
+            table_name: table_name.to_owned(),
+            column_name: column_def.name.to_owned(),
+        }
+        .into());
+    }
+
+    Ok(())
 }
 
 pub enum ColumnValidation<'column_def> {
     /// `INSERT`
     All(&'column_def [ColumnDef]),
-    /// `UPDATE`
-    SpecifiedColumns(&'column_def [ColumnDef], Vec<String>),
+    /// `UPDATE`, with the keys of the rows being updated
+    SpecifiedColumns(&'column_def [ColumnDef], Vec<String>, HashSet<Key>),
+    /// `INSERT ... ON CONFLICT`, with the conflict target column indexes and the target values
+    /// of the rows being updated
+    Upsert(
+        &'column_def [ColumnDef],
+        &'column_def [usize],
+        HashSet<Vec<Key>>,
+    ),
 }
 
+/// `(column_indexes, column_names)` of a unique column or of a composite primary key
+type UniqueColumns = (Vec<usize>, Vec<String>);
+
 #[derive(Debug)]
 struct UniqueConstraint {
-    column_index: usize,
-    column_name: String,
-    keys: HashSet<Key>,
+    column_indexes: Vec<usize>,
+    column_names: Vec<String>,
+    keys: HashSet<Vec<Key>>,
 }
 
 impl UniqueConstraint {
-    fn new(column_index: usize, column_name: String) -> Self {
+    fn new((column_indexes, column_names): UniqueColumns) -> Self {
         Self {
-            column_index,
-            column_name,
+            column_indexes,
+            column_names,
             keys: HashSet::new(),
         }
     }
 
-    fn add(self, value: &Value) -> Result<Self> {
-        let new_key = self.check(value)?;
-
-        if matches!(new_key, Key::None) {
+    fn add(self, row: &[Value]) -> Result<Self> {
+        let Some(new_key) = self.check(row)? else {
             return Ok(self);
-        }
+        };
 
         let keys = self.keys.update(new_key);
 
         Ok(Self {
-            column_index: self.column_index,
-            column_name: self.column_name,
+            column_indexes: self.column_indexes,
+            column_names: self.column_names,
             keys,
         })
     }
 
-    fn check(&self, value: &Value) -> Result<Key> {
-        let key = Key::try_from(value)?;
-
-        if !self.keys.contains(&key) {
-            Ok(key)
-        } else {
-            Err(ValidateError::DuplicateEntryOnUniqueField(
-                value.clone(),
-                self.column_name.to_owned(),
-            )
-            .into())
+    /// Returns `None` when any of the values is NULL, which never conflicts.
+    fn check(&self, row: &[Value]) -> Result<Option<Vec<Key>>> {
+        let values = self
+            .column_indexes
+            .iter()
+            .map(|&column_index| {
+                row.get(column_index)
+                    .ok_or(ValidateError::ConflictOnStorageColumnIndex(column_index))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let key = values
+            .iter()
+            .map(|value| Key::try_from(*value))
+            .collect::<Result<Vec<_>>>()?;
+
+        if key.contains(&Key::None) {
+            return Ok(None);
+        } else if !self.keys.contains(&key) {
+            return Ok(Some(key));
         }
+
+        let error = match (values.as_slice(), self.column_names.as_slice()) {
+            ([value], [column_name]) => {
+                ValidateError::DuplicateEntryOnUniqueField((*value).clone(), column_name.to_owned())
+            }
+            _ => ValidateError::DuplicateEntryOnCompositePrimaryKey(
+                values.into_iter().cloned().collect(),
+                self.column_names.clone(),
+            ),
+        };
+
+        Err(error.into())
     }
 }
 
@@ -86,15 +207,25 @@ pub async fn validate_unique<T: Store>(
     storage: &T,
     table_name: &str,
     column_validation: ColumnValidation<'_>,
+    composite_primary_key: Option<&[String]>,
     row_iter: impl Iterator<Item = &[Value]> + Clone,
 ) -> Result<()> {
     enum Columns {
         /// key index
         PrimaryKeyOnly(usize),
-        /// `[(key_index, table_name)]`
-        All(Vec<(usize, String)>),
+        All(Vec<UniqueColumns>),
     }
 
+    let replaced_keys = match &column_validation {
+        ColumnValidation::All(_) => HashSet::new(),
+        ColumnValidation::SpecifiedColumns(_, _, keys) => keys.clone(),
+        ColumnValidation::Upsert(..) => HashSet::new(),
+    };
+    let replaced_conflict_keys = match &column_validation {
+        ColumnValidation::Upsert(_, conflict_columns, keys) => Some((*conflict_columns, keys)),
+        _ => None,
+    };
+
     let columns = match &column_validation {
         ColumnValidation::All(column_defs) => {
             let primary_key_index = column_defs
@@ -113,12 +244,15 @@ pub async fn validate_unique<T: Store>(
 
             match (primary_key_index, other_unique_column_def_count) {
                 (Some(primary_key_index), 0) => Columns::PrimaryKeyOnly(primary_key_index),
-                _ => Columns::All(fetch_all_unique_columns(column_defs)),
+                _ => Columns::All(fetch_all_unique_columns(column_defs, composite_primary_key)),
             }
         }
-        ColumnValidation::SpecifiedColumns(column_defs, specified_columns) => Columns::All(
-            fetch_specified_unique_columns(column_defs, specified_columns),
+        ColumnValidation::SpecifiedColumns(column_defs, specified_columns, _) => Columns::All(
+            fetch_specified_unique_columns(column_defs, composite_primary_key, specified_columns),
         ),
+        ColumnValidation::Upsert(column_defs, ..) => {
+            Columns::All(fetch_all_unique_columns(column_defs, composite_primary_key))
+        }
     };
 
     match columns {
@@ -142,10 +276,16 @@ pub async fn validate_unique<T: Store>(
             }
 
             let unique_constraints = &unique_constraints;
+            let replaced_keys = &replaced_keys;
             storage
                 .scan_data(table_name)
                 .await?
-                .try_for_each(|(_, data_row)| async {
+                .try_for_each(|(key, data_row)| async move {
+                    // stored values of updated rows are replaced, so only their new values count
+                    if replaced_keys.contains(&key) {
+                        return Ok(());
+                    }
+
                     let values = match data_row {
                         DataRow::Vec(values) => values,
                         DataRow::Map(_) => {
@@ -155,16 +295,27 @@ pub async fn validate_unique<T: Store>(
                         }
                     };
 
-                    unique_constraints.iter().try_for_each(|constraint| {
-                        let col_idx = constraint.column_index;
-                        let val = values
-                            .get(col_idx)
-                            .ok_or(ValidateError::ConflictOnStorageColumnIndex(col_idx))?;
+                    if let Some((conflict_columns, keys)) = replaced_conflict_keys {
+                        let key = conflict_columns
+                            .iter()
+                            .map(|&i| {
+                                values
+                                    .get(i)
+                                    .ok_or_else(|| {
+                                        ValidateError::ConflictOnStorageColumnIndex(i).into()
+                                    })
+                                    .and_then(Key::try_from)
+                            })
+                            .collect::<Result<Vec<_>>>()?;
 
-                        constraint.check(val)?;
+                        if keys.contains(&key) {
+                            return Ok(());
+                        }
+                    }
 
-                        Ok(())
-                    })
+                    unique_constraints
+                        .iter()
+                        .try_for_each(|constraint| constraint.check(&values).map(|_| ()))
                 })
                 .await
         }
@@ -172,46 +323,74 @@ pub async fn validate_unique<T: Store>(
 }
 
 fn create_unique_constraints<'a>(
-    unique_columns: Vec<(usize, String)>,
+    unique_columns: Vec<UniqueColumns>,
     row_iter: impl Iterator<Item = &'a [Value]> + Clone,
 ) -> Result<Vector<UniqueConstraint>> {
     unique_columns
         .into_iter()
-        .try_fold(Vector::new(), |constraints, col| {
-            let (col_idx, col_name) = col;
-            let new_constraint = UniqueConstraint::new(col_idx, col_name);
+        .try_fold(Vector::new(), |constraints, columns| {
+            let new_constraint = UniqueConstraint::new(columns);
             let new_constraint = row_iter
                 .clone()
-                .try_fold(new_constraint, |constraint, row| {
-                    let val = row
-                        .get(col_idx)
-                        .ok_or(ValidateError::ConflictOnStorageColumnIndex(col_idx))?;
-
-                    constraint.add(val)
-                })?;
+                .try_fold(new_constraint, |constraint, row| constraint.add(row))?;
             Ok(constraints.push(new_constraint))
         })
 }
 
-fn fetch_all_unique_columns(column_defs: &[ColumnDef]) -> Vec<(usize, String)> {
+fn fetch_all_unique_columns(
+    column_defs: &[ColumnDef],
+    composite_primary_key: Option<&[String]>,
+) -> Vec<UniqueColumns> {
     column_defs
         .iter()
         .enumerate()
-        .filter_map(|(i, table_col)| table_col.unique.map(|_| (i, table_col.name.to_owned())))
+        .filter_map(|(i, table_col)| {
+            table_col
+                .unique
+                .map(|_| (vec![i], vec![table_col.name.to_owned()]))
+        })
+        .chain(fetch_composite_primary_key(
+            column_defs,
+            composite_primary_key,
+        ))
         .collect()
 }
 
 fn fetch_specified_unique_columns(
     all_column_defs: &[ColumnDef],
+    composite_primary_key: Option<&[String]>,
     specified_columns: &[String],
-) -> Vec<(usize, String)> {
+) -> Vec<UniqueColumns> {
+    let is_specified =
+        |column_name: &String| specified_columns.iter().any(|col| col == column_name);
+
     all_column_defs
         .iter()
         .enumerate()
         .filter_map(|(i, table_col)| {
-            (table_col.unique.is_some()
-                && specified_columns.iter().any(|col| col == &table_col.name))
-            .then_some((i, table_col.name.to_owned()))
+            (table_col.unique.is_some() && is_specified(&table_col.name))
+                .then_some((vec![i], vec![table_col.name.to_owned()]))
         })
+        .chain(
+            fetch_composite_primary_key(all_column_defs, composite_primary_key)
+                .filter(|(_, column_names)| column_names.iter().any(is_specified)),
+        )
         .collect()
 }
+
+fn fetch_composite_primary_key(
+    column_defs: &[ColumnDef],
+    composite_primary_key: Option<&[String]>,
+) -> Option<UniqueColumns> {
+    let column_names = composite_primary_key?;
+    let column_indexes = column_names
+        .iter()
+        .map(|column_name| {
+            column_defs
+                .iter()
+                .position(|column_def| &column_def.name == column_name)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((column_indexes, column_names.to_vec()))
+}
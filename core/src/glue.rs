@@ -1,12 +1,13 @@
 use {
     crate::{
         ast::Statement,
+        data::Value,
         executor::{Payload, execute},
-        parse_sql::parse,
+        parse_sql::{parse, parse_with_placeholders},
         plan::plan,
-        result::Result,
+        result::{Error, Result},
         store::{GStore, GStoreMut},
-        translate::translate,
+        translate::{bind_params, translate},
     },
     futures::{
         TryStreamExt,
@@ -40,6 +41,38 @@ impl<T: GStore + GStoreMut> Glue<T> {
 
     pub async fn execute<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
         let statements = self.plan(sql).await?;
+
+        self.execute_planned(statements).await
+    }
+
+    /// Executes `sql` with each `?` placeholder bound to the next value of `params`, in order.
+    ///
+    /// The statements are parsed once, and each placeholder of the translated statements is
+    /// replaced by its value as it is, so a [`Value::Str`] never changes the structure of the
+    /// statement and every value keeps its type. A [`Value::Str`] is still compared with a date,
+    /// time, UUID or INET value by being parsed, the same as a string literal. Fails with
+    /// [`Error::ParamsNotMatched`] when the number of placeholders and `params` differ.
+    pub async fn execute_with_params<Sql: AsRef<str>>(
+        &mut self,
+        sql: Sql,
+        params: Vec<Value>,
+    ) -> Result<Vec<Payload>> {
+        let mut statements = parse_with_placeholders(sql)?
+            .iter()
+            .map(translate)
+            .collect::<Result<Vec<_>>>()?;
+        bind_params(&mut statements, params)?;
+
+        let storage = &self.storage;
+        let statements = stream::iter(statements)
+            .then(|statement| async move { plan(storage, statement).await })
+            .try_collect()
+            .await?;
+
+        self.execute_planned(statements).await
+    }
+
+    async fn execute_planned(&mut self, statements: Vec<Statement>) -> Result<Vec<Payload>> {
         let mut payloads = Vec::<Payload>::new();
         for statement in statements.iter() {
             let payload = self.execute_stmt(statement).await?;
@@ -48,4 +81,31 @@ impl<T: GStore + GStoreMut> Glue<T> {
 
         Ok(payloads)
     }
+
+    /// Runs `;`-separated statements one by one, planning each statement right before it is
+    /// executed so that it sees the tables created or altered by the statements before it.
+    ///
+    /// Execution stops at the first failing statement, whose error is returned as
+    /// [`Error::Batch`] together with its index. Statements before it are not rolled back.
+    pub async fn execute_batch<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
+        let parsed = parse(sql)?;
+        let mut payloads = Vec::with_capacity(parsed.len());
+
+        for (index, parsed) in parsed.iter().enumerate() {
+            let payload = async {
+                let statement = plan(&self.storage, translate(parsed)?).await?;
+
+                self.execute_stmt(&statement).await
+            }
+            .await
+            .map_err(|error| Error::Batch {
+                index,
+                error: Box::new(error),
+            })?;
+
+            payloads.push(payload);
+        }
+
+        Ok(payloads)
+    }
 }
@@ -150,6 +150,7 @@ mod tests {
                         value: "true".to_owned(),
                     },
                     asc: None,
+                    nulls_first: None,
                 },
             ))
             .is_err()
@@ -6,7 +6,7 @@ use {
             Expr as SqlExpr, Ident as SqlIdent, OrderByExpr as SqlOrderByExpr, Query as SqlQuery,
             SelectItem as SqlSelectItem, Statement as SqlStatement,
         },
-        dialect::PostgreSqlDialect,
+        dialect::{Dialect, PostgreSqlDialect},
         parser::Parser,
         tokenizer::Tokenizer,
     },
@@ -18,6 +18,52 @@ pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
     Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{e:#?}")))
 }
 
+/// Parses `sql` the same as [`parse`], but reads each `?` as a placeholder to be bound by
+/// [`Glue::execute_with_params`](crate::prelude::Glue::execute_with_params).
+pub fn parse_with_placeholders<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
+    let tokens = Tokenizer::new(&PlaceholderDialect, sql.as_ref())
+        .tokenize_with_location()
+        .map_err(|e| Error::Parser(format!("{e:#?}")))?;
+
+    Parser::new(&DIALECT)
+        .with_tokens_with_locations(tokens)
+        .parse_statements()
+        .map_err(|e| Error::Parser(format!("{e:#?}")))
+}
+
+/// Tokenizes the same as [`PostgreSqlDialect`], except for `?`.
+///
+/// [`PostgreSqlDialect`] reads `?`, `?|` and `?&` as jsonb operators and lets `?` continue
+/// a custom operator such as `<?`, while this reads every `?` as a placeholder.
+#[derive(Debug)]
+struct PlaceholderDialect;
+
+impl Dialect for PlaceholderDialect {
+    fn identifier_quote_style(&self, identifier: &str) -> Option<char> {
+        DIALECT.identifier_quote_style(identifier)
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        DIALECT.is_delimited_identifier_start(ch)
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        DIALECT.is_identifier_start(ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        DIALECT.is_identifier_part(ch)
+    }
+
+    fn supports_unicode_string_literal(&self) -> bool {
+        DIALECT.supports_unicode_string_literal()
+    }
+
+    fn is_custom_operator_part(&self, ch: char) -> bool {
+        ch != '?' && DIALECT.is_custom_operator_part(ch)
+    }
+}
+
 macro_rules! generate_parse_fn {
     ($fn_name: ident, $output_type: ty) => {
         pub fn $fn_name<Sql: AsRef<str>>(sql_expr: Sql) -> Result<$output_type> {
@@ -7,6 +7,15 @@ pub enum PlanError {
     #[error("column reference {0} is ambiguous, please specify the table name")]
     ColumnReferenceAmbiguous(String),
 
+    /// Error that occurs when a column is projected from a grouped query without being listed in
+    /// `GROUP BY` or wrapped in an aggregate function.
+    #[error("column {0} must appear in the GROUP BY clause or be used in an aggregate function")]
+    ColumnNotInGroupBy(String),
+
+    /// Error that occurs when a select without `FROM` refers to a column.
+    #[error("column cannot be resolved without FROM: {0}")]
+    ColumnWithoutFrom(String),
+
     #[error("unreachable")]
     Unreachable,
 }
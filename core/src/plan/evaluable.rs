@@ -38,6 +38,19 @@ pub fn check_expr(context: Option<Arc<Context<'_>>>, expr: &Expr) -> bool {
     }
 }
 
+fn check_set_expr(context: Option<Arc<Context<'_>>>, set_expr: &SetExpr) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => check_select(context, select),
+        SetExpr::Values(Values(rows)) => rows
+            .iter()
+            .flatten()
+            .all(|expr| check_expr(context.as_ref().map(Arc::clone), expr)),
+        SetExpr::SetOperation { left, right, .. } => {
+            check_set_expr(context.as_ref().map(Arc::clone), left) && check_set_expr(context, right)
+        }
+    }
+}
+
 fn check_query(context: Option<Arc<Context<'_>>>, query: &Query) -> bool {
     let Query {
         body,
@@ -46,15 +59,7 @@ fn check_query(context: Option<Arc<Context<'_>>>, query: &Query) -> bool {
         offset,
     } = query;
 
-    let body = match body {
-        SetExpr::Select(select) => check_select(context.as_ref().map(Arc::clone), select),
-        SetExpr::Values(Values(rows)) => rows
-            .iter()
-            .flatten()
-            .all(|expr| check_expr(context.as_ref().map(Arc::clone), expr)),
-    };
-
-    if !body {
+    if !check_set_expr(context.as_ref().map(Arc::clone), body) {
         return false;
     }
 
@@ -77,6 +82,7 @@ fn check_select(context: Option<Arc<Context<'_>>>, select: &Select) -> bool {
         distinct: _,
         projection,
         from,
+        without_from: _,
         selection,
         group_by,
         having,
@@ -108,11 +114,15 @@ fn check_select(context: Option<Arc<Context<'_>>>, select: &Select) -> bool {
 
         match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr))
-            | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+            | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+            | JoinOperator::RightOuter(JoinConstraint::On(expr))
+            | JoinOperator::FullOuter(JoinConstraint::On(expr)) => {
                 check_expr(context.as_ref().map(Arc::clone), expr)
             }
             JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => true,
+            | JoinOperator::LeftOuter(JoinConstraint::None)
+            | JoinOperator::RightOuter(JoinConstraint::None)
+            | JoinOperator::FullOuter(JoinConstraint::None) => true,
         }
     }) {
         return false;
@@ -2,8 +2,9 @@ use {
     super::PlanError,
     crate::{
         ast::{
-            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, OrderByExpr,
-            Query, Select, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, JoinOperator,
+            OrderByExpr, Query, Select, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins,
         },
         data::{Schema, SchemaIndex, SchemaIndexOrd},
         result::Result,
@@ -30,6 +31,11 @@ impl Indexes {
     }
 
     fn find_ordered(&self, target: &OrderByExpr) -> Option<String> {
+        // indexes keep NULLs at the largest end, explicit placement is left to the sort
+        if target.nulls_first.is_some() {
+            return None;
+        }
+
         self.0
             .iter()
             .find(|SchemaIndex { expr, order, .. }| {
@@ -67,9 +73,53 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
                 offset,
             });
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            // ORDER BY and LIMIT apply to the whole set operation, so only each side is planned
+            let plan_side = |side: Box<SetExpr>| {
+                let query = Query {
+                    body: *side,
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                };
+
+                plan_query(schema_map, query).map(|query| Box::new(query.body))
+            };
+
+            return Ok(Query {
+                body: SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: plan_side(left)?,
+                    right: plan_side(right)?,
+                },
+                order_by,
+                limit,
+                offset,
+            });
+        }
     };
 
-    let TableWithJoins { relation, .. } = &select.from;
+    let TableWithJoins { relation, joins } = &select.from;
+    if joins.iter().any(|join| {
+        matches!(
+            join.join_operator,
+            JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+        )
+    }) {
+        return Ok(Query {
+            body: SetExpr::Select(select),
+            order_by,
+            limit,
+            offset,
+        });
+    }
+
     let table_name = match relation {
         TableFactor::Table { name, .. } => name,
         TableFactor::Derived { .. } => {
@@ -117,6 +167,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             distinct,
             projection,
             from,
+            without_from,
             selection,
             group_by,
             having,
@@ -141,6 +192,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             distinct,
             projection,
             from,
+            without_from,
             selection,
             group_by,
             having,
@@ -175,6 +227,7 @@ fn plan_select(
         distinct,
         projection,
         from,
+        without_from,
         selection,
         group_by,
         having,
@@ -187,6 +240,7 @@ fn plan_select(
                 distinct,
                 projection,
                 from,
+                without_from,
                 selection,
                 group_by,
                 having,
@@ -199,6 +253,7 @@ fn plan_select(
             distinct,
             projection,
             from,
+            without_from,
             selection: Some(selection),
             group_by,
             having,
@@ -233,6 +288,7 @@ fn plan_select(
                 distinct,
                 projection,
                 from,
+                without_from,
                 selection,
                 group_by,
                 having,
@@ -442,6 +498,7 @@ fn is_stateless(expr: &Expr) -> bool {
         Expr::Literal(AstLiteral::Null) => false,
         Expr::Literal(_) => true,
         Expr::TypedString { .. } => true,
+        Expr::Value(value) => !value.is_null(),
         Expr::IsNull(expr)
         | Expr::IsNotNull(expr)
         | Expr::UnaryOp { expr, .. }
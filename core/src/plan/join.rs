@@ -37,14 +37,7 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
             offset,
         } = query;
 
-        let body = match body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => body,
-        };
+        let body = self.set_expr(outer_context, body);
 
         Query {
             body,
@@ -60,11 +53,34 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 }
 
 impl<'a> JoinPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Arc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer_context.as_ref().map(Arc::clone), *left)),
+                right: Box::new(self.set_expr(outer_context, *right)),
+            },
+        }
+    }
+
     fn select(&self, outer_context: Option<Arc<Context<'a>>>, select: Select) -> Select {
         let Select {
             distinct,
             projection,
             from,
+            without_from,
             selection,
             group_by,
             having,
@@ -77,6 +93,7 @@ impl<'a> JoinPlanner<'a> {
             distinct,
             projection,
             from,
+            without_from,
             selection,
             group_by,
             having,
@@ -137,8 +154,12 @@ impl<'a> JoinPlanner<'a> {
         let (join_op, expr) = match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr)) => (JoinOp::Inner, expr),
             JoinOperator::LeftOuter(JoinConstraint::On(expr)) => (JoinOp::LeftOuter, expr),
+            // unmatched right rows are only known after every left row is seen,
+            // so RIGHT and FULL OUTER JOIN always run as nested loop joins
             JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => {
+            | JoinOperator::LeftOuter(JoinConstraint::None)
+            | JoinOperator::RightOuter(_)
+            | JoinOperator::FullOuter(_) => {
                 let context = self.update_context(inner_context, &relation);
                 let join = Join {
                     relation,
@@ -17,7 +17,9 @@ pub trait Planner<'a> {
             Expr::Identifier(_)
             | Expr::CompoundIdentifier { .. }
             | Expr::Literal(_)
-            | Expr::TypedString { .. } => expr,
+            | Expr::TypedString { .. }
+            | Expr::Placeholder
+            | Expr::Value(_) => expr,
             Expr::IsNull(expr) => Expr::IsNull(Box::new(self.subquery_expr(outer_context, *expr))),
             Expr::IsNotNull(expr) => {
                 Expr::IsNotNull(Box::new(self.subquery_expr(outer_context, *expr)))
@@ -82,6 +84,7 @@ pub trait Planner<'a> {
                 expr,
                 negated,
                 pattern,
+                escape,
             } => {
                 let expr =
                     Box::new(self.subquery_expr(outer_context.as_ref().map(Arc::clone), *expr));
@@ -92,12 +95,14 @@ pub trait Planner<'a> {
                     expr,
                     negated,
                     pattern,
+                    escape,
                 }
             }
             Expr::ILike {
                 expr,
                 negated,
                 pattern,
+                escape,
             } => {
                 let expr =
                     Box::new(self.subquery_expr(outer_context.as_ref().map(Arc::clone), *expr));
@@ -108,6 +113,7 @@ pub trait Planner<'a> {
                     expr,
                     negated,
                     pattern,
+                    escape,
                 }
             }
             Expr::BinaryOp { left, op, right } => Expr::BinaryOp {
@@ -2,8 +2,8 @@ use {
     super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
     crate::{
         ast::{
-            BinaryOperator, Expr, IndexItem, Query, Select, SetExpr, Statement, TableFactor,
-            TableWithJoins,
+            BinaryOperator, Expr, IndexItem, JoinOperator, Query, Select, SetExpr, Statement,
+            TableFactor, TableWithJoins,
         },
         data::Schema,
     },
@@ -29,14 +29,7 @@ struct PrimaryKeyPlanner<'a> {
 
 impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
     fn query(&self, outer_context: Option<Arc<Context<'a>>>, query: Query) -> Query {
-        let body = match query.body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => query.body,
-        };
+        let body = self.set_expr(outer_context, query.body);
 
         Query { body, ..query }
     }
@@ -55,7 +48,39 @@ enum PrimaryKey {
 }
 
 impl<'a> PrimaryKeyPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Arc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer_context.as_ref().map(Arc::clone), *left)),
+                right: Box::new(self.set_expr(outer_context, *right)),
+            },
+        }
+    }
+
     fn select(&self, outer_context: Option<Arc<Context<'a>>>, select: Select) -> Select {
+        // narrowing the base table would turn matched right rows into unmatched ones
+        if select.from.joins.iter().any(|join| {
+            matches!(
+                join.join_operator,
+                JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+            )
+        }) {
+            return select;
+        }
+
         let current_context = self.update_context(None, &select.from.relation);
         let current_context = select
             .from
@@ -276,6 +301,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -295,6 +321,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -314,6 +341,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(expr("True")),
             group_by: Vec::new(),
             having: None,
@@ -339,6 +367,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(expr("name IS NOT NULL AND True")),
             group_by: Vec::new(),
             having: None,
@@ -373,6 +402,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(expr("name IS NOT NULL AND (True)")),
             group_by: Vec::new(),
             having: None,
@@ -414,6 +444,7 @@ mod tests {
                     join_executor: JoinExecutor::NestedLoop,
                 }],
             },
+            without_from: false,
             selection: None,
             group_by: Vec::new(),
             having: None,
@@ -441,6 +472,7 @@ mod tests {
                     join_executor: JoinExecutor::NestedLoop,
                 }],
             },
+            without_from: false,
             selection: Some(expr("Player.id = Badge.user_id")),
             group_by: Vec::new(),
             having: None,
@@ -466,6 +498,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
@@ -486,6 +519,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: Some(Expr::InSubquery {
                     expr: Box::new(expr("name")),
                     subquery: Box::new(subquery),
@@ -525,6 +559,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: None,
                     group_by: Vec::new(),
                     having: None,
@@ -545,6 +580,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("name".to_owned())),
                     op: BinaryOperator::Eq,
@@ -578,6 +614,7 @@ mod tests {
                         },
                         joins: Vec::new(),
                     },
+                    without_from: false,
                     selection: Some(expr("id = id")),
                     group_by: Vec::new(),
                     having: None,
@@ -598,6 +635,7 @@ mod tests {
                     },
                     joins: Vec::new(),
                 },
+                without_from: false,
                 selection: Some(Expr::InSubquery {
                     expr: Box::new(Expr::Identifier("id".to_owned())),
                     subquery: Box::new(subquery),
@@ -618,6 +656,7 @@ mod tests {
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::Number(1.into()))),
             }),
+            returning: Vec::new(),
         };
         assert_eq!(actual, expected, "delete statement:\n{sql}");
 
@@ -647,6 +686,7 @@ mod tests {
                 },
                 joins: Vec::new(),
             },
+            without_from: false,
             selection: Some(Expr::Nested(Box::new(expr("name")))),
             group_by: Vec::new(),
             having: None,
@@ -71,10 +71,7 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
         ..
     } = query;
 
-    let schema_list = match body {
-        SetExpr::Select(select) => scan_select(storage, select).await?,
-        SetExpr::Values(_) => HashMap::new(),
-    };
+    let schema_list = scan_set_expr(storage, body).await?;
 
     let schema_list = match (limit, offset) {
         (Some(limit), Some(offset)) => schema_list
@@ -92,11 +89,28 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
     Ok(schema_list)
 }
 
+#[async_recursion]
+async fn scan_set_expr<T>(storage: &T, set_expr: &SetExpr) -> Result<HashMap<String, Schema>>
+where
+    T: Store,
+{
+    match set_expr {
+        SetExpr::Select(select) => scan_select(storage, select).await,
+        SetExpr::Values(_) => Ok(HashMap::new()),
+        SetExpr::SetOperation { left, right, .. } => Ok(scan_set_expr(storage, left)
+            .await?
+            .into_iter()
+            .chain(scan_set_expr(storage, right).await?)
+            .collect()),
+    }
+}
+
 async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<String, Schema>> {
     let Select {
         distinct: _,
         projection,
         from,
+        without_from: _,
         selection,
         group_by,
         having,
@@ -156,13 +170,17 @@ async fn scan_join<T: Store>(storage: &T, join: &Join) -> Result<HashMap<String,
     let schema_list = scan_table_factor(storage, relation).await?;
     let schema_list = match join_operator {
         JoinOperator::Inner(JoinConstraint::On(expr))
-        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
             .await?
             .into_iter()
             .chain(schema_list)
             .collect(),
         JoinOperator::Inner(JoinConstraint::None)
-        | JoinOperator::LeftOuter(JoinConstraint::None) => schema_list,
+        | JoinOperator::LeftOuter(JoinConstraint::None)
+        | JoinOperator::RightOuter(JoinConstraint::None)
+        | JoinOperator::FullOuter(JoinConstraint::None) => schema_list,
     };
 
     Ok(schema_list)
@@ -1,8 +1,11 @@
 use {
-    super::PlanError,
+    super::{PlanError, expr::PlanExpr},
     crate::{
-        ast::{Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins},
-        data::Schema,
+        ast::{
+            Expr, Join, JoinConstraint, JoinOperator, OrderByExpr, Query, Select, SelectItem,
+            SetExpr, Statement, TableFactor, TableWithJoins,
+        },
+        data::{Schema, get_alias},
         result::Result,
     },
     std::{collections::HashMap, sync::Arc},
@@ -19,28 +22,345 @@ pub fn validate(schema_map: &SchemaMap, statement: &Statement) -> Result<()> {
     };
 
     if let Some(query) = query {
-        if let Query {
-            body: SetExpr::Select(select),
-            ..
-        } = query
-        {
-            for select_item in &select.projection {
-                if let SelectItem::Expr {
-                    expr: Expr::Identifier(ident),
-                    ..
-                } = select_item
-                {
-                    if let Some(context) = contextualize_query(schema_map, query) {
-                        context.validate_duplicated(ident)?;
-                    }
-                }
-            }
-        }
+        validate_group_by(schema_map, &query.body)?;
+        validate_ambiguous(schema_map, &query.body, &query.order_by)?;
     }
 
     Ok(())
 }
 
+/// Validate every unqualified column used in a select resolves to only one of its relations
+///
+/// `JOIN ... ON` only sees the relations joined so far, and `ORDER BY` may also refer to
+/// projection labels. Subqueries in expressions are left to their own evaluation.
+fn validate_ambiguous(
+    schema_map: &SchemaMap,
+    set_expr: &SetExpr,
+    order_by: &[OrderByExpr],
+) -> Result<()> {
+    let select = match set_expr {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(_) => return Ok(()),
+        SetExpr::SetOperation { left, right, .. } => {
+            validate_ambiguous(schema_map, left, &[])?;
+
+            return validate_ambiguous(schema_map, right, &[]);
+        }
+    };
+
+    let Select {
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+        ..
+    } = select.as_ref();
+
+    for table_factor in std::iter::once(relation).chain(joins.iter().map(|join| &join.relation)) {
+        if let TableFactor::Derived { subquery, .. } = table_factor {
+            validate_ambiguous(schema_map, &subquery.body, &subquery.order_by)?;
+        }
+    }
+
+    if select.without_from {
+        return validate_without_from(select, order_by);
+    }
+
+    let mut context = contextualize_table_factor(schema_map, relation);
+    for Join {
+        relation,
+        join_operator,
+        ..
+    } in joins
+    {
+        context = Context::concat(context, contextualize_table_factor(schema_map, relation));
+
+        let (JoinOperator::Inner(constraint)
+        | JoinOperator::LeftOuter(constraint)
+        | JoinOperator::RightOuter(constraint)
+        | JoinOperator::FullOuter(constraint)) = join_operator;
+
+        if let (Some(context), JoinConstraint::On(expr)) = (&context, constraint) {
+            validate_identifiers(context, expr)?;
+        }
+    }
+
+    let Some(context) = context else {
+        return Ok(());
+    };
+
+    let mut labels = Vec::new();
+    for select_item in projection {
+        if let SelectItem::Expr { expr, label } = select_item {
+            validate_identifiers(&context, expr)?;
+            labels.push(label.as_str());
+        }
+    }
+
+    selection
+        .iter()
+        .chain(group_by)
+        .chain(having)
+        .try_for_each(|expr| validate_identifiers(&context, expr))?;
+
+    order_by
+        .iter()
+        .filter(|OrderByExpr { expr, .. }| {
+            !matches!(expr, Expr::Identifier(ident) if labels.contains(&ident.as_str()))
+        })
+        .try_for_each(|OrderByExpr { expr, .. }| validate_identifiers(&context, expr))
+}
+
+fn validate_identifiers(context: &Context, expr: &Expr) -> Result<()> {
+    match expr.into() {
+        PlanExpr::None | PlanExpr::CompoundIdentifier { .. } | PlanExpr::Query(_) => Ok(()),
+        PlanExpr::Identifier(ident) => context.validate_duplicated(ident),
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => {
+            validate_identifiers(context, expr)
+        }
+        PlanExpr::TwoExprs(expr, expr2) => {
+            validate_identifiers(context, expr)?;
+            validate_identifiers(context, expr2)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            validate_identifiers(context, expr)?;
+            validate_identifiers(context, expr2)?;
+            validate_identifiers(context, expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs
+            .into_iter()
+            .try_for_each(|expr| validate_identifiers(context, expr)),
+    }
+}
+
+/// Validate a select without `FROM` does not refer to any column, except for projection labels
+/// in `ORDER BY`
+fn validate_without_from(select: &Select, order_by: &[OrderByExpr]) -> Result<()> {
+    let Select {
+        projection,
+        selection,
+        group_by,
+        having,
+        ..
+    } = select;
+
+    let mut labels = Vec::new();
+    for select_item in projection {
+        if let SelectItem::Expr { expr, label } = select_item {
+            check_no_column(expr)?;
+            labels.push(label.as_str());
+        }
+    }
+
+    selection
+        .iter()
+        .chain(group_by)
+        .chain(having)
+        .try_for_each(check_no_column)?;
+
+    order_by
+        .iter()
+        .filter(|OrderByExpr { expr, .. }| {
+            !matches!(expr, Expr::Identifier(ident) if labels.contains(&ident.as_str()))
+        })
+        .try_for_each(|OrderByExpr { expr, .. }| check_no_column(expr))
+}
+
+fn check_no_column(expr: &Expr) -> Result<()> {
+    match expr.into() {
+        PlanExpr::None | PlanExpr::Query(_) => Ok(()),
+        PlanExpr::Identifier(ident) => Err(PlanError::ColumnWithoutFrom(ident.to_owned()).into()),
+        PlanExpr::CompoundIdentifier { alias, ident } => {
+            Err(PlanError::ColumnWithoutFrom(format!("{alias}.{ident}")).into())
+        }
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => check_no_column(expr),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            check_no_column(expr)?;
+            check_no_column(expr2)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            check_no_column(expr)?;
+            check_no_column(expr2)?;
+            check_no_column(expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().try_for_each(check_no_column),
+    }
+}
+
+/// Validate every non-aggregated column in the projection and `HAVING` is listed in `GROUP BY`
+///
+/// Wildcards in the projection are expanded to the columns of their relations.
+fn validate_group_by(schema_map: &SchemaMap, set_expr: &SetExpr) -> Result<()> {
+    let select = match set_expr {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(_) => return Ok(()),
+        SetExpr::SetOperation { left, right, .. } => {
+            validate_group_by(schema_map, left)?;
+
+            return validate_group_by(schema_map, right);
+        }
+    };
+
+    let Select {
+        projection,
+        from,
+        group_by,
+        having,
+        ..
+    } = select.as_ref();
+
+    let TableWithJoins { relation, joins } = from;
+    for table_factor in std::iter::once(relation).chain(joins.iter().map(|join| &join.relation)) {
+        if let TableFactor::Derived { subquery, .. } = table_factor {
+            validate_group_by(schema_map, &subquery.body)?;
+        }
+    }
+
+    let exprs = projection
+        .iter()
+        .filter_map(|select_item| match select_item {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+        });
+
+    if group_by.is_empty() && !exprs.clone().chain(having).any(contains_aggregate) {
+        return Ok(());
+    }
+
+    let table_factors = std::iter::once(relation).chain(joins.iter().map(|join| &join.relation));
+    let aliases = table_factors.clone().map(get_alias).collect::<Vec<_>>();
+
+    let wildcard_exprs = projection
+        .iter()
+        .flat_map(|select_item| {
+            table_factors
+                .clone()
+                .filter(move |table_factor| match select_item {
+                    SelectItem::Wildcard => true,
+                    SelectItem::QualifiedWildcard(alias) => get_alias(table_factor) == alias,
+                    SelectItem::Expr { .. } => false,
+                })
+        })
+        .flat_map(|table_factor| {
+            let alias = get_alias(table_factor);
+
+            wildcard_columns(schema_map, table_factor)
+                .into_iter()
+                .map(|column| Expr::CompoundIdentifier {
+                    alias: alias.to_owned(),
+                    ident: column.to_owned(),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    exprs
+        .chain(&wildcard_exprs)
+        .chain(having)
+        .try_for_each(|expr| check_grouped(&aliases, group_by, expr))
+}
+
+/// Columns a wildcard expands to for `table_factor`, empty when they are not known before
+/// the rows are fetched
+fn wildcard_columns<'a>(schema_map: &'a SchemaMap, table_factor: &'a TableFactor) -> Vec<&'a str> {
+    let (columns, alias_columns) = match table_factor {
+        TableFactor::Table { name, alias, .. } => (
+            schema_map.get(name).and_then(get_labels),
+            alias.as_ref().map(|alias| alias.columns.as_slice()),
+        ),
+        TableFactor::Derived { subquery, alias } => {
+            let labels = match &subquery.body {
+                SetExpr::Select(select) => select
+                    .projection
+                    .iter()
+                    .map(|select_item| match select_item {
+                        SelectItem::Expr { label, .. } => Some(label.as_str()),
+                        SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+                    })
+                    .collect::<Option<Vec<_>>>(),
+                SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
+            };
+
+            (labels, Some(alias.columns.as_slice()))
+        }
+        TableFactor::Series { .. } => (Some(vec!["N"]), None),
+        TableFactor::Dictionary { .. } => (None, None),
+    };
+
+    let Some(columns) = columns else {
+        return Vec::new();
+    };
+    let alias_columns = alias_columns.unwrap_or_default();
+
+    alias_columns
+        .iter()
+        .map(String::as_str)
+        .chain(columns.into_iter().skip(alias_columns.len()))
+        .collect()
+}
+
+fn contains_aggregate(expr: &Expr) -> bool {
+    if matches!(expr, Expr::Aggregate(_)) {
+        return true;
+    }
+
+    match expr.into() {
+        PlanExpr::None
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. }
+        | PlanExpr::Query(_) => false,
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => contains_aggregate(expr),
+        PlanExpr::TwoExprs(expr, expr2) => contains_aggregate(expr) || contains_aggregate(expr2),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            contains_aggregate(expr) || contains_aggregate(expr2) || contains_aggregate(expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(contains_aggregate),
+    }
+}
+
+fn check_grouped(aliases: &[&String], group_by: &[Expr], expr: &Expr) -> Result<()> {
+    let is_grouped = |target: &Expr| {
+        group_by.iter().any(|grouped| match (grouped, target) {
+            (Expr::Identifier(grouped), Expr::CompoundIdentifier { ident, .. })
+            | (Expr::CompoundIdentifier { ident: grouped, .. }, Expr::Identifier(ident)) => {
+                grouped == ident
+            }
+            _ => grouped == target,
+        })
+    };
+
+    if matches!(expr, Expr::Aggregate(_)) || is_grouped(expr) {
+        return Ok(());
+    }
+
+    match expr.into() {
+        PlanExpr::None | PlanExpr::Query(_) => Ok(()),
+        PlanExpr::Identifier(ident) => Err(PlanError::ColumnNotInGroupBy(ident.to_owned()).into()),
+        PlanExpr::CompoundIdentifier { alias, ident } => {
+            if aliases.iter().any(|name| name.as_str() == alias) {
+                Err(PlanError::ColumnNotInGroupBy(format!("{alias}.{ident}")).into())
+            } else {
+                Ok(())
+            }
+        }
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => {
+            check_grouped(aliases, group_by, expr)
+        }
+        PlanExpr::TwoExprs(expr, expr2) => {
+            check_grouped(aliases, group_by, expr)?;
+            check_grouped(aliases, group_by, expr2)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            check_grouped(aliases, group_by, expr)?;
+            check_grouped(aliases, group_by, expr2)?;
+            check_grouped(aliases, group_by, expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs
+            .into_iter()
+            .try_for_each(|expr| check_grouped(aliases, group_by, expr)),
+    }
+}
+
 enum Context<'a> {
     Data {
         labels: Option<Vec<&'a str>>,
@@ -128,7 +448,7 @@ fn contextualize_query<'a>(
 
             Context::concat(by_table, by_joins)
         }
-        SetExpr::Values(_) => None,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
     }
 }
 
@@ -141,7 +461,33 @@ fn contextualize_table_factor<'a>(
             let schema = schema_map.get(name);
             schema.map(|schema| Arc::from(Context::new(get_labels(schema), None)))
         }
-        TableFactor::Derived { subquery, .. } => contextualize_query(schema_map, subquery),
+        TableFactor::Derived { subquery, alias } => {
+            let labels = match &subquery.body {
+                SetExpr::Select(select) => select
+                    .projection
+                    .iter()
+                    .map(|select_item| match select_item {
+                        SelectItem::Expr { label, .. } => Some(label.as_str()),
+                        SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+                    })
+                    .collect::<Option<Vec<_>>>(),
+                SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
+            };
+
+            match labels {
+                Some(labels) => {
+                    let labels = alias
+                        .columns
+                        .iter()
+                        .map(String::as_str)
+                        .chain(labels.into_iter().skip(alias.columns.len()))
+                        .collect();
+
+                    Some(Arc::from(Context::new(Some(labels), None)))
+                }
+                None => contextualize_query(schema_map, subquery),
+            }
+        }
         TableFactor::Series { .. } | TableFactor::Dictionary { .. } => None,
     }
 }
@@ -178,6 +524,64 @@ mod tests {
                 "CREATE TABLE Ids AS SELECT id FROM Users A JOIN Users B on A.id = B.id",
                 false,
             ),
+            ("SELECT id, COUNT(*) FROM Users GROUP BY id", true),
+            ("SELECT U.id, COUNT(*) FROM Users U GROUP BY id", true),
+            ("SELECT id + 1 FROM Users GROUP BY id + 1", true),
+            ("SELECT COUNT(*) FROM Users GROUP BY name", true),
+            ("SELECT id, COUNT(*) FROM Users", false),
+            ("SELECT name FROM Users GROUP BY id", false),
+            ("SELECT * FROM Users GROUP BY name", false),
+            ("SELECT * FROM Users GROUP BY id, name", true),
+            ("SELECT U.* FROM Users U GROUP BY U.id", false),
+            ("SELECT *, COUNT(*) FROM Users", false),
+            ("SELECT * FROM Users U(a, b) GROUP BY a, b", true),
+            (
+                "SELECT * FROM (SELECT id FROM Users) AS Sub GROUP BY id",
+                true,
+            ),
+            (
+                "SELECT * FROM (SELECT id, name FROM Users) AS Sub GROUP BY id",
+                false,
+            ),
+            (
+                "SELECT id FROM Users GROUP BY id HAVING COUNT(name) > 1",
+                true,
+            ),
+            ("SELECT id FROM Users GROUP BY id HAVING name = 'a'", false),
+            (
+                "SELECT * FROM (SELECT name FROM Users GROUP BY id) AS Sub",
+                false,
+            ),
+            (
+                "SELECT A.name FROM Users A JOIN Users B ON id = B.id",
+                false,
+            ),
+            (
+                "SELECT A.name FROM Users A JOIN Users B ON A.id = B.id WHERE name = 'a'",
+                false,
+            ),
+            (
+                "SELECT A.name AS name FROM Users A JOIN Users B ON A.id = B.id ORDER BY name",
+                true,
+            ),
+            (
+                "SELECT id FROM (SELECT A.id FROM Users A JOIN Users B ON A.id = B.id) AS Sub",
+                true,
+            ),
+            (
+                "SELECT x FROM (SELECT A.id, B.id FROM Users A JOIN Users B ON A.id = B.id) AS Sub(x)",
+                true,
+            ),
+            (
+                "SELECT A.id FROM Users A JOIN Users B ON A.id = B.id UNION SELECT id FROM Users",
+                true,
+            ),
+            ("SELECT 1 + 2 AS n ORDER BY n", true),
+            ("SELECT (SELECT MAX(id) FROM Users)", true),
+            ("SELECT id", false),
+            ("SELECT 1 WHERE id = 1", false),
+            ("SELECT 1 AS n ORDER BY id", false),
+            ("SELECT * FROM SERIES(2) AS Series WHERE N > 1", true),
         ];
 
         for (sql, expected) in cases {
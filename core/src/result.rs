@@ -47,9 +47,9 @@ pub enum Error {
     Sort(#[from] SortError),
     #[error("insert: {0}")]
     Insert(#[from] InsertError),
-    #[error("update: {0}")]
-    Delete(#[from] DeleteError),
     #[error("delete: {0}")]
+    Delete(#[from] DeleteError),
+    #[error("update: {0}")]
     Update(#[from] UpdateError),
     #[error("table: {0}")]
     Table(#[from] TableError),
@@ -73,6 +73,21 @@ pub enum Error {
     Plan(#[from] PlanError),
     #[error("schema-parse: {0}")]
     Schema(#[from] SchemaParseError),
+
+    /// Failure of one statement in [`Glue::execute_batch`](crate::prelude::Glue::execute_batch),
+    /// `index` is the zero-based position of the failed statement.
+    #[error("statement {index}: {error}")]
+    Batch { index: usize, error: Box<Error> },
+
+    /// Failure of one tuple of a multi-row `INSERT ... VALUES`, `index` is the zero-based
+    /// position of the failed tuple.
+    #[error("tuple {index}: {error}")]
+    Tuple { index: usize, error: Box<Error> },
+
+    /// The number of `?` placeholders differs from the number of params given to
+    /// [`Glue::execute_with_params`](crate::prelude::Glue::execute_with_params).
+    #[error("placeholders and params not matched: {placeholders} placeholders, {params} params")]
+    ParamsNotMatched { placeholders: usize, params: usize },
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
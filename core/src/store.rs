@@ -85,6 +85,8 @@ pub trait StoreMut: Send + Sync {
         Err(Error::StorageMsg(msg))
     }
 
+    /// Removes the table, including all of its rows, so that a table created again under the
+    /// same name starts empty.
     async fn delete_schema(&mut self, _table_name: &str) -> Result<()> {
         let msg = "[Storage] StoreMut::delete_schema is not supported".to_owned();
 
@@ -81,6 +81,12 @@ pub trait AlterTable: Store + StoreMut {
             .ok_or(AlterTableError::RenamingColumnNotFound)?
             .name = new_column_name.to_owned();
 
+        for column_name in schema.composite_primary_key.iter_mut().flatten() {
+            if column_name == old_column_name {
+                new_column_name.clone_into(column_name);
+            }
+        }
+
         let rows = self
             .scan_data(table_name)
             .await?
@@ -190,4 +196,15 @@ pub trait AlterTable: Store + StoreMut {
         self.insert_schema(&schema).await?;
         self.insert_data(table_name, rows).await
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let keys = self
+            .scan_data(table_name)
+            .await?
+            .map_ok(|(key, _)| key)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        self.delete_data(table_name, keys).await
+    }
 }
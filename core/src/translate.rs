@@ -5,6 +5,7 @@ mod error;
 mod expr;
 mod function;
 mod operator;
+mod param;
 mod query;
 
 pub use self::{
@@ -12,23 +13,30 @@ pub use self::{
     ddl::{translate_column_def, translate_operate_function_arg},
     error::TranslateError,
     expr::{translate_expr, translate_order_by_expr},
+    param::bind_params,
     query::{alias_or_name, translate_query, translate_select_item},
 };
 
 use {
     crate::{
-        ast::{Assignment, ForeignKey, ReferentialAction, Statement, Variable},
+        ast::{
+            Assignment, ColumnDef, ColumnUniqueOption, ForeignKey, OnConflict, OnConflictAction,
+            ReferentialAction, SelectItem, Statement, Variable,
+        },
         result::Result,
     },
     ddl::translate_alter_table_operation,
     sqlparser::ast::{
         Assignment as SqlAssignment, AssignmentTarget as SqlAssignmentTarget,
-        CommentDef as SqlCommentDef, CreateFunctionBody as SqlCreateFunctionBody,
-        CreateIndex as SqlCreateIndex, CreateTable as SqlCreateTable, Delete as SqlDelete,
+        CommentDef as SqlCommentDef, ConflictTarget as SqlConflictTarget,
+        CreateFunctionBody as SqlCreateFunctionBody, CreateIndex as SqlCreateIndex,
+        CreateTable as SqlCreateTable, Delete as SqlDelete, DoUpdate as SqlDoUpdate,
         FromTable as SqlFromTable, Ident as SqlIdent, Insert as SqlInsert,
-        ObjectName as SqlObjectName, ObjectType as SqlObjectType,
-        ReferentialAction as SqlReferentialAction, Statement as SqlStatement,
-        TableConstraint as SqlTableConstraint, TableFactor, TableWithJoins,
+        ObjectName as SqlObjectName, ObjectType as SqlObjectType, OnConflict as SqlOnConflict,
+        OnConflictAction as SqlOnConflictAction, OnInsert as SqlOnInsert,
+        ReferentialAction as SqlReferentialAction, SelectItem as SqlSelectItem,
+        Statement as SqlStatement, TableConstraint as SqlTableConstraint, TableFactor,
+        TableWithJoins,
     },
 };
 
@@ -39,6 +47,8 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             table_name,
             columns,
             source,
+            on,
+            returning,
             ..
         }) => {
             let table_name = translate_object_name(table_name)?;
@@ -54,12 +64,15 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 table_name,
                 columns,
                 source,
+                on_conflict: on.as_ref().map(translate_on_conflict).transpose()?,
+                returning: translate_returning(returning)?,
             })
         }
         SqlStatement::Update {
             table,
             assignments,
             selection,
+            returning,
             ..
         } => Ok(Statement::Update {
             table_name: translate_table_with_join(table)?,
@@ -68,9 +81,13 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .map(translate_assignment)
                 .collect::<Result<_>>()?,
             selection: selection.as_ref().map(translate_expr).transpose()?,
+            returning: translate_returning(returning)?,
         }),
         SqlStatement::Delete(SqlDelete {
-            from, selection, ..
+            from,
+            selection,
+            returning,
+            ..
         }) => {
             let from = match from {
                 SqlFromTable::WithFromKeyword(from) => from,
@@ -87,6 +104,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             Ok(Statement::Delete {
                 table_name,
                 selection: selection.as_ref().map(translate_expr).transpose()?,
+                returning: translate_returning(returning)?,
             })
         }
         SqlStatement::CreateTable(SqlCreateTable {
@@ -104,12 +122,24 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .map(translate_column_def)
                 .collect::<Result<Vec<_>>>()?;
 
-            let columns = (!columns.is_empty()).then_some(columns);
-
             let name = translate_object_name(name)?;
 
+            let (primary_keys, constraints): (Vec<_>, Vec<_>) =
+                constraints.iter().partition(|constraint| {
+                    matches!(constraint, SqlTableConstraint::PrimaryKey { .. })
+                });
+
+            let primary_key = match primary_keys.as_slice() {
+                [] => None,
+                [SqlTableConstraint::PrimaryKey { columns, .. }] => Some(translate_idents(columns)),
+                _ => return Err(TranslateError::MultiplePrimaryKeysNotAllowed(name).into()),
+            };
+            let (columns, composite_primary_key) =
+                translate_primary_key(&name, columns, primary_key)?;
+            let columns = (!columns.is_empty()).then_some(columns);
+
             let foreign_keys = constraints
-                .iter()
+                .into_iter()
                 .map(translate_foreign_key)
                 .collect::<Result<Vec<_>>>()?;
 
@@ -125,6 +155,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                     .as_ref()
                     .map(|table_engine| table_engine.name.to_owned()),
                 foreign_keys,
+                composite_primary_key,
                 comment: comment.as_ref().map(|comment| match comment {
                     SqlCommentDef::WithEq(comment)
                     | SqlCommentDef::WithoutEq(comment)
@@ -149,6 +180,12 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 operation: translate_alter_table_operation(operation)?,
             })
         }
+        SqlStatement::Truncate { table_names, .. } => Ok(Statement::Truncate {
+            table_names: table_names
+                .iter()
+                .map(|target| translate_object_name(&target.name))
+                .collect::<Result<Vec<_>>>()?,
+        }),
         SqlStatement::Drop {
             object_type: SqlObjectType::Table,
             if_exists,
@@ -184,6 +221,10 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 return Err(TranslateError::CompositeIndexNotSupported.into());
             }
 
+            if columns[0].nulls_first.is_some() {
+                return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
+            }
+
             let Some(name) = name else {
                 return Err(TranslateError::UnsupportedUnnamedIndex.into());
             };
@@ -327,6 +368,50 @@ fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
     }
 }
 
+fn translate_on_conflict(sql_on_insert: &SqlOnInsert) -> Result<OnConflict> {
+    let SqlOnConflict {
+        conflict_target,
+        action,
+    } = match sql_on_insert {
+        SqlOnInsert::OnConflict(on_conflict) => on_conflict,
+        _ => {
+            return Err(TranslateError::UnsupportedOnInsert(sql_on_insert.to_string()).into());
+        }
+    };
+
+    let target = match conflict_target {
+        None => Vec::new(),
+        Some(SqlConflictTarget::Columns(columns)) => translate_idents(columns),
+        Some(SqlConflictTarget::OnConstraint(name)) => {
+            return Err(TranslateError::ConflictOnConstraintNotSupported(name.to_string()).into());
+        }
+    };
+
+    let action = match action {
+        SqlOnConflictAction::DoNothing => OnConflictAction::DoNothing,
+        SqlOnConflictAction::DoUpdate(SqlDoUpdate {
+            assignments,
+            selection,
+        }) => OnConflictAction::DoUpdate {
+            assignments: assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+            selection: selection.as_ref().map(translate_expr).transpose()?,
+        },
+    };
+
+    Ok(OnConflict { target, action })
+}
+
+fn translate_returning(returning: &Option<Vec<SqlSelectItem>>) -> Result<Vec<SelectItem>> {
+    returning
+        .iter()
+        .flatten()
+        .map(translate_select_item)
+        .collect()
+}
+
 fn translate_object_name(sql_object_name: &SqlObjectName) -> Result<String> {
     let sql_object_name = &sql_object_name.0;
     if sql_object_name.len() > 1 {
@@ -357,6 +442,51 @@ pub fn translate_referential_action(
     }
 }
 
+/// Applies a table-level `PRIMARY KEY` to the column definitions.
+///
+/// A key of a single column becomes that column's own `PRIMARY KEY` option. The columns of a
+/// composite key are only made `NOT NULL`, and the key itself is returned to be kept in the schema.
+/// A table may declare its primary key only once, either on a single column or as a constraint.
+fn translate_primary_key(
+    table_name: &str,
+    mut columns: Vec<ColumnDef>,
+    primary_key: Option<Vec<String>>,
+) -> Result<(Vec<ColumnDef>, Option<Vec<String>>)> {
+    let num_primary_columns = columns
+        .iter()
+        .filter(|column_def| column_def.unique == Some(ColumnUniqueOption { is_primary: true }))
+        .count();
+
+    let primary_key = match (num_primary_columns, primary_key) {
+        (0, None) | (1, None) => return Ok((columns, None)),
+        (0, Some(primary_key)) => primary_key,
+        _ => {
+            return Err(
+                TranslateError::MultiplePrimaryKeysNotAllowed(table_name.to_owned()).into(),
+            );
+        }
+    };
+
+    if let [column_name] = primary_key.as_slice() {
+        if let Some(column_def) = columns
+            .iter_mut()
+            .find(|column_def| &column_def.name == column_name)
+        {
+            column_def.nullable = false;
+            column_def.unique = Some(ColumnUniqueOption { is_primary: true });
+
+            return Ok((columns, None));
+        }
+    }
+
+    columns
+        .iter_mut()
+        .filter(|column_def| primary_key.contains(&column_def.name))
+        .for_each(|column_def| column_def.nullable = false);
+
+    Ok((columns, Some(primary_key)))
+}
+
 pub fn translate_foreign_key(table_constraint: &SqlTableConstraint) -> Result<ForeignKey> {
     match table_constraint {
         SqlTableConstraint::ForeignKey {
@@ -1,10 +1,18 @@
+// the derived impls match on the deprecated variants as well
+#![allow(deprecated)]
+
 use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum TranslateError {
+    /// No longer returned, comma separated tables in `FROM` are translated into cross joins.
+    #[deprecated(note = "comma separated tables in `FROM` are translated into cross joins")]
     #[error("unimplemented - select on two or more than tables are not supported")]
     TooManyTables,
 
+    #[error("RIGHT or FULL JOIN of a table listed after a comma is not supported: {0}")]
+    OuterJoinAfterCommaNotSupported(String),
+
     #[error("unimplemented - SELECT DISTINCT ON is not supported")]
     SelectDistinctOnNotSupported,
 
@@ -62,6 +70,12 @@ pub enum TranslateError {
     #[error("INSERT INTO {0} DEFAULT VALUES is not supported")]
     DefaultValuesOnInsertNotSupported(String),
 
+    #[error("unimplemented - ON CONFLICT ON CONSTRAINT is not supported: {0}")]
+    ConflictOnConstraintNotSupported(String),
+
+    #[error("unsupported insert conflict clause: {0}")]
+    UnsupportedOnInsert(String),
+
     #[error("empty function body is not supported")]
     UnsupportedEmptyFunctionBody,
 
@@ -73,6 +87,9 @@ pub enum TranslateError {
     )]
     UnsupportedTrimChars,
 
+    #[error("LIKE escape must be a single character: '{0}'")]
+    InvalidLikeEscapeChar(String),
+
     #[error("unsupported CAST format: {0}")]
     UnsupportedCastFormat(String),
 
@@ -182,4 +199,7 @@ pub enum TranslateError {
 
     #[error("unsupported constraint: {0}")]
     UnsupportedConstraint(String),
+
+    #[error("multiple primary keys for table '{0}' are not allowed")]
+    MultiplePrimaryKeysNotAllowed(String),
 }
@@ -18,7 +18,7 @@ use {
     sqlparser::ast::{
         Array, CeilFloorKind as SqlCeilFloorKind, DateTimeField as SqlDateTimeField,
         Expr as SqlExpr, Interval as SqlInterval, OrderByExpr as SqlOrderByExpr,
-        Subscript as SqlSubscript,
+        Subscript as SqlSubscript, Value as SqlValue,
     },
 };
 
@@ -76,23 +76,31 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             expr,
             negated,
             pattern,
-            escape_char: None,
+            escape_char,
             ..
         } => Ok(Expr::Like {
             expr: translate_expr(expr).map(Box::new)?,
             negated: *negated,
             pattern: translate_expr(pattern).map(Box::new)?,
+            escape: escape_char
+                .as_deref()
+                .map(translate_escape_char)
+                .transpose()?,
         }),
         SqlExpr::ILike {
             expr,
             negated,
             pattern,
-            escape_char: None,
+            escape_char,
             ..
         } => Ok(Expr::ILike {
             expr: translate_expr(expr).map(Box::new)?,
             negated: *negated,
             pattern: translate_expr(pattern).map(Box::new)?,
+            escape: escape_char
+                .as_deref()
+                .map(translate_escape_char)
+                .transpose()?,
         }),
         SqlExpr::BinaryOp { left, op, right } => Ok(Expr::BinaryOp {
             left: translate_expr(left).map(Box::new)?,
@@ -105,6 +113,9 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
         }),
         SqlExpr::Extract { field, expr, .. } => translate_extract(field, expr),
         SqlExpr::Nested(expr) => translate_expr(expr).map(Box::new).map(Expr::Nested),
+        SqlExpr::Value(SqlValue::Placeholder(placeholder)) if placeholder == "?" => {
+            Ok(Expr::Placeholder)
+        }
         SqlExpr::Value(value) => translate_ast_literal(value).map(Expr::Literal),
         SqlExpr::TypedString { data_type, value } => Ok(Expr::TypedString {
             data_type: translate_data_type(data_type)?,
@@ -213,6 +224,16 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
     }
 }
 
+/// `ESCAPE` of LIKE and ILIKE takes exactly one character.
+fn translate_escape_char(escape_char: &str) -> Result<char> {
+    let mut chars = escape_char.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(TranslateError::InvalidLikeEscapeChar(escape_char.to_owned()).into()),
+    }
+}
+
 pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<OrderByExpr> {
     let SqlOrderByExpr {
         expr,
@@ -221,12 +242,9 @@ pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<Ord
         ..
     } = sql_order_by_expr;
 
-    if nulls_first.is_some() {
-        return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
-    }
-
     Ok(OrderByExpr {
         expr: translate_expr(expr)?,
         asc: *asc,
+        nulls_first: *nulls_first,
     })
 }
@@ -0,0 +1,258 @@
+use {
+    crate::{
+        ast::{
+            AggregateFunction, Assignment, CountArgExpr, Expr, Join, JoinConstraint, JoinOperator,
+            OnConflict, OnConflictAction, Query, Select, SelectItem, SetExpr, Statement,
+            TableFactor, Values,
+        },
+        data::Value,
+        result::{Error, Result},
+    },
+    std::vec::IntoIter,
+};
+
+/// Replaces each [`Expr::Placeholder`] of `statements` with the next value of `params`,
+/// in the order the placeholders are written.
+///
+/// Only the placeholders of queries, `INSERT`, `UPDATE` and `DELETE` are bound.
+/// Fails with [`Error::ParamsNotMatched`] when the number of placeholders and `params` differ.
+pub fn bind_params(statements: &mut [Statement], params: Vec<Value>) -> Result<()> {
+    let num_params = params.len();
+    let mut binder = Binder {
+        params: params.into_iter(),
+        placeholders: 0,
+    };
+
+    statements
+        .iter_mut()
+        .for_each(|statement| binder.statement(statement));
+
+    if binder.placeholders != num_params {
+        return Err(Error::ParamsNotMatched {
+            placeholders: binder.placeholders,
+            params: num_params,
+        });
+    }
+
+    Ok(())
+}
+
+struct Binder {
+    params: IntoIter<Value>,
+    placeholders: usize,
+}
+
+impl Binder {
+    fn statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Query(query) => self.query(query),
+            Statement::Insert {
+                source,
+                on_conflict,
+                returning,
+                ..
+            } => {
+                self.query(source);
+
+                if let Some(OnConflict {
+                    action:
+                        OnConflictAction::DoUpdate {
+                            assignments,
+                            selection,
+                        },
+                    ..
+                }) = on_conflict
+                {
+                    self.assignments(assignments);
+                    self.exprs(selection);
+                }
+
+                self.select_items(returning);
+            }
+            Statement::Update {
+                assignments,
+                selection,
+                returning,
+                ..
+            } => {
+                self.assignments(assignments);
+                self.exprs(selection);
+                self.select_items(returning);
+            }
+            Statement::Delete {
+                selection,
+                returning,
+                ..
+            } => {
+                self.exprs(selection);
+                self.select_items(returning);
+            }
+            _ => {}
+        }
+    }
+
+    fn query(&mut self, query: &mut Query) {
+        let Query {
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        self.set_expr(body);
+        order_by
+            .iter_mut()
+            .for_each(|order_by| self.expr(&mut order_by.expr));
+        self.exprs(limit);
+        self.exprs(offset);
+    }
+
+    fn set_expr(&mut self, set_expr: &mut SetExpr) {
+        match set_expr {
+            SetExpr::Select(select) => self.select(select),
+            SetExpr::Values(Values(rows)) => {
+                rows.iter_mut().flatten().for_each(|expr| self.expr(expr))
+            }
+            SetExpr::SetOperation { left, right, .. } => {
+                self.set_expr(left);
+                self.set_expr(right);
+            }
+        }
+    }
+
+    fn select(&mut self, select: &mut Select) {
+        let Select {
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+            ..
+        } = select;
+
+        self.select_items(projection);
+        self.table_factor(&mut from.relation);
+        from.joins.iter_mut().for_each(|join| self.join(join));
+        self.exprs(selection);
+        group_by.iter_mut().for_each(|expr| self.expr(expr));
+        self.exprs(having);
+    }
+
+    fn select_items(&mut self, items: &mut [SelectItem]) {
+        for item in items {
+            if let SelectItem::Expr { expr, .. } = item {
+                self.expr(expr);
+            }
+        }
+    }
+
+    fn assignments(&mut self, assignments: &mut [Assignment]) {
+        assignments
+            .iter_mut()
+            .for_each(|assignment| self.expr(&mut assignment.value));
+    }
+
+    fn table_factor(&mut self, table_factor: &mut TableFactor) {
+        match table_factor {
+            TableFactor::Derived { subquery, .. } => self.query(subquery),
+            TableFactor::Series { size, .. } => self.expr(size),
+            TableFactor::Table { .. } | TableFactor::Dictionary { .. } => {}
+        }
+    }
+
+    fn join(&mut self, join: &mut Join) {
+        self.table_factor(&mut join.relation);
+
+        match &mut join.join_operator {
+            JoinOperator::Inner(JoinConstraint::On(expr))
+            | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+            | JoinOperator::RightOuter(JoinConstraint::On(expr))
+            | JoinOperator::FullOuter(JoinConstraint::On(expr)) => self.expr(expr),
+            _ => {}
+        }
+    }
+
+    fn exprs<'a>(&mut self, exprs: impl IntoIterator<Item = &'a mut Expr>) {
+        exprs.into_iter().for_each(|expr| self.expr(expr));
+    }
+
+    fn expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Placeholder => {
+                self.placeholders += 1;
+
+                if let Some(param) = self.params.next() {
+                    *expr = Expr::Value(Box::new(param));
+                }
+            }
+            Expr::Identifier(_)
+            | Expr::CompoundIdentifier { .. }
+            | Expr::Literal(_)
+            | Expr::TypedString { .. }
+            | Expr::Value(_) => {}
+            Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Nested(expr)
+            | Expr::Interval { expr, .. } => self.expr(expr),
+            Expr::InList { expr, list, .. } => {
+                self.expr(expr);
+                self.exprs(list);
+            }
+            Expr::InSubquery { expr, subquery, .. } => {
+                self.expr(expr);
+                self.query(subquery);
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                self.expr(expr);
+                self.expr(low);
+                self.expr(high);
+            }
+            Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+                self.expr(expr);
+                self.expr(pattern);
+            }
+            Expr::BinaryOp { left, right, .. } => {
+                self.expr(left);
+                self.expr(right);
+            }
+            Expr::Function(function) => self.exprs(function.as_exprs_mut()),
+            Expr::Aggregate(aggregate) => match &mut aggregate.func {
+                AggregateFunction::Count(CountArgExpr::Expr(expr))
+                | AggregateFunction::Sum(expr)
+                | AggregateFunction::Max(expr)
+                | AggregateFunction::Min(expr)
+                | AggregateFunction::Avg(expr)
+                | AggregateFunction::Variance(expr)
+                | AggregateFunction::Stdev(expr) => self.expr(expr),
+                AggregateFunction::Count(CountArgExpr::Wildcard) => {}
+            },
+            Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => self.query(subquery),
+            Expr::Case {
+                operand,
+                when_then,
+                else_result,
+            } => {
+                if let Some(operand) = operand {
+                    self.expr(operand);
+                }
+
+                for (when, then) in when_then {
+                    self.expr(when);
+                    self.expr(then);
+                }
+
+                if let Some(else_result) = else_result {
+                    self.expr(else_result);
+                }
+            }
+            Expr::ArrayIndex { obj, indexes } => {
+                self.expr(obj);
+                self.exprs(indexes);
+            }
+            Expr::Array { elem } => self.exprs(elem),
+        }
+    }
+}
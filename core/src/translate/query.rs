@@ -6,7 +6,8 @@ use {
     crate::{
         ast::{
             AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins,
+            Values,
         },
         result::Result,
     },
@@ -14,7 +15,8 @@ use {
         Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
         GroupByExpr as SqlGroupByExpr, Join as SqlJoin, JoinConstraint as SqlJoinConstraint,
         JoinOperator as SqlJoinOperator, Query as SqlQuery, Select as SqlSelect,
-        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, TableAlias as SqlTableAlias,
+        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, SetOperator as SqlSetOperator,
+        SetQuantifier as SqlSetQuantifier, TableAlias as SqlTableAlias,
         TableFactor as SqlTableFactor, TableFunctionArgs as SqlTableFunctionArgs,
         TableWithJoins as SqlTableWithJoins,
     },
@@ -57,6 +59,36 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Union => SetOperator::Union,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+                SqlSetOperator::Except => SetOperator::Except,
+            };
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                SqlSetQuantifier::ByName
+                | SqlSetQuantifier::AllByName
+                | SqlSetQuantifier::DistinctByName => {
+                    return Err(
+                        TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                    );
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all,
+                left: translate_set_expr(left).map(Box::new)?,
+                right: translate_set_expr(right).map(Box::new)?,
+            })
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
@@ -72,10 +104,6 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         ..
     } = sql_select;
 
-    if from.len() > 1 {
-        return Err(TranslateError::TooManyTables.into());
-    }
-
     let distinct = match distinct {
         Some(SqlDistinct::Distinct) => true,
         Some(SqlDistinct::On(_)) => {
@@ -84,8 +112,45 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         None => false,
     };
 
-    let from = match from.first() {
-        Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
+    let without_from = from.is_empty();
+    let from = match from.split_first() {
+        Some((sql_table_with_joins, sql_tables_with_joins)) => {
+            let TableWithJoins {
+                relation,
+                mut joins,
+            } = translate_table_with_joins(sql_table_with_joins)?;
+
+            // `FROM A, B` is a cross join of A and B
+            for sql_table_with_joins in sql_tables_with_joins {
+                // `A, B RIGHT JOIN C` joins A with `B RIGHT JOIN C`, which cannot be flattened
+                // into `A CROSS JOIN B RIGHT JOIN C`
+                if sql_table_with_joins.joins.iter().any(|join| {
+                    matches!(
+                        join.join_operator,
+                        SqlJoinOperator::RightOuter(_) | SqlJoinOperator::FullOuter(_)
+                    )
+                }) {
+                    return Err(TranslateError::OuterJoinAfterCommaNotSupported(
+                        sql_table_with_joins.to_string(),
+                    )
+                    .into());
+                }
+
+                let TableWithJoins {
+                    relation,
+                    joins: table_joins,
+                } = translate_table_with_joins(sql_table_with_joins)?;
+
+                joins.push(Join {
+                    relation,
+                    join_operator: JoinOperator::Inner(JoinConstraint::None),
+                    join_executor: JoinExecutor::NestedLoop,
+                });
+                joins.extend(table_joins);
+            }
+
+            TableWithJoins { relation, joins }
+        }
         None => TableWithJoins {
             relation: TableFactor::Series {
                 alias: TableAlias {
@@ -112,6 +177,7 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
             .map(translate_select_item)
             .collect::<Result<_>>()?,
         from,
+        without_from,
         selection: selection.as_ref().map(translate_expr).transpose()?,
         group_by: group_by.iter().map(translate_expr).collect::<Result<_>>()?,
         having: having.as_ref().map(translate_expr).transpose()?,
@@ -271,6 +337,13 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
         SqlJoinOperator::LeftOuter(sql_join_constraint) => {
             translate_constraint(sql_join_constraint).map(JoinOperator::LeftOuter)
         }
+        SqlJoinOperator::RightOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::RightOuter)
+        }
+        SqlJoinOperator::FullOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::FullOuter)
+        }
+        SqlJoinOperator::CrossJoin => Ok(JoinOperator::Inner(JoinConstraint::None)),
         _ => Err(TranslateError::UnsupportedJoinOperator(format!("{sql_join_operator:?}")).into()),
     }?;
 
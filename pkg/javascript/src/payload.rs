@@ -84,6 +84,11 @@ fn convert_payload(payload: Payload) -> Json {
             "type": "INSERT",
             "affected": num
         }),
+        Payload::Upsert { inserted, updated } => json!({
+            "type": "UPSERT",
+            "inserted": inserted,
+            "updated": updated
+        }),
         Payload::Update(num) => json!({
             "type": "UPDATE",
             "affected": num
@@ -92,6 +97,7 @@ fn convert_payload(payload: Payload) -> Json {
             "type": "DELETE",
             "affected": num
         }),
+        Payload::Truncate => json!({ "type": "TRUNCATE" }),
         Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
         Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
         Payload::DropIndex => json!({ "type": "DROP INDEX" }),
@@ -91,6 +91,11 @@ fn convert_payload(payload: Payload) -> Json {
             "type": "INSERT",
             "affected": num
         }),
+        Payload::Upsert { inserted, updated } => json!({
+            "type": "UPSERT",
+            "inserted": inserted,
+            "updated": updated
+        }),
         Payload::Update(num) => json!({
             "type": "UPDATE",
             "affected": num
@@ -99,6 +104,7 @@ fn convert_payload(payload: Payload) -> Json {
             "type": "DELETE",
             "affected": num
         }),
+        Payload::Truncate => json!({ "type": "TRUNCATE" }),
         Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
         Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
         Payload::DropIndex => json!({ "type": "DROP INDEX" }),
@@ -2,6 +2,8 @@
 use {
     futures::executor::block_on,
     gluesql_core::{
+        data::Interval,
+        error::{Error, FetchError},
         executor::Payload,
         prelude::{Glue, Value},
         store::{GStore, GStoreMut},
@@ -55,6 +57,226 @@ async fn basic<T: GStore + GStoreMut>(mut glue: Glue<T>) {
     );
 }
 
+async fn batch<T: GStore + GStoreMut>(mut glue: Glue<T>) {
+    assert_eq!(
+        glue.execute_batch(
+            "
+                CREATE TABLE batch_test (id INTEGER, name TEXT);
+                INSERT INTO batch_test VALUES (1, 'a'), (2, 'b');
+                SELECT id FROM batch_test WHERE id > 1;
+            "
+        )
+        .await,
+        Ok(vec![
+            Payload::Create,
+            Payload::Insert(2),
+            Payload::Select {
+                labels: vec![String::from("id")],
+                rows: vec![vec![Value::I64(2)]],
+            },
+        ])
+    );
+
+    assert_eq!(
+        glue.execute_batch(
+            "
+                INSERT INTO batch_test VALUES (3, 'c');
+                SELECT * FROM missing;
+                INSERT INTO batch_test VALUES (4, 'd');
+            "
+        )
+        .await,
+        Err(Error::Batch {
+            index: 1,
+            error: Box::new(FetchError::TableNotFound(String::from("missing")).into()),
+        })
+    );
+
+    assert_eq!(
+        glue.execute_batch("SELECT COUNT(*) AS count FROM batch_test")
+            .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("count")],
+            rows: vec![vec![Value::I64(3)]],
+        }])
+    );
+}
+
+async fn params<T: GStore + GStoreMut>(mut glue: Glue<T>) {
+    glue.execute("CREATE TABLE params_test (id INTEGER, name TEXT, created DATE NULL)")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        glue.execute_with_params(
+            "INSERT INTO params_test VALUES (?, ?, ?), (?, ?, NULL)",
+            vec![
+                Value::I64(1),
+                Value::Str(String::from("it's")),
+                Value::Date("2024-01-02".parse().unwrap()),
+                Value::I64(-2),
+                Value::Str(String::from("'); DROP TABLE params_test; --")),
+            ],
+        )
+        .await,
+        Ok(vec![Payload::Insert(2)])
+    );
+
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT id, name FROM params_test WHERE id = ? OR created = ?",
+            vec![Value::I64(-2), Value::Date("2024-01-02".parse().unwrap())],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("id"), String::from("name")],
+            rows: vec![
+                vec![Value::I64(1), Value::Str(String::from("it's"))],
+                vec![
+                    Value::I64(-2),
+                    Value::Str(String::from("'); DROP TABLE params_test; --"))
+                ],
+            ]
+        }])
+    );
+
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT id FROM params_test WHERE created = ?",
+            vec![Value::Str(String::from("2024-01-02"))],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("id")],
+            rows: vec![vec![Value::I64(1)]],
+        }])
+    );
+
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT id FROM params_test WHERE name = '?' OR id - ? = 0",
+            vec![Value::I64(-2)],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("id")],
+            rows: vec![vec![Value::I64(-2)]],
+        }])
+    );
+
+    assert_eq!(
+        glue.execute_with_params("SELECT id FROM params_test WHERE id = ?", vec![])
+            .await,
+        Err(Error::ParamsNotMatched {
+            placeholders: 1,
+            params: 0,
+        })
+    );
+
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT id FROM params_test WHERE id = ?",
+            vec![Value::I64(1), Value::I64(2)],
+        )
+        .await,
+        Err(Error::ParamsNotMatched {
+            placeholders: 1,
+            params: 2,
+        })
+    );
+
+    let decimal = Value::Decimal("12345678901234567.89".parse().unwrap());
+    let interval = Value::Interval(Interval::Microsecond(1_500_000));
+    let quoted = Value::Str(String::from("it's"));
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT ? AS flt, ? AS inf, ? AS dec, ? AS itv, ? AS str",
+            vec![
+                Value::F64(1.0),
+                Value::F64(f64::INFINITY),
+                decimal.clone(),
+                interval.clone(),
+                quoted.clone(),
+            ],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![
+                String::from("flt"),
+                String::from("inf"),
+                String::from("dec"),
+                String::from("itv"),
+                String::from("str"),
+            ],
+            rows: vec![vec![
+                Value::F64(1.0),
+                Value::F64(f64::INFINITY),
+                decimal,
+                interval,
+                quoted,
+            ]],
+        }])
+    );
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT TRIM(LEADING ? FROM ?) AS trimmed",
+            vec![
+                Value::Str(String::from("_")),
+                Value::Str(String::from("__glue"))
+            ],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("trimmed")],
+            rows: vec![vec![Value::Str(String::from("glue"))]],
+        }])
+    );
+
+    // `?` is a placeholder even right before an operator
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT ?||'x' AS concat, 0<? AS lt, 3-?*2 AS calc, ?&? AS bit",
+            vec![
+                Value::Str(String::from("a")),
+                Value::I64(1),
+                Value::I64(1),
+                Value::I64(5),
+                Value::I64(6),
+            ],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![
+                String::from("concat"),
+                String::from("lt"),
+                String::from("calc"),
+                String::from("bit"),
+            ],
+            rows: vec![vec![
+                Value::Str(String::from("ax")),
+                Value::Bool(true),
+                Value::I64(1),
+                Value::I64(4),
+            ]],
+        }])
+    );
+
+    assert_eq!(
+        glue.execute_with_params(
+            "SELECT id\nFROM params_test\nWHERE id = ?\n  OR name = ?",
+            vec![
+                Value::I64(1),
+                Value::Str(String::from("'); DROP TABLE params_test; --"))
+            ],
+        )
+        .await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("id")],
+            rows: vec![vec![Value::I64(1)], vec![Value::I64(-2)]],
+        }])
+    );
+}
+
 #[cfg(feature = "gluesql_sled_storage")]
 #[test]
 fn sled_basic() {
@@ -80,3 +302,25 @@ fn memory_basic() {
 
     block_on(basic(glue));
 }
+
+#[cfg(feature = "gluesql_memory_storage")]
+#[test]
+fn memory_batch() {
+    use gluesql_memory_storage::MemoryStorage;
+
+    let storage = MemoryStorage::default();
+    let glue = Glue::new(storage);
+
+    block_on(batch(glue));
+}
+
+#[cfg(feature = "gluesql_memory_storage")]
+#[test]
+fn memory_params() {
+    use gluesql_memory_storage::MemoryStorage;
+
+    let storage = MemoryStorage::default();
+    let glue = Glue::new(storage);
+
+    block_on(params(glue));
+}
@@ -54,7 +54,8 @@ impl JsonStorage {
         }
 
         let schema_path = self.schema_path(table_name);
-        let (column_defs, foreign_keys, comment) = match schema_path.exists() {
+        let (column_defs, foreign_keys, composite_primary_key, comment) = match schema_path.exists()
+        {
             true => {
                 let mut file = File::open(&schema_path).map_storage_err()?;
                 let mut ddl = String::new();
@@ -67,9 +68,14 @@ impl JsonStorage {
                     ));
                 }
 
-                (schema.column_defs, schema.foreign_keys, schema.comment)
+                (
+                    schema.column_defs,
+                    schema.foreign_keys,
+                    schema.composite_primary_key,
+                    schema.comment,
+                )
             }
-            false => (None, Vec::new(), None),
+            false => (None, Vec::new(), None, None),
         };
 
         Ok(Some(Schema {
@@ -78,6 +84,7 @@ impl JsonStorage {
             indexes: vec![],
             engine: None,
             foreign_keys,
+            composite_primary_key,
             comment,
         }))
     }
@@ -1,5 +1,5 @@
 use {
-    super::MemoryStorage,
+    super::{IndexData, MemoryStorage},
     async_trait::async_trait,
     gluesql_core::{
         ast::ColumnDef,
@@ -54,6 +54,16 @@ impl AlterTable for MemoryStorage {
 
         new_column_name.clone_into(&mut column_def.name);
 
+        for index in item.schema.indexes.iter_mut() {
+            index.rename_column(old_column_name, new_column_name);
+        }
+
+        for column_name in item.schema.composite_primary_key.iter_mut().flatten() {
+            if column_name == old_column_name {
+                new_column_name.clone_into(column_name);
+            }
+        }
+
         Ok(())
     }
 
@@ -165,4 +175,16 @@ impl AlterTable for MemoryStorage {
 
         Ok(())
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        item.rows.clear();
+        item.indexes.values_mut().for_each(IndexData::clear);
+
+        Ok(())
+    }
 }
@@ -1,26 +1,147 @@
 use {
-    super::MemoryStorage,
+    super::{Item, MemoryStorage},
     async_trait::async_trait,
+    futures::stream::iter,
     gluesql_core::{
         ast::{IndexOperator, OrderByExpr},
-        data::Value,
-        error::{Error, Result},
-        store::{Index, IndexMut, RowIter},
+        chrono::Utc,
+        data::{Key, SchemaIndex, SchemaIndexOrd, Value},
+        error::{IndexError, Result},
+        executor::evaluate_stateless,
+        store::{DataRow, Index, IndexMut, RowIter},
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        ops::Bound,
     },
 };
 
+/// Data keys of an index, grouped by the big-endian bytes of their index value.
+pub type IndexData = BTreeMap<Vec<u8>, BTreeSet<Key>>;
+
+impl Item {
+    fn columns(&self) -> Option<Vec<String>> {
+        self.schema.column_defs.as_ref().map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.clone())
+                .collect()
+        })
+    }
+
+    async fn index_values(&self, row: &DataRow) -> Result<Vec<(String, Vec<u8>)>> {
+        let columns = self.columns();
+        let mut values = Vec::with_capacity(self.schema.indexes.len());
+
+        for index in &self.schema.indexes {
+            let value = evaluate_index_value(index, columns.as_deref(), row).await?;
+
+            values.push((index.name.clone(), value));
+        }
+
+        Ok(values)
+    }
+
+    /// Stores the row and registers its key in every index of the table.
+    pub(crate) async fn insert_row(&mut self, key: Key, row: DataRow) -> Result<()> {
+        let index_values = self.index_values(&row).await?;
+
+        self.remove_row(&key).await?;
+
+        for (index_name, value) in index_values {
+            self.indexes
+                .entry(index_name)
+                .or_default()
+                .entry(value)
+                .or_default()
+                .insert(key.clone());
+        }
+
+        self.rows.insert(key, row);
+
+        Ok(())
+    }
+
+    /// Removes the row and unregisters its key from every index of the table.
+    pub(crate) async fn remove_row(&mut self, key: &Key) -> Result<()> {
+        let Some(row) = self.rows.remove(key) else {
+            return Ok(());
+        };
+
+        for (index_name, value) in self.index_values(&row).await? {
+            let Some(data) = self.indexes.get_mut(&index_name) else {
+                continue;
+            };
+
+            if let Some(keys) = data.get_mut(&value) {
+                keys.remove(key);
+
+                if keys.is_empty() {
+                    data.remove(&value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn evaluate_index_value(
+    index: &SchemaIndex,
+    columns: Option<&[String]>,
+    row: &DataRow,
+) -> Result<Vec<u8>> {
+    let context = Some(row.as_context(columns));
+    let value: Value = evaluate_stateless(context, &index.expr).await?.try_into()?;
+
+    value.to_cmp_be_bytes()
+}
+
 #[async_trait]
 impl Index for MemoryStorage {
     async fn scan_indexed_data<'a>(
         &'a self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter<'a>> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let item = self
+            .items
+            .get(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+        let data = item
+            .indexes
+            .get(index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let range = match cmp_value {
+            None => (Bound::Unbounded, Bound::Unbounded),
+            Some((op, value)) => {
+                let value = value.to_cmp_be_bytes()?;
+
+                match op {
+                    IndexOperator::Eq => (Bound::Included(value.clone()), Bound::Included(value)),
+                    IndexOperator::Gt => (Bound::Excluded(value), Bound::Unbounded),
+                    IndexOperator::GtEq => (Bound::Included(value), Bound::Unbounded),
+                    IndexOperator::Lt => (Bound::Unbounded, Bound::Excluded(value)),
+                    IndexOperator::LtEq => (Bound::Unbounded, Bound::Included(value)),
+                }
+            }
+        };
+
+        let keys = data.range(range).flat_map(|(_, keys)| keys);
+        let keys: Vec<&Key> = match asc {
+            Some(true) | None => keys.collect(),
+            Some(false) => keys.rev().collect(),
+        };
+
+        let rows = keys
+            .into_iter()
+            .filter_map(|key| item.rows.get(key).map(|row| Ok((key.clone(), row.clone()))))
+            .collect::<Vec<_>>();
+
+        Ok(Box::pin(iter(rows)))
     }
 }
 
@@ -28,18 +149,63 @@ impl Index for MemoryStorage {
 impl IndexMut for MemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if item
+            .schema
+            .indexes
+            .iter()
+            .any(|index| index.name == index_name)
+        {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let index = SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+        };
+
+        let columns = item.columns();
+        let mut data = IndexData::new();
+        for (key, row) in &item.rows {
+            data.entry(evaluate_index_value(&index, columns.as_deref(), row).await?)
+                .or_default()
+                .insert(key.clone());
+        }
+
+        item.schema.indexes.push(index);
+        item.indexes.insert(index_name.to_owned(), data);
+
+        Ok(())
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if !item
+            .schema
+            .indexes
+            .iter()
+            .any(|index| index.name == index_name)
+        {
+            return Err(IndexError::IndexNameDoesNotExist(index_name.to_owned()).into());
+        }
+
+        item.schema.indexes.retain(|index| index.name != index_name);
+        item.indexes.remove(index_name);
+
+        Ok(())
     }
 }
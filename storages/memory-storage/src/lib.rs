@@ -5,6 +5,8 @@ mod index;
 mod metadata;
 mod transaction;
 
+pub use index::IndexData;
+
 use {
     async_trait::async_trait,
     futures::stream::iter,
@@ -22,6 +24,7 @@ use {
 pub struct Item {
     pub schema: Schema,
     pub rows: BTreeMap<Key, DataRow>,
+    pub indexes: HashMap<String, IndexData>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -118,6 +121,11 @@ impl StoreMut for MemoryStorage {
         let item = Item {
             schema: schema.clone(),
             rows: BTreeMap::new(),
+            indexes: schema
+                .indexes
+                .iter()
+                .map(|index| (index.name.clone(), IndexData::new()))
+                .collect(),
         };
         self.items.insert(table_name, item);
 
@@ -136,7 +144,7 @@ impl StoreMut for MemoryStorage {
             for row in rows {
                 self.id_counter += 1;
 
-                item.rows.insert(Key::I64(self.id_counter), row);
+                item.insert_row(Key::I64(self.id_counter), row).await?;
             }
         }
 
@@ -146,7 +154,7 @@ impl StoreMut for MemoryStorage {
     async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
         if let Some(item) = self.items.get_mut(table_name) {
             for (key, row) in rows {
-                item.rows.insert(key, row);
+                item.insert_row(key, row).await?;
             }
         }
 
@@ -156,7 +164,7 @@ impl StoreMut for MemoryStorage {
     async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
         if let Some(item) = self.items.get_mut(table_name) {
             for key in keys {
-                item.rows.remove(&key);
+                item.remove_row(&key).await?;
             }
         }
 
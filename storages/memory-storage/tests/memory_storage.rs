@@ -23,10 +23,16 @@ impl Tester<MemoryStorage> for MemoryTester {
 
 generate_store_tests!(tokio::test, MemoryTester);
 
+generate_index_tests!(tokio::test, MemoryTester);
+
 generate_alter_table_tests!(tokio::test, MemoryTester);
 
+generate_alter_table_index_tests!(tokio::test, MemoryTester);
+
 generate_metadata_table_tests!(tokio::test, MemoryTester);
 
+generate_metadata_index_tests!(tokio::test, MemoryTester);
+
 generate_custom_function_tests!(tokio::test, MemoryTester);
 
 macro_rules! exec {
@@ -44,7 +50,9 @@ macro_rules! test {
 #[tokio::test]
 async fn memory_storage_index() {
     use gluesql_core::{
-        prelude::{Error, Glue},
+        ast::IndexOperator,
+        error::IndexError,
+        prelude::{Glue, Payload, Value},
         store::{Index, Store},
     };
 
@@ -66,21 +74,48 @@ async fn memory_storage_index() {
             .scan_indexed_data("Idx", "hello", None, None)
             .await
             .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned()
-        ))
+        Err(IndexError::TableNotFound("Idx".to_owned()).into())
     );
 
     let mut glue = Glue::new(storage);
 
-    exec!(glue "CREATE TABLE Idx (id INTEGER);");
+    exec!(glue "CREATE TABLE Idx (id INTEGER, name TEXT);");
+    exec!(glue "INSERT INTO Idx VALUES (1, 'a'), (2, 'b'), (3, 'c');");
+    test!(glue "CREATE INDEX idx_id ON Idx (id);", Ok(vec![Payload::CreateIndex]));
     test!(
-        glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+        glue "CREATE INDEX idx_id ON Idx (name);",
+        Err(IndexError::IndexNameAlreadyExists("idx_id".to_owned()).into())
     );
+
+    exec!(glue "INSERT INTO Idx VALUES (4, 'd');");
+    exec!(glue "UPDATE Idx SET id = 20 WHERE id = 2;");
+    exec!(glue "DELETE FROM Idx WHERE id = 3;");
+
+    async fn scan(storage: &MemoryStorage, op: IndexOperator, value: i64) -> usize {
+        storage
+            .scan_indexed_data("Idx", "idx_id", None, Some((&op, Value::I64(value))))
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .len()
+    }
+
+    assert_eq!(scan(&glue.storage, IndexOperator::Eq, 1).await, 1);
+    assert_eq!(scan(&glue.storage, IndexOperator::Eq, 2).await, 0);
+    assert_eq!(scan(&glue.storage, IndexOperator::Eq, 3).await, 0);
+    assert_eq!(scan(&glue.storage, IndexOperator::Eq, 20).await, 1);
+    assert_eq!(scan(&glue.storage, IndexOperator::GtEq, 4).await, 2);
+    assert_eq!(scan(&glue.storage, IndexOperator::Lt, 20).await, 2);
+
+    exec!(glue "TRUNCATE TABLE Idx;");
+    assert_eq!(scan(&glue.storage, IndexOperator::GtEq, 0).await, 0);
+
+    test!(glue "DROP INDEX Idx.idx_id;", Ok(vec![Payload::DropIndex]));
     test!(
         glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+        Err(IndexError::IndexNameDoesNotExist("idx_id".to_owned()).into())
     );
 }
 
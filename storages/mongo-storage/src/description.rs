@@ -6,6 +6,7 @@ use {
 #[derive(Serialize, Deserialize)]
 pub struct TableDescription {
     pub foreign_keys: Vec<ForeignKey>,
+    pub composite_primary_key: Option<Vec<String>>,
     pub comment: Option<String>,
 }
 
@@ -277,6 +277,7 @@ impl MongoStorage {
             let table_description = validator.get_str("description").map_storage_err()?;
             let TableDescription {
                 foreign_keys,
+                composite_primary_key,
                 comment,
             } = from_str::<TableDescription>(table_description).map_storage_err()?;
 
@@ -286,6 +287,7 @@ impl MongoStorage {
                 indexes: Vec::new(),
                 engine: None,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
 
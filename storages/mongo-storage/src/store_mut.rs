@@ -122,7 +122,13 @@ impl StoreMut for MongoStorage {
             .unwrap_or_default();
 
         let comment = schema.comment.as_ref().map(ToOwned::to_owned);
-        let validator = Validator::new(labels, column_types, schema.foreign_keys.clone(), comment)?;
+        let validator = Validator::new(
+            labels,
+            column_types,
+            schema.foreign_keys.clone(),
+            schema.composite_primary_key.clone(),
+            comment,
+        )?;
 
         let schema_exists = self
             .fetch_schema(&schema.table_name)
@@ -24,6 +24,7 @@ impl Validator {
         labels: Vec<String>,
         column_types: Document,
         foreign_keys: Vec<ForeignKey>,
+        composite_primary_key: Option<Vec<String>>,
         comment: Option<String>,
     ) -> Result<Self> {
         let mut required = vec!["_id".to_owned()];
@@ -38,6 +39,7 @@ impl Validator {
         let table_description = to_string(
             &(TableDescription {
                 foreign_keys,
+                composite_primary_key,
                 comment,
             }),
         )
@@ -61,6 +61,7 @@ impl ParquetStorage {
 
         let mut is_schemaless = false;
         let mut foreign_keys = Vec::new();
+        let mut composite_primary_key = None;
         let mut comment = None;
         if let Some(metadata) = key_value_file_metadata {
             for kv in metadata.iter() {
@@ -68,6 +69,13 @@ impl ParquetStorage {
                     is_schemaless = matches!(kv.value.as_deref(), Some("true"));
                 } else if kv.key == "comment" {
                     comment.clone_from(&kv.value)
+                } else if kv.key == "composite_primary_key" {
+                    composite_primary_key = kv
+                        .value
+                        .as_deref()
+                        .map(from_str::<Vec<String>>)
+                        .transpose()
+                        .map_storage_err()?;
                 } else if kv.key.starts_with("foreign_key") {
                     let fk = kv
                         .value
@@ -106,6 +114,7 @@ impl ParquetStorage {
             indexes: vec![],
             engine: None,
             foreign_keys,
+            composite_primary_key,
             comment,
         }))
     }
@@ -192,6 +201,7 @@ impl ParquetStorage {
             indexes: vec![],
             engine: None,
             foreign_keys: Vec::new(),
+            composite_primary_key: None,
             comment: None,
         }
     }
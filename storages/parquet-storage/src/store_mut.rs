@@ -512,6 +512,13 @@ impl ParquetStorage {
             });
         }
 
+        if let Some(composite_primary_key) = &schema.composite_primary_key {
+            metadata.push(KeyValue {
+                key: "composite_primary_key".to_owned(),
+                value: Some(serde_json::to_string(composite_primary_key).map_storage_err()?),
+            });
+        }
+
         if schema.comment.is_some() {
             metadata.push(KeyValue {
                 key: "comment".to_owned(),
@@ -66,6 +66,12 @@ impl AlterTable for RedisStorage {
 
             new_column_name.clone_into(&mut column_def.name);
 
+            for column_name in schema.composite_primary_key.iter_mut().flatten() {
+                if column_name == old_column_name {
+                    new_column_name.clone_into(column_name);
+                }
+            }
+
             self.redis_delete_schema(table_name)?;
             self.redis_store_schema(&schema)?;
         } else {
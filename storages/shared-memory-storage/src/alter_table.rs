@@ -48,4 +48,11 @@ impl AlterTable for SharedMemoryStorage {
             .drop_column(table_name, column_name, if_exists)
             .await
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.truncate_data(table_name).await
+    }
 }
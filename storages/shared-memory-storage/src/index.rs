@@ -1,26 +1,35 @@
 use {
     super::SharedMemoryStorage,
     async_trait::async_trait,
+    futures::stream::{self, TryStreamExt},
     gluesql_core::{
         ast::{IndexOperator, OrderByExpr},
         data::Value,
-        error::{Error, Result},
+        error::Result,
         store::{Index, IndexMut, RowIter},
     },
+    std::sync::Arc,
 };
 
 #[async_trait]
 impl Index for SharedMemoryStorage {
     async fn scan_indexed_data<'a>(
         &'a self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter<'a>> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let database = self.database.read().await;
+        let rows = database
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(Ok);
+
+        Ok(Box::pin(stream::iter(rows)))
     }
 }
 
@@ -28,18 +37,20 @@ impl Index for SharedMemoryStorage {
 impl IndexMut for SharedMemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.create_index(table_name, index_name, column).await
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.drop_index(table_name, index_name).await
     }
 }
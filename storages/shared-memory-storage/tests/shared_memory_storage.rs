@@ -23,8 +23,12 @@ impl Tester<SharedMemoryStorage> for SharedMemoryTester {
 
 generate_store_tests!(tokio::test, SharedMemoryTester);
 
+generate_index_tests!(tokio::test, SharedMemoryTester);
+
 generate_alter_table_tests!(tokio::test, SharedMemoryTester);
 
+generate_alter_table_index_tests!(tokio::test, SharedMemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -40,8 +44,8 @@ macro_rules! test {
 #[tokio::test]
 async fn shared_memory_storage_index() {
     use gluesql_core::{
-        error::Error,
-        prelude::Glue,
+        error::IndexError,
+        prelude::{Glue, Payload},
         store::{Index, Store},
     };
 
@@ -64,22 +68,14 @@ async fn shared_memory_storage_index() {
             .scan_indexed_data("Idx", "hello", None, None)
             .await
             .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned()
-        ))
+        Err(IndexError::TableNotFound("Idx".to_owned()).into())
     );
 
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE Idx (id INTEGER);");
-    test!(
-        glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
-    );
-    test!(
-        glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
-    );
+    test!(glue "CREATE INDEX idx_id ON Idx (id);", Ok(vec![Payload::CreateIndex]));
+    test!(glue "DROP INDEX Idx.idx_id;", Ok(vec![Payload::DropIndex]));
 }
 
 #[tokio::test]
@@ -2,7 +2,9 @@ use {
     super::{
         SledStorage, Snapshot,
         error::err_into,
-        fetch_schema, key,
+        fetch_schema,
+        index_sync::IndexSync,
+        key,
         lock::{self, LockAcquired},
         transaction::TxPayload,
     },
@@ -15,7 +17,7 @@ use {
         executor::evaluate_stateless,
         store::{AlterTable, DataRow},
     },
-    sled::transaction::ConflictableTransactionError,
+    sled::transaction::{ConflictableTransactionError, ConflictableTransactionResult},
     std::{iter::once, str},
     utils::Vector,
 };
@@ -52,6 +54,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
                 ..
             } = old_schema
@@ -64,6 +67,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
 
@@ -164,6 +168,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment: schema_comment,
                 ..
             } = snapshot
@@ -208,6 +213,23 @@ impl AlterTable for SledStorage {
                 comment,
             };
             let column_defs = Vector::from(column_defs).update(i, column_def).into();
+            let indexes = indexes
+                .into_iter()
+                .map(|mut index| {
+                    index.rename_column(old_column_name, new_column_name);
+
+                    index
+                })
+                .collect();
+            let composite_primary_key = composite_primary_key.map(|column_names| {
+                column_names
+                    .into_iter()
+                    .map(|column_name| match column_name == old_column_name {
+                        true => new_column_name.to_owned(),
+                        false => column_name,
+                    })
+                    .collect()
+            });
 
             let schema = Schema {
                 table_name: table_name.to_owned(),
@@ -215,6 +237,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment: schema_comment,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
@@ -269,6 +292,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             } = schema_snapshot
                 .get(txid, None)
@@ -368,6 +392,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
@@ -425,6 +450,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             } = schema_snapshot
                 .get(txid, None)
@@ -507,6 +533,7 @@ impl AlterTable for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
@@ -528,4 +555,66 @@ impl AlterTable for SledStorage {
 
         Ok(())
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let prefix = key::data_prefix(table_name);
+        let items = self
+            .tree
+            .scan_prefix(prefix.as_bytes())
+            .map(|item| item.map_err(err_into))
+            .collect::<Result<Vec<_>>>()?;
+
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+        let tx_result = self.tree.transaction(move |tree| {
+            let (txid, autocommit) = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, autocommit } => (txid, autocommit),
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let index_sync = IndexSync::new(tree, txid, table_name)?;
+
+            // rows are deleted straight from the scanned snapshots, no keys are decoded
+            block_on(async {
+                for (key, snapshot) in items.iter() {
+                    let snapshot: Snapshot<DataRow> = bincode::deserialize(snapshot)
+                        .map_err(err_into)
+                        .map_err(ConflictableTransactionError::Abort)?;
+                    if snapshot.get(txid, None).is_none() {
+                        continue;
+                    }
+
+                    let (snapshot, row) = snapshot.delete(txid);
+                    let Some(row) = row else {
+                        continue;
+                    };
+
+                    let snapshot = bincode::serialize(&snapshot)
+                        .map_err(err_into)
+                        .map_err(ConflictableTransactionError::Abort)?;
+                    tree.insert(key, snapshot)?;
+
+                    index_sync.delete(key, &row).await?;
+
+                    if !autocommit {
+                        let temp_key = key::temp_data(txid, key);
+
+                        tree.insert(temp_key, key)?;
+                    }
+                }
+
+                Ok(()) as ConflictableTransactionResult<(), Error>
+            })?;
+
+            Ok(TxPayload::Success)
+        });
+
+        if self.check_retry(tx_result)? {
+            self.truncate_data(table_name).await?;
+        }
+
+        Ok(())
+    }
 }
@@ -74,6 +74,7 @@ impl IndexMut for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
                 ..
             } = schema
@@ -104,6 +105,7 @@ impl IndexMut for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
 
@@ -170,6 +172,7 @@ impl IndexMut for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
                 ..
             } = schema
@@ -195,6 +198,7 @@ impl IndexMut for SledStorage {
                 indexes,
                 engine,
                 foreign_keys,
+                composite_primary_key,
                 comment,
             };
 
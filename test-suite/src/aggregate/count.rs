@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
 
 test_case!(count, {
     let g = get_tester!();
@@ -79,4 +82,46 @@ test_case!(count, {
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
+
+    g.named_test(
+        "aggregate without GROUP BY over no rows returns a single row",
+        "SELECT COUNT(*), COUNT(age), SUM(id), AVG(id), MIN(id), MAX(id) FROM Item WHERE id > 100",
+        Ok(select_with_null!(
+            "COUNT(*)" | "COUNT(age)" | "SUM(id)" | "AVG(id)" | "MIN(id)" | "MAX(id)";
+            I64(0)       I64(0)         Null        Null        Null        Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "aggregate expression over no rows",
+        "SELECT COUNT(*) + 1 AS c FROM Item WHERE id > 100",
+        Ok(select!(c; I64; 1)),
+    )
+    .await;
+    g.named_test(
+        "HAVING still filters the single row of an empty aggregate",
+        "SELECT COUNT(*) FROM Item WHERE id > 100 HAVING COUNT(*) > 0",
+        Ok(Payload::Select {
+            labels: vec!["COUNT(*)".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+    g.named_test(
+        "aggregate with GROUP BY over no rows returns no rows",
+        "SELECT id, COUNT(*) FROM Item WHERE id > 100 GROUP BY id",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "COUNT(*)".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+
+    g.run("CREATE TABLE Empty (x INTEGER)").await;
+    g.named_test(
+        "aggregate in function arguments over an empty table",
+        "SELECT COALESCE(SUM(x), 0) AS s FROM Empty",
+        Ok(select!(s; I64; 0)),
+    )
+    .await;
 });
@@ -1,6 +1,10 @@
 use {
     crate::*,
-    gluesql_core::error::{EvaluateError, TranslateError},
+    gluesql_core::{
+        data::NumericBinaryOperator,
+        error::{EvaluateError, TranslateError, ValueError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(error, {
@@ -47,4 +51,32 @@ test_case!(error, {
     for (sql, error) in test_cases {
         g.test(sql, Err(error)).await;
     }
+
+    g.run("CREATE TABLE Word (name TEXT);").await;
+    g.run("INSERT INTO Word VALUES ('a'), ('c'), ('b');").await;
+
+    for sql in ["SELECT SUM(name) FROM Word;", "SELECT AVG(name) FROM Word;"] {
+        g.named_test(
+            "SUM and AVG require numeric values",
+            sql,
+            Err(ValueError::NonNumericMathOperation {
+                lhs: Str("a".to_owned()),
+                rhs: Str("c".to_owned()),
+                operator: NumericBinaryOperator::Add,
+            }
+            .into()),
+        )
+        .await;
+    }
+
+    g.named_test(
+        "MIN and MAX accept any comparable values",
+        "SELECT MIN(name), MAX(name) FROM Word;",
+        Ok(select!(
+            "MIN(name)" | "MAX(name)"
+            Str         | Str;
+            "a".to_owned() "c".to_owned()
+        )),
+    )
+    .await;
 });
@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::{error::PlanError, prelude::Value::*},
+};
 
 test_case!(group_by, {
     let g = get_tester!();
@@ -55,15 +58,19 @@ test_case!(group_by, {
             ),
         ),
         (
-            "SELECT id, city FROM Item GROUP BY city",
+            "SELECT COUNT(*) FROM Item GROUP BY city",
+            select!("COUNT(*)"; I64; 2; 1; 1; 1; 1),
+        ),
+        (
+            "SELECT Item.city, COUNT(*) FROM Item GROUP BY city",
             select!(
-                id  | city
-                I64 | Str;
-                1     "Seoul".to_owned();
-                2     "Dhaka".to_owned();
-                3     "Beijing".to_owned();
-                3     "Daejeon".to_owned();
-                5     "Seattle".to_owned()
+                city | "COUNT(*)"
+                Str  | I64;
+                "Seoul".to_owned()    2;
+                "Dhaka".to_owned()    1;
+                "Beijing".to_owned()  1;
+                "Daejeon".to_owned()  1;
+                "Seattle".to_owned()  1
             ),
         ),
         (
@@ -77,12 +84,21 @@ test_case!(group_by, {
             ),
         ),
         (
-            "SELECT ratio FROM Item GROUP BY id, city",
-            select!(ratio; F64; 0.2; 6.11; 1.1; 0.2; 1.1; 6.11),
+            "SELECT id, city FROM Item GROUP BY id, city",
+            select!(
+                id  | city
+                I64 | Str;
+                1     "Seoul".to_owned();
+                2     "Dhaka".to_owned();
+                3     "Beijing".to_owned();
+                3     "Daejeon".to_owned();
+                4     "Seoul".to_owned();
+                5     "Seattle".to_owned()
+            ),
         ),
         (
-            "SELECT id, ratio FROM Item GROUP BY id, city HAVING ratio > 6",
-            select!(id | ratio; I64 | F64; 2 6.11; 5 6.11),
+            "SELECT id, MAX(ratio) FROM Item GROUP BY id, city HAVING MAX(ratio) > 6",
+            select!(id | "MAX(ratio)"; I64 | F64; 2 6.11; 5 6.11),
         ),
         (
             "SELECT SUM(quantity), COUNT(*), city FROM Item GROUP BY city HAVING COUNT(*) > 1",
@@ -98,6 +114,129 @@ test_case!(group_by, {
         g.test(sql, Ok(expected)).await;
     }
 
+    g.named_test(
+        "GROUP BY expression",
+        "SELECT id % 2, COUNT(*) FROM Item GROUP BY id % 2",
+        Ok(select!(
+            "id % 2" | "COUNT(*)"
+            I64      | I64;
+            1          4;
+            0          2
+        )),
+    )
+    .await;
+    g.named_test(
+        "DISTINCT aggregates deduplicate values within each group",
+        "SELECT id % 2, COUNT(id), COUNT(DISTINCT id), SUM(DISTINCT id), COUNT(DISTINCT ratio) FROM Item GROUP BY id % 2",
+        Ok(select!(
+            "id % 2" | "COUNT(id)" | "COUNT(DISTINCT id)" | "SUM(DISTINCT id)" | "COUNT(DISTINCT ratio)"
+            I64      | I64         | I64                  | I64                | I64;
+            1          4             3                      9                    3;
+            0          2             2                      6                    2
+        )),
+    )
+    .await;
+    g.named_test(
+        "GROUP BY multiple expressions including NULL checks",
+        "SELECT city, quantity IS NULL, COUNT(*) FROM Item WHERE id = 3 GROUP BY city, quantity IS NULL",
+        Ok(select!(
+            city               | "quantity IS NULL" | "COUNT(*)"
+            Str                | Bool               | I64;
+            "Beijing".to_owned() true                 1;
+            "Daejeon".to_owned() false                1
+        )),
+    )
+    .await;
+    g.named_test(
+        "HAVING with an aggregate which is not projected",
+        "SELECT city FROM Item GROUP BY city HAVING MAX(ratio) > 6",
+        Ok(select!(city; Str; "Dhaka".to_owned(); "Seattle".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "HAVING without GROUP BY aggregates the whole table",
+        "SELECT 1 AS one FROM Item HAVING COUNT(*) > 3",
+        Ok(select!(one; I64; 1)),
+    )
+    .await;
+    g.named_test(
+        "HAVING with both grouped columns and aggregates",
+        "SELECT city, SUM(quantity) FROM Item GROUP BY city HAVING city <> 'Seoul' AND SUM(quantity) > 20",
+        Ok(select!(
+            city                 | "SUM(quantity)"
+            Str                  | I64;
+            "Daejeon".to_owned()   30;
+            "Seattle".to_owned()   24
+        )),
+    )
+    .await;
+    g.named_test(
+        "HAVING cannot reference a qualified column which is not grouped",
+        "SELECT city FROM Item GROUP BY city HAVING SUM(quantity) > Item.id",
+        Err(PlanError::ColumnNotInGroupBy("Item.id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "HAVING cannot reference a column which is not grouped",
+        "SELECT city, COUNT(*) FROM Item GROUP BY city HAVING ratio > 6",
+        Err(PlanError::ColumnNotInGroupBy("ratio".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "non-aggregated column must be listed in GROUP BY",
+        "SELECT id, city FROM Item GROUP BY city",
+        Err(PlanError::ColumnNotInGroupBy("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "non-aggregated column inside an expression must be listed in GROUP BY",
+        "SELECT city, quantity + 1 FROM Item GROUP BY city",
+        Err(PlanError::ColumnNotInGroupBy("quantity".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "qualified non-aggregated column must be listed in GROUP BY",
+        "SELECT Item.ratio FROM Item GROUP BY id, city",
+        Err(PlanError::ColumnNotInGroupBy("Item.ratio".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "wildcard expands to columns which must be listed in GROUP BY",
+        "SELECT * FROM Item GROUP BY city",
+        Err(PlanError::ColumnNotInGroupBy("Item.id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "qualified wildcard expands to columns which must be listed in GROUP BY",
+        "SELECT I.* FROM Item I GROUP BY id, quantity, city",
+        Err(PlanError::ColumnNotInGroupBy("I.ratio".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "wildcard is allowed when every column is grouped",
+        "SELECT * FROM Item WHERE id = 1 GROUP BY id, quantity, city, ratio",
+        Ok(select!(
+            id  | quantity | city               | ratio;
+            I64 | I64      | Str                | F64;
+            1     10         "Seoul".to_owned()   0.2
+        )),
+    )
+    .await;
+
+    g.run("INSERT INTO Item VALUES (6, NULL, 'Seoul', 0.5);")
+        .await;
+    g.named_test(
+        "NULL values are collected into a single group",
+        "SELECT quantity, COUNT(*) FROM Item WHERE city IN ('Seoul', 'Beijing') GROUP BY quantity",
+        Ok(select_with_null!(
+            quantity | "COUNT(*)";
+            I64(10)    I64(1);
+            Null       I64(2);
+            I64(11)    I64(1)
+        )),
+    )
+    .await;
+
     g.run("CREATE TABLE Sub (id INTEGER);").await;
     g.run("INSERT INTO Sub VALUES (101), (102), (103), (104), (105);")
         .await;
@@ -2,10 +2,12 @@ mod alter_table;
 mod create_table;
 mod drop_indexed;
 mod drop_table;
+mod rename_indexed;
 
 pub use {
-    alter_table::{alter_table_add_drop, alter_table_rename},
+    alter_table::{alter_table_add_drop, alter_table_drop_column, alter_table_rename},
     create_table::create_table,
     drop_indexed::{drop_indexed_column, drop_indexed_table},
     drop_table::drop_table,
+    rename_indexed::rename_indexed_column,
 };
@@ -3,7 +3,9 @@ use {
     gluesql_core::{
         ast::*,
         data::Value::*,
-        error::{AlterError, AlterTableError, EvaluateError, TranslateError},
+        error::{
+            AlterError, AlterTableError, EvaluateError, FetchError, TranslateError, ValidateError,
+        },
         executor::Referencing,
         prelude::Payload,
     },
@@ -33,6 +35,21 @@ test_case!(alter_table_rename, {
             Ok(Payload::AlterTable),
         ),
         ("SELECT new_id FROM Bar", Ok(select!(new_id; I64; 1; 2; 3))),
+        (
+            "SELECT id FROM Bar",
+            Err(EvaluateError::IdentifierNotFound("id".to_owned()).into()),
+        ),
+        (
+            "SELECT id FROM Foo",
+            Err(FetchError::TableNotFound("Foo".to_owned()).into()),
+        ),
+        ("CREATE TABLE Other (id INTEGER);", Ok(Payload::Create)),
+        (
+            "ALTER TABLE Bar RENAME TO Other;",
+            Err(AlterError::TableAlreadyExists("Other".to_owned()).into()),
+        ),
+        ("SELECT id FROM Other", Ok(select!(id))),
+        ("SELECT new_id FROM Bar", Ok(select!(new_id; I64; 1; 2; 3))),
         (
             "ALTER TABLE Bar RENAME COLUMN hello TO idid",
             Err(AlterTableError::RenamingColumnNotFound.into()),
@@ -42,6 +59,32 @@ test_case!(alter_table_rename, {
             "ALTER TABLE Bar RENAME COLUMN name TO new_id",
             Err(AlterTableError::AlreadyExistingColumn("new_id".to_owned()).into()),
         ),
+        (
+            "CREATE TABLE Pair (a INTEGER, b INTEGER, PRIMARY KEY (a, b));",
+            Ok(Payload::Create),
+        ),
+        ("INSERT INTO Pair VALUES (1, 1);", Ok(Payload::Insert(1))),
+        (
+            // renamed in the composite primary key as well
+            "ALTER TABLE Pair RENAME COLUMN b TO c",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "INSERT INTO Pair VALUES (1, 1);",
+            Err(ValidateError::DuplicateEntryOnCompositePrimaryKey(
+                vec![I64(1), I64(1)],
+                vec!["a".to_owned(), "c".to_owned()],
+            )
+            .into()),
+        ),
+        (
+            "ALTER TABLE Pair DROP COLUMN c",
+            Err(AlterError::CannotAlterPrimaryKeyColumn {
+                table_name: "Pair".to_owned(),
+                column_name: "c".to_owned(),
+            }
+            .into()),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -68,6 +111,14 @@ test_case!(alter_table_add_drop, {
             })
             .into()),
         ),
+        (
+            "ALTER TABLE Foo ADD COLUMN amount INTEGER NOT NULL DEFAULT NULL",
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "Foo".to_owned(),
+                column_name: "amount".to_owned(),
+            }
+            .into()),
+        ),
         (
             "ALTER TABLE Foo ADD COLUMN id INTEGER",
             Err(AlterTableError::AlreadyExistingColumn("id".to_owned()).into()),
@@ -104,6 +155,36 @@ test_case!(alter_table_add_drop, {
                 I64(2)   I64(10)   Null   Bool(true)
             )),
         ),
+        (
+            "ALTER TABLE Foo ADD COLUMN IF NOT EXISTS amount TEXT DEFAULT 'x'",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "ALTER TABLE Foo ADD COLUMN IF NOT EXISTS active BOOLEAN",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "SELECT * FROM Foo;",
+            Ok(select_with_null!(
+                id     | amount  | opt  | opt2         | active;
+                I64(1)   I64(10)   Null   Bool(true)     Null;
+                I64(2)   I64(10)   Null   Bool(true)     Null
+            )),
+        ),
+        (
+            "ALTER TABLE Foo DROP COLUMN active",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            // existing rows would all get the same value
+            "ALTER TABLE Foo ADD COLUMN code INTEGER UNIQUE DEFAULT 1",
+            Err(ValidateError::DuplicateEntryOnUniqueField(I64(1), "code".to_owned()).into()),
+        ),
+        (
+            "ALTER TABLE Foo ADD COLUMN code INTEGER UNIQUE",
+            Ok(Payload::AlterTable),
+        ),
+        ("ALTER TABLE Foo DROP COLUMN code", Ok(Payload::AlterTable)),
         (
             "ALTER TABLE Foo ADD COLUMN something INTEGER DEFAULT (SELECT id FROM Bar LIMIT 1)",
             Err(
@@ -255,3 +336,87 @@ test_case!(alter_table_add_drop, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(alter_table_drop_column, {
+    let g = get_tester!();
+
+    let test_cases = [
+        (
+            "CREATE TABLE Users (
+                id INTEGER PRIMARY KEY,
+                legacy TEXT,
+                name TEXT,
+                email TEXT UNIQUE
+            );",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO Users VALUES (1, 'x', 'Alice', 'a@gluesql.org'), (2, 'y', 'Bob', 'b@gluesql.org');",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            // values after the dropped column shift to the left
+            "ALTER TABLE Users DROP COLUMN legacy",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "SELECT * FROM Users",
+            Ok(select!(
+                id  | name               | email
+                I64 | Str                | Str;
+                1     "Alice".to_owned()   "a@gluesql.org".to_owned();
+                2     "Bob".to_owned()     "b@gluesql.org".to_owned()
+            )),
+        ),
+        (
+            "SELECT id, email FROM Users WHERE name = 'Bob'",
+            Ok(select!(
+                id  | email
+                I64 | Str;
+                2     "b@gluesql.org".to_owned()
+            )),
+        ),
+        (
+            "INSERT INTO Users VALUES (3, 'Carol', 'c@gluesql.org')",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT legacy FROM Users",
+            Err(EvaluateError::IdentifierNotFound("legacy".to_owned()).into()),
+        ),
+        (
+            "ALTER TABLE Users DROP COLUMN id",
+            Err(AlterError::CannotAlterPrimaryKeyColumn {
+                table_name: "Users".to_owned(),
+                column_name: "id".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "ALTER TABLE Users DROP COLUMN email",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "SELECT * FROM Users",
+            Ok(select!(
+                id  | name
+                I64 | Str;
+                1     "Alice".to_owned();
+                2     "Bob".to_owned();
+                3     "Carol".to_owned()
+            )),
+        ),
+        (
+            "ALTER TABLE Users RENAME COLUMN id TO user_id",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "SELECT user_id FROM Users WHERE name = 'Carol'",
+            Ok(select!(user_id I64; 3)),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+});
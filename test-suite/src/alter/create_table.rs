@@ -2,7 +2,7 @@ use {
     crate::*,
     gluesql_core::{
         data::value::Value::{I64, Null, Str},
-        error::{AlterError, EvaluateError, TranslateError},
+        error::{AlterError, EvaluateError, FetchError, TranslateError},
         prelude::Payload,
     },
 };
@@ -120,6 +120,29 @@ test_case!(create_table, {
                 I64(2)   I64(2)   Str("2".to_owned())
             )),
         ),
+        (
+            // existing table is kept as it is, no rows are appended
+            "CREATE TABLE IF NOT EXISTS TargetTableWithLimit AS SELECT * FROM CreateTable2",
+            Ok(Payload::Create),
+        ),
+        (
+            "SELECT * FROM TargetTableWithLimit",
+            Ok(select_with_null!(
+                id     | num    | name;
+                Null     I64(1)   Str("1".to_owned())
+            )),
+        ),
+        (
+            "CREATE TABLE IF NOT EXISTS TargetTableIfNotExists AS SELECT * FROM CreateTable2 OFFSET 1",
+            Ok(Payload::Create),
+        ),
+        (
+            "SELECT * FROM TargetTableIfNotExists",
+            Ok(select_with_null!(
+                id     | num    | name;
+                I64(2)   I64(2)   Str("2".to_owned())
+            )),
+        ),
         (
             // Target Table already exists
             "CREATE TABLE TargetTableWithData AS SELECT * FROM CreateTable2",
@@ -135,6 +158,11 @@ test_case!(create_table, {
             "CREATE TABLE DuplicateColumns (id INT, id INT)",
             Err(AlterError::DuplicateColumnName("id".to_owned()).into()),
         ),
+        (
+            // The rejected table is not created
+            "SELECT * FROM DuplicateColumns",
+            Err(FetchError::TableNotFound("DuplicateColumns".to_owned()).into()),
+        ),
     ];
 
     for (sql, expected) in test_cases {
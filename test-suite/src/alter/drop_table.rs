@@ -47,6 +47,11 @@ CREATE TABLE DropTable (
 )",
             Ok(Payload::Create),
         ),
+        (
+            // rows of the dropped table are not left behind
+            "SELECT id, num, name FROM DropTable;",
+            Ok(select!(id | num | name)),
+        ),
         ("DROP TABLE IF EXISTS DropTable;", Ok(Payload::DropTable(1))),
         ("DROP TABLE IF EXISTS DropTable;", Ok(Payload::DropTable(0))),
         (
@@ -143,6 +148,42 @@ CREATE TABLE DropTable (
             "SELECT id, num, name FROM DropTable2;",
             Err(FetchError::TableNotFound("DropTable2".to_owned()).into()),
         ),
+        (
+            "DROP TABLE IF EXISTS DropTable2;",
+            Ok(Payload::DropTable(0)),
+        ),
+        (
+            "
+        CREATE TABLE DropTable1 (
+            id INT,
+            num INT,
+            name TEXT
+        )",
+            Ok(Payload::Create),
+        ),
+        (
+            // a missing table drops none of the listed tables
+            "DROP TABLE DropTable1, DropTable2;",
+            Err(AlterError::TableNotFound("DropTable2".to_owned()).into()),
+        ),
+        (
+            "SELECT id, num, name FROM DropTable1;",
+            Ok(select!(id | num | name)),
+        ),
+        (
+            // a table listed twice is rejected rather than dropped once
+            "DROP TABLE DropTable1, DropTable1;",
+            Err(AlterError::DuplicateTableName("DropTable1".to_owned()).into()),
+        ),
+        (
+            "DROP TABLE IF EXISTS DropTable1, DropTable2, DropTable1;",
+            Err(AlterError::DuplicateTableName("DropTable1".to_owned()).into()),
+        ),
+        (
+            "SELECT id, num, name FROM DropTable1;",
+            Ok(select!(id | num | name)),
+        ),
+        ("DROP TABLE DropTable1;", Ok(Payload::DropTable(1))),
     ];
 
     for (sql, expected) in sqls {
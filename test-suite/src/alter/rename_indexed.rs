@@ -0,0 +1,52 @@
+use {
+    crate::*,
+    gluesql_core::{ast::IndexOperator::*, prelude::Value::*},
+};
+
+test_case!(rename_indexed_column, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Test (id INTEGER, num INTEGER);").await;
+    g.run("INSERT INTO Test VALUES (1, 10);").await;
+    g.run("CREATE INDEX idx_id ON Test (id)").await;
+    g.run("CREATE INDEX idx_num ON Test (CAST(num AS TEXT) || -(id + 1))")
+        .await;
+
+    g.run("ALTER TABLE Test RENAME COLUMN id TO uid").await;
+
+    // the index expressions follow the renamed column
+    g.run("INSERT INTO Test VALUES (2, 20);").await;
+    g.test_idx(
+        "SELECT uid, num FROM Test WHERE uid = 2",
+        Ok(select!(
+            uid | num
+            I64 | I64;
+            2     20
+        )),
+        idx!(idx_id, Eq, "2"),
+    )
+    .await;
+    g.test_idx(
+        "SELECT uid, num FROM Test WHERE CAST(num AS TEXT) || -(uid + 1) = '10-2'",
+        Ok(select!(
+            uid | num
+            I64 | I64;
+            1     10
+        )),
+        idx!(idx_num, Eq, "'10-2'"),
+    )
+    .await;
+
+    g.run("UPDATE Test SET uid = 3 WHERE uid = 1").await;
+    g.run("DELETE FROM Test WHERE uid = 2").await;
+    g.test_idx(
+        "SELECT uid, num FROM Test WHERE uid < 10",
+        Ok(select!(
+            uid | num
+            I64 | I64;
+            3     10
+        )),
+        idx!(idx_id, Lt, "10"),
+    )
+    .await;
+});
@@ -118,6 +118,18 @@ test_case!(error, {
             "SELECT * FROM Arith WHERE id = 2 % 0.0",
             LiteralError::DivisorShouldNotBeZero.into(),
         ),
+        (
+            "SELECT id / (num - num) FROM Arith",
+            ValueError::DivisorShouldNotBeZero.into(),
+        ),
+        (
+            "SELECT id % (num - num) FROM Arith",
+            ValueError::DivisorShouldNotBeZero.into(),
+        ),
+        (
+            "SELECT id / CAST(num - num AS FLOAT) FROM Arith",
+            ValueError::DivisorShouldNotBeZero.into(),
+        ),
         (
             "SELECT * FROM Arith WHERE TRUE AND 'hello'",
             EvaluateError::BooleanTypeRequired(format!(
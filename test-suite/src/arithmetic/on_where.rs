@@ -1,4 +1,11 @@
-use crate::*;
+use {
+    crate::*,
+    gluesql_core::{
+        data::NumericBinaryOperator,
+        error::ValueError,
+        prelude::Value::{self, *},
+    },
+};
 
 test_case!(on_where, {
     let g = get_tester!();
@@ -67,4 +74,89 @@ test_case!(on_where, {
     for (num, sql) in test_cases {
         g.count(sql, num).await;
     }
+
+    g.run(
+        "
+        CREATE TABLE Budget (
+            id INTEGER,
+            a INTEGER NULL,
+            b INTEGER,
+            price FLOAT,
+            budget FLOAT,
+            name TEXT
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Budget VALUES
+            (1, 1, 3, 10.0, 10.5, 'x'),
+            (2, 2, 3, 5.0, 6.0, 'y'),
+            (3, NULL, 1, 20.0, 30.0, 'z'),
+            (4, 4, 2, 9.0, 9.0, 'w');
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "SELECT id FROM Budget WHERE price * 1.1 > budget",
+            select!(id I64; 1; 4),
+        ),
+        (
+            // NULL operand makes the comparison NULL, which filters the row out
+            "SELECT id FROM Budget WHERE (a + b) % 2 = 0",
+            select!(id I64; 1; 4),
+        ),
+        (
+            "SELECT id FROM Budget WHERE (a + b) % 2 <> 0",
+            select!(id I64; 2),
+        ),
+        (
+            "SELECT id FROM Budget WHERE a + 0.5 > b",
+            select!(id I64; 4),
+        ),
+        (
+            "SELECT id FROM Budget WHERE ((((a + b)) * (2 - (1)))) / 2 = 2",
+            select!(id I64; 1; 2),
+        ),
+        (
+            // the projection evaluates the same comparison the same way
+            "SELECT id, price * 1.1 > budget AS over FROM Budget",
+            select!(
+                id  | over
+                I64 | Value::Bool;
+                1     true;
+                2     false;
+                3     false;
+                4     true
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+
+    g.test(
+        "SELECT id FROM Budget WHERE name + 1 > 0",
+        Err(ValueError::NonNumericMathOperation {
+            lhs: Str("x".to_owned()),
+            operator: NumericBinaryOperator::Add,
+            rhs: I64(1),
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT name + 1 FROM Budget",
+        Err(ValueError::NonNumericMathOperation {
+            lhs: Str("x".to_owned()),
+            operator: NumericBinaryOperator::Add,
+            rhs: I64(1),
+        }
+        .into()),
+    )
+    .await;
 });
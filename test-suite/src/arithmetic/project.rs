@@ -1,6 +1,10 @@
 use {
     crate::*,
-    gluesql_core::prelude::Value::{self, *},
+    gluesql_core::{
+        data::NumericBinaryOperator,
+        error::ValueError,
+        prelude::Value::{self, *},
+    },
 };
 
 test_case!(project, {
@@ -62,4 +66,103 @@ test_case!(project, {
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
+
+    g.run("CREATE TABLE Product (id INTEGER, price FLOAT, quantity INTEGER);")
+        .await;
+    g.run("INSERT INTO Product VALUES (1, 2.5, 4), (2, 10.0, 0), (3, 1.25, 3);")
+        .await;
+
+    let test_cases = [
+        (
+            "SELECT price * quantity AS total FROM Product",
+            select!(total; F64; 10.0; 0.0; 3.75),
+        ),
+        (
+            // integer mixed with float is computed as float
+            "SELECT quantity - price AS l, id + price AS r FROM Product",
+            select!(
+                l     | r
+                F64   | F64;
+                1.5     3.5;
+                -10.0   12.0;
+                1.75    4.25
+            ),
+        ),
+        (
+            "SELECT id * 1.5 AS v, id / 2.0 AS w FROM Product",
+            select!(
+                v   | w
+                F64 | F64;
+                1.5   0.5;
+                3.0   1.0;
+                4.5   1.5
+            ),
+        ),
+        (
+            // integers stay integers
+            "SELECT id / 2 AS q, id % 2 AS m, quantity * id AS p FROM Product",
+            select!(
+                q   | m   | p
+                I64 | I64 | I64;
+                0     1     4;
+                1     0     0;
+                1     1     9
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+
+    g.run(
+        "
+        CREATE TABLE Orders (
+            id INTEGER,
+            price FLOAT NULL,
+            quantity INTEGER NULL,
+            discount FLOAT NULL,
+            name TEXT
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "INSERT INTO Orders VALUES (1, 2.5, 4, 0.5, 'a'), (2, 10.0, 3, NULL, 'b'), (3, NULL, 7, 1.0, 'c');",
+    )
+    .await;
+
+    g.named_test(
+        "computed values keep the projection order and NULL operands give NULL",
+        "SELECT name, price * quantity AS total, id, price - discount AS net, quantity % 2 AS odd FROM Orders",
+        Ok(select_with_null!(
+            name                | total     | id     | net      | odd;
+            Str("a".to_owned())   F64(10.0)   I64(1)   F64(2.0)   I64(0);
+            Str("b".to_owned())   F64(30.0)   I64(2)   Null       I64(1);
+            Str("c".to_owned())   Null        I64(3)   Null       I64(1)
+        )),
+    )
+    .await;
+    g.named_test(
+        "string plus number in the projection",
+        "SELECT id, name + quantity AS bad FROM Orders",
+        Err(ValueError::NonNumericMathOperation {
+            lhs: Str("a".to_owned()),
+            operator: NumericBinaryOperator::Add,
+            rhs: I64(4),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "number times string in the projection",
+        "SELECT price * name AS bad FROM Orders",
+        Err(ValueError::NonNumericMathOperation {
+            lhs: F64(2.5),
+            operator: NumericBinaryOperator::Multiply,
+            rhs: Str("a".to_owned()),
+        }
+        .into()),
+    )
+    .await;
 });
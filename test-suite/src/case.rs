@@ -1,7 +1,8 @@
 use {
     crate::*,
     gluesql_core::{
-        error::TranslateError,
+        ast::{BinaryOperator, DataType},
+        error::{EvaluateError, TranslateError, ValidateError, ValueError},
         prelude::{Payload, Value::*},
     },
 };
@@ -105,6 +106,97 @@ test_case!(case, {
                 5
             )),
         ),
+        (
+            "
+            SELECT id, CASE WHEN id >= 3 THEN 'A' WHEN id >= 2 THEN 'B' ELSE 'F' END AS grade
+            FROM Item
+            ",
+            Ok(select!(
+                id  | grade
+                I64 | Str;
+                1     "F".to_owned();
+                2     "B".to_owned();
+                3     "A".to_owned()
+            )),
+        ),
+        (
+            "SELECT name FROM Item WHERE CASE id WHEN 1 THEN TRUE WHEN 3 THEN TRUE ELSE FALSE END",
+            Ok(select!(name Str; "Harry".to_owned(); "Hermione".to_owned())),
+        ),
+        (
+            // branches after the first matched one are never evaluated
+            "
+            SELECT CASE
+                WHEN id < 3 THEN 10 / id
+                WHEN 10 / (id - 3) > 0 THEN 0
+                ELSE 10 / 0 END
+            AS case FROM Item WHERE id = 1
+            ",
+            Ok(select!(case I64; 10)),
+        ),
+        (
+            "
+            SELECT CASE id WHEN 3 THEN 10 / (id - 3) ELSE 0 END AS case
+            FROM Item WHERE id = 3
+            ",
+            Err(ValueError::DivisorShouldNotBeZero.into()),
+        ),
+        (
+            // a condition which is NULL does not match, neither does NULL in the simple form
+            "
+            SELECT
+                CASE WHEN NULL THEN 1 ELSE 2 END AS a,
+                CASE NULL WHEN NULL THEN 1 ELSE 2 END AS b,
+                CASE WHEN NULL = 1 THEN 1 END AS c
+            ",
+            Ok(select_with_null!(
+                a      | b      | c;
+                I64(2)   I64(2)   Null
+            )),
+        ),
+        (
+            // a condition of the searched form must be a boolean
+            "SELECT CASE WHEN name THEN 1 END AS case FROM Item",
+            Err(EvaluateError::BooleanTypeRequired(r#"Str("Harry")"#.to_owned()).into()),
+        ),
+        (
+            // branches are not coerced to a common type, each row takes the matched one as is
+            "
+            SELECT id, CASE WHEN id = 1 THEN 'one' WHEN id = 2 THEN 2.5 ELSE id END AS case
+            FROM Item
+            ",
+            Ok(select_with_null!(
+                id     | case;
+                I64(1)   Str("one".to_owned());
+                I64(2)   F64(2.5);
+                I64(3)   I64(3)
+            )),
+        ),
+        ("CREATE TABLE Target (value INTEGER);", Ok(Payload::Create)),
+        (
+            // the column type is checked when the result is stored
+            "INSERT INTO Target SELECT CASE WHEN id = 1 THEN 'one' ELSE id END FROM Item;",
+            Err(ValidateError::TypeMismatch {
+                table_name: "Target".to_owned(),
+                column_name: "value".to_owned(),
+                expected: DataType::Int,
+                found: Str("one".to_owned()),
+            }
+            .into()),
+        ),
+        (
+            "INSERT INTO Target SELECT CASE WHEN id = 1 THEN 100 ELSE id END FROM Item;",
+            Ok(Payload::Insert(3)),
+        ),
+        (
+            "SELECT CASE id WHEN 'one' THEN 1 END AS case FROM Item;",
+            Err(EvaluateError::NumberComparedWithString {
+                left: "I64(1)".to_owned(),
+                op: BinaryOperator::Eq,
+                right: r#"Str("one")"#.to_owned(),
+            }
+            .into()),
+        ),
         (
             "
             SELECT CASE 1 COLLATE Item
@@ -142,6 +142,26 @@ test_case!(column_alias, {
                 2         "b".to_owned()
             )),
         ),
+        (
+            // expression alias combined with table alias
+            "SELECT u.name AS username, u.id * 10 AS score FROM User u WHERE u.id > 1",
+            Ok(select!(
+                username          | score
+                Str               | I64;
+                "Mike".to_owned()   20;
+                "Jorno".to_owned()  30
+            )),
+        ),
+        (
+            // table alias used on both sides of a self join
+            "SELECT a.name AS left_name, b.name AS right_name FROM User a JOIN User b ON a.id + 1 = b.id",
+            Ok(select!(
+                left_name            | right_name
+                Str                  | Str;
+                "Taehoon".to_owned()   "Mike".to_owned();
+                "Mike".to_owned()      "Jorno".to_owned()
+            )),
+        ),
         (
             "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS Derived(id, name, dummy)",
             Err(FetchError::TooManyColumnAliases("Derived".into(), 2, 3).into()),
@@ -0,0 +1,85 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{EvaluateError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(bool, {
+    let g = get_tester!();
+
+    let test_cases = [
+        (
+            "CREATE TABLE Flags (id INTEGER, active BOOLEAN)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO Flags VALUES (1, TRUE), (2, FALSE), (3, NULL)",
+            Ok(Payload::Insert(3)),
+        ),
+        (
+            "SELECT * FROM Flags",
+            Ok(select_with_null!(
+                id     | active;
+                I64(1)   Bool(true);
+                I64(2)   Bool(false);
+                I64(3)   Null
+            )),
+        ),
+        ("SELECT id FROM Flags WHERE active", Ok(select!(id I64; 1))),
+        (
+            "SELECT id FROM Flags WHERE NOT active",
+            Ok(select!(id I64; 2)),
+        ),
+        (
+            "SELECT id FROM Flags WHERE active = TRUE",
+            Ok(select!(id I64; 1)),
+        ),
+        (
+            "SELECT id FROM Flags WHERE active <> TRUE",
+            Ok(select!(id I64; 2)),
+        ),
+        (
+            "SELECT id FROM Flags WHERE active OR id = 3",
+            Ok(select!(id I64; 1; 3)),
+        ),
+        (
+            "SELECT id FROM Flags WHERE active IS NULL",
+            Ok(select!(id I64; 3)),
+        ),
+        (
+            "SELECT id, active = FALSE AS inactive FROM Flags",
+            Ok(select_with_null!(
+                id     | inactive;
+                I64(1)   Bool(false);
+                I64(2)   Bool(true);
+                I64(3)   Null
+            )),
+        ),
+        (
+            "UPDATE Flags SET active = NOT active",
+            Ok(Payload::Update(3)),
+        ),
+        ("SELECT id FROM Flags WHERE active", Ok(select!(id I64; 2))),
+        (
+            "SELECT id FROM Flags WHERE id",
+            Err(EvaluateError::BooleanTypeRequired(format!("{:?}", I64(1))).into()),
+        ),
+        (
+            "INSERT INTO Flags VALUES (4, 'yes')",
+            Err(ValidateError::TypeMismatch {
+                table_name: "Flags".to_owned(),
+                column_name: "active".to_owned(),
+                expected: DataType::Boolean,
+                found: Str("yes".to_owned()),
+            }
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+});
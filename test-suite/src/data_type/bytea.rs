@@ -1,13 +1,13 @@
 use {
     crate::*,
-    bigdecimal::BigDecimal,
     gluesql_core::{
         ast::DataType,
-        data::Literal,
-        error::{LiteralError, ValueError},
-        prelude::{Payload, Value::Bytea},
+        error::{LiteralError, ValidateError},
+        prelude::{
+            Payload,
+            Value::{self, Bytea},
+        },
     },
-    std::borrow::Cow,
 };
 
 test_case!(bytea, {
@@ -37,9 +37,11 @@ test_case!(bytea, {
         ),
         (
             "INSERT INTO Bytea VALUES (0)",
-            Err(ValueError::IncompatibleLiteralForDataType {
-                data_type: DataType::Bytea,
-                literal: format!("{:?}", Literal::Number(Cow::Owned(BigDecimal::from(0)))),
+            Err(ValidateError::TypeMismatch {
+                table_name: "Bytea".to_owned(),
+                column_name: "bytes".to_owned(),
+                expected: DataType::Bytea,
+                found: Value::Decimal(0.into()),
             }
             .into()),
         ),
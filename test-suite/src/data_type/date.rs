@@ -122,6 +122,30 @@ INSERT INTO DateLog VALUES
     )
     .await;
 
+    g.test(
+        "SELECT id, date2 FROM DateLog ORDER BY date2 DESC",
+        Ok(select!(
+            id  | date2
+            I64 | Date;
+            3     date!("2021-05-01");
+            1     date!("2021-03-01");
+            2     date!("1989-01-01")
+        )),
+    )
+    .await;
+
+    g.test(
+        "SELECT id FROM DateLog WHERE date1 BETWEEN DATE '2020-01-01' AND '2020-12-31' ORDER BY date1",
+        Ok(select!(id I64; 1; 2)),
+    )
+    .await;
+
+    g.test(
+        "SELECT id FROM DateLog WHERE date2 = TIMESTAMP '2021-03-01 00:00:00'",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
     g.test(
         "INSERT INTO DateLog VALUES (1, '12345-678', '2021-05-01')",
         Err(ValueError::FailedToParseDate("12345-678".to_owned()).into()),
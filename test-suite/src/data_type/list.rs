@@ -134,7 +134,7 @@ INSERT INTO ListType2 VALUES
     .await;
 
     g.test(
-        r#"SELECT id FROM ListType GROUP BY items"#,
+        r#"SELECT items FROM ListType GROUP BY items"#,
         Err(KeyError::ListTypeKeyNotSupported.into()),
     )
     .await;
@@ -152,7 +152,7 @@ INSERT INTO MapType2 VALUES
     )
     .await;
     g.test(
-        "SELECT id FROM MapType GROUP BY nested",
+        "SELECT nested FROM MapType GROUP BY nested",
         Err(KeyError::MapTypeKeyNotSupported.into()),
     )
     .await;
@@ -1,13 +1,10 @@
 use {
     crate::*,
-    bigdecimal::BigDecimal,
     gluesql_core::{
         ast::DataType,
-        data::Literal,
-        error::{TranslateError, ValueError},
+        error::{TranslateError, ValidateError, ValueError},
         prelude::{Payload, Value::*},
     },
-    std::borrow::Cow,
 };
 
 test_case!(point, {
@@ -48,9 +45,11 @@ test_case!(point, {
         ),
         (
             r#"INSERT INTO POINT VALUES (0)"#,
-            Err(ValueError::IncompatibleLiteralForDataType {
-                data_type: DataType::Point,
-                literal: format!("{:?}", Literal::Number(Cow::Owned(BigDecimal::from(0)))),
+            Err(ValidateError::TypeMismatch {
+                table_name: "POINT".to_owned(),
+                column_name: "point_field".to_owned(),
+                expected: DataType::Point,
+                found: Decimal(0.into()),
             }
             .into()),
         ),
@@ -154,6 +154,24 @@ INSERT INTO TimeLog VALUES
     )
     .await;
 
+    g.test(
+        "SELECT id, time1 FROM TimeLog ORDER BY time1",
+        Ok(select!(
+            id  | time1
+            I64 | Time;
+            2     t(9, 2, 1, 0);
+            1     t(12, 30, 0, 0);
+            3     t(14, 59, 0, 0)
+        )),
+    )
+    .await;
+
+    g.test(
+        "SELECT id FROM TimeLog WHERE time2 BETWEEN TIME '08:00' AND TIME '09:00' ORDER BY id",
+        Ok(select!(id I64; 2; 3)),
+    )
+    .await;
+
     g.test(
         "INSERT INTO TimeLog VALUES (1, '12345-678', '20:05:01')",
         Err(ValueError::FailedToParseTime("12345-678".to_owned()).into()),
@@ -129,4 +129,26 @@ INSERT INTO TimestampLog VALUES
         Err(ValueError::FailedToParseTimestamp("12345-678".to_owned()).into()),
     )
     .await;
+
+    g.test(
+        "SELECT id FROM TimestampLog ORDER BY t1 DESC",
+        Ok(select!(id I64; 3; 2; 1)),
+    )
+    .await;
+
+    g.run(
+        "INSERT INTO TimestampLog VALUES (4, TIMESTAMP '2021-01-01 10:00:00', TIMESTAMP '2021-01-01 10:00:00')",
+    )
+    .await;
+
+    g.test(
+        "SELECT id, t1 FROM TimestampLog WHERE t1 > TIMESTAMP '2021-01-01 09:59:59' ORDER BY t1",
+        Ok(select!(
+            id  | t1
+            I64 | Timestamp;
+            4     t!("2021-01-01T10:00:00");
+            3     t!("2021-05-01T00:00:00.1234")
+        )),
+    )
+    .await;
 });
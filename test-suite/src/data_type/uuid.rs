@@ -1,13 +1,10 @@
 use {
     crate::*,
-    bigdecimal::BigDecimal,
     gluesql_core::{
         ast::DataType,
-        data::Literal,
-        error::ValueError,
+        error::{ValidateError, ValueError},
         prelude::{Payload, Value::*},
     },
-    std::borrow::Cow,
     uuid::Uuid as UUID,
 };
 
@@ -45,9 +42,11 @@ test_case!(uuid, {
         ("CREATE TABLE UUID (uuid_field UUID)", Ok(Payload::Create)),
         (
             r#"INSERT INTO UUID VALUES (0)"#,
-            Err(ValueError::IncompatibleLiteralForDataType {
-                data_type: DataType::Uuid,
-                literal: format!("{:?}", Literal::Number(Cow::Owned(BigDecimal::from(0)))),
+            Err(ValidateError::TypeMismatch {
+                table_name: "UUID".to_owned(),
+                column_name: "uuid_field".to_owned(),
+                expected: DataType::Uuid,
+                found: Decimal(0.into()),
             }
             .into()),
         ),
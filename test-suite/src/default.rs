@@ -2,7 +2,8 @@ use {
     crate::*,
     chrono::NaiveDate,
     gluesql_core::{
-        error::EvaluateError,
+        ast::DataType,
+        error::{EvaluateError, InsertError, ValidateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -96,4 +97,93 @@ test_case!(default, {
         )),
     )
     .await;
+
+    g.run(
+        "
+        CREATE TABLE Jobs (
+            id INTEGER NOT NULL,
+            retries INTEGER NOT NULL DEFAULT 0,
+            status TEXT DEFAULT 'pending',
+            note TEXT NULL
+        )",
+    )
+    .await;
+
+    let test_cases = [
+        ("INSERT INTO Jobs (id) VALUES (1)", Ok(Payload::Insert(1))),
+        (
+            "INSERT INTO Jobs (id, status) VALUES (2, 'done'), (3, NULL)",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "INSERT INTO Jobs (id, retries) SELECT id + 10, id FROM Jobs WHERE id = 1",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO Jobs (status) VALUES ('orphan')",
+            Err(InsertError::LackOfRequiredColumn("id".to_owned()).into()),
+        ),
+        (
+            "SELECT * FROM Jobs",
+            Ok(select_with_null!(
+                id      | retries | status                    | note;
+                I64(1)    I64(0)    Str("pending".to_owned())   Null;
+                I64(2)    I64(0)    Str("done".to_owned())      Null;
+                I64(3)    I64(0)    Null                        Null;
+                I64(11)   I64(1)    Str("pending".to_owned())   Null
+            )),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.run(
+        "
+        CREATE TABLE Users (
+            id INTEGER,
+            name TEXT,
+            status TEXT DEFAULT 'active',
+            level INTEGER DEFAULT 1 + 1,
+            code INTEGER DEFAULT 'none'
+        )",
+    )
+    .await;
+
+    g.named_test(
+        "a default which does not fit the column type fails on insert",
+        "INSERT INTO Users (id, name) VALUES (1, 'kim')",
+        Err(ValidateError::TypeMismatch {
+            table_name: "Users".to_owned(),
+            column_name: "code".to_owned(),
+            expected: DataType::Int,
+            found: Str("none".to_owned()),
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "omitted columns take their defaults, or NULL without one",
+        "INSERT INTO Users (id, name, code) VALUES (1, 'kim', 7), (2, 'lee', NULL)",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "an omitted column without a default is NULL",
+        "INSERT INTO Users (id, code) VALUES (3, 9)",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Users",
+        Ok(select_with_null!(
+            id     | name                 | status                   | level  | code;
+            I64(1)   Str("kim".to_owned())  Str("active".to_owned())   I64(2)   I64(7);
+            I64(2)   Str("lee".to_owned())  Str("active".to_owned())   I64(2)   Null;
+            I64(3)   Null                   Str("active".to_owned())   I64(2)   I64(9)
+        )),
+    )
+    .await;
 });
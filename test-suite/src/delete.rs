@@ -69,3 +69,69 @@ test_case!(delete, {
     )
     .await;
 });
+
+test_case!(delete_with_subquery, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Users (id INTEGER PRIMARY KEY, name TEXT)")
+        .await;
+    g.run("CREATE TABLE Orders (id INTEGER, user_id INTEGER)")
+        .await;
+    g.run("INSERT INTO Users VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Carol'), (4, 'Dave')")
+        .await;
+    g.run("INSERT INTO Orders VALUES (10, 1), (11, 3), (12, 3)")
+        .await;
+
+    g.named_test(
+        "delete rows without a related row using NOT EXISTS",
+        "DELETE FROM Users WHERE NOT EXISTS (SELECT 1 FROM Orders WHERE Orders.user_id = Users.id)",
+        Ok(Payload::Delete(2)),
+    )
+    .await;
+
+    g.test(
+        "SELECT * FROM Users",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Alice".to_owned();
+            3     "Carol".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "delete rows with a related row using EXISTS",
+        "DELETE FROM Users WHERE EXISTS (SELECT 1 FROM Orders WHERE user_id = Users.id AND id > 10)",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+
+    g.test(
+        "SELECT * FROM Users",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Alice".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "inner columns shadow outer columns of the same name",
+        "DELETE FROM Orders WHERE EXISTS (SELECT 1 FROM Users WHERE id = Orders.user_id)",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+
+    g.test(
+        "SELECT * FROM Orders",
+        Ok(select!(
+            id  | user_id
+            I64 | I64;
+            11    3;
+            12    3
+        )),
+    )
+    .await;
+});
@@ -30,6 +30,41 @@ test_case!(distinct, {
     )
     .await;
 
+    g.run("INSERT INTO Item VALUES (4, NULL, 200)").await;
+
+    g.named_test(
+        "DISTINCT treats NULLs as equal",
+        "SELECT DISTINCT name FROM Item",
+        Ok(select_with_null!(
+            name;
+            Str("Apple".to_owned());
+            Str("Banana".to_owned());
+            Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DISTINCT is applied before LIMIT and OFFSET",
+        "SELECT DISTINCT id FROM Item ORDER BY id LIMIT 2 OFFSET 1",
+        Ok(select!(id; I64; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "DISTINCT keeps the first seen order",
+        "SELECT DISTINCT id FROM Item",
+        Ok(select!(id; I64; 1; 2; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "DISTINCT with LIMIT stops fetching rows once satisfied",
+        "SELECT DISTINCT 12 / (4 - id) AS v FROM Item LIMIT 3",
+        Ok(select!(v; I64; 4; 6; 12)),
+    )
+    .await;
+
     g.run("CREATE TABLE Restaurant (id INTEGER, menu MAP)")
         .await;
     g.run(
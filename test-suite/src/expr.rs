@@ -1,2 +1,3 @@
 pub mod between;
 pub mod in_list;
+pub mod logical;
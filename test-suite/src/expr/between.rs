@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::{ast::BinaryOperator, error::EvaluateError, prelude::Value::*},
+};
 
 test_case!(between, {
     let g = get_tester!();
@@ -95,4 +98,147 @@ test_case!(between, {
         )
         .await;
     }
+
+    g.run("CREATE TABLE Person (id INTEGER, age INTEGER NULL)")
+        .await;
+    g.run("INSERT INTO Person VALUES (1, 12), (2, 18), (3, 40), (4, 65), (5, 80), (6, NULL)")
+        .await;
+
+    g.named_test(
+        "'BETWEEN' in 'WHERE' includes both bounds",
+        "SELECT id FROM Person WHERE age BETWEEN 18 AND 65",
+        Ok(select!(id; I64; 2; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "'NOT BETWEEN' in 'WHERE' filters out 'NULL'",
+        "SELECT id FROM Person WHERE age NOT BETWEEN 18 AND 65",
+        Ok(select!(id; I64; 1; 5)),
+    )
+    .await;
+
+    g.named_test(
+        "'BETWEEN' is equivalent to '>=' and '<='",
+        "
+        SELECT id, age BETWEEN id * 10 AND id * 15 AS res FROM Person
+        WHERE (age BETWEEN id * 10 AND id * 15) = (age >= id * 10 AND age <= id * 15)
+        ",
+        Ok(select!(
+            id  | res
+            I64 | Bool;
+            1     true;
+            2     false;
+            3     true;
+            4     false;
+            5     false
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "a string is not between numbers",
+        "SELECT 'a' BETWEEN 1 AND 3 AS a",
+        Err(EvaluateError::NumberComparedWithString {
+            left: r#"Str("a")"#.to_owned(),
+            op: BinaryOperator::GtEq,
+            right: "I64(1)".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "a number is not between strings",
+        "SELECT 2 NOT BETWEEN 1 AND 'b' AS a",
+        Err(EvaluateError::NumberComparedWithString {
+            left: "I64(2)".to_owned(),
+            op: BinaryOperator::LtEq,
+            right: r#"Str("b")"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "values of other types which cannot be compared are never between",
+        "SELECT TRUE BETWEEN 1 AND 3 AS a, TRUE NOT BETWEEN 1 AND 3 AS b",
+        Ok(select!(
+            a     | b
+            Bool  | Bool;
+            false   true
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Product (id INTEGER, name TEXT, price FLOAT NULL)")
+        .await;
+    g.run(
+        "
+        INSERT INTO Product VALUES
+            (1, 'apple', 9.5),
+            (2, 'banana', 10.0),
+            (3, 'cherry', 15.25),
+            (4, 'durian', 20.0),
+            (5, 'elder', 20.5),
+            (6, 'fig', NULL)
+        ",
+    )
+    .await;
+    g.run("CREATE TABLE Band (label TEXT, min_price FLOAT, max_price FLOAT NULL)")
+        .await;
+    g.run(
+        "INSERT INTO Band VALUES ('cheap', 0.0, 10.0), ('mid', 10.0, 20.0), ('open', 20.0, NULL)",
+    )
+    .await;
+
+    g.named_test(
+        "'BETWEEN' on floats includes both bounds",
+        "SELECT id FROM Product WHERE price BETWEEN 9.75 AND 15.25",
+        Ok(select!(id; I64; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "'BETWEEN' on strings compares lexicographically",
+        "SELECT id FROM Product WHERE name BETWEEN 'banana' AND 'd'",
+        Ok(select!(id; I64; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "'BETWEEN' in 'JOIN ON' with bounds from the joined table",
+        "
+        SELECT p.id, b.label
+        FROM Product p
+        JOIN Band b ON p.price BETWEEN b.min_price AND b.max_price
+        ",
+        Ok(select!(
+            id  | label
+            I64 | Str;
+            1     "cheap".to_owned();
+            2     "cheap".to_owned();
+            2     "mid".to_owned();
+            3     "mid".to_owned();
+            4     "mid".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "'NOT BETWEEN' in 'LEFT JOIN ON' does not match 'NULL' prices or bounds",
+        "
+        SELECT p.id, b.label
+        FROM Product p
+        LEFT JOIN Band b ON p.price NOT BETWEEN b.min_price AND b.max_price AND b.label <> 'cheap'
+        ",
+        Ok(select_with_null!(
+            id     | label;
+            I64(1)   Str("mid".to_owned());
+            I64(2)   Null;
+            I64(3)   Null;
+            I64(4)   Null;
+            I64(5)   Str("mid".to_owned());
+            I64(6)   Null
+        )),
+    )
+    .await;
 });
@@ -1,4 +1,11 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::{
+        ast::BinaryOperator,
+        error::EvaluateError,
+        prelude::{Payload, Value::*},
+    },
+};
 
 test_case!(in_list, {
     let g = get_tester!();
@@ -22,4 +29,90 @@ test_case!(in_list, {
         )),
     )
     .await;
+
+    g.run("CREATE TABLE Task (id INTEGER, status TEXT)").await;
+    g.run("INSERT INTO Task VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, NULL)")
+        .await;
+
+    g.named_test(
+        "IN filters rows by a literal list",
+        "SELECT id FROM Task WHERE status IN ('a', 'c')",
+        Ok(select!(id; I64; 1; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "NOT IN filters rows by a literal list",
+        "SELECT id FROM Task WHERE id NOT IN (1, 2)",
+        Ok(select!(id; I64; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "no match against a list including 'NULL' is 'NULL'",
+        "SELECT id, id IN (1, NULL) AS found, id NOT IN (1, NULL) AS missing FROM Task",
+        Ok(select_with_null!(
+            id     | found      | missing;
+            I64(1)   Bool(true)   Bool(false);
+            I64(2)   Null         Null;
+            I64(3)   Null         Null;
+            I64(4)   Null         Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "'NOT IN' with 'NULL' in the list filters every unmatched row",
+        "SELECT id FROM Task WHERE status NOT IN ('a', NULL)",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+
+    g.named_test(
+        "integer values match number literals with the same value",
+        "SELECT id FROM Task WHERE id IN (1.0, 2.5, 3)",
+        Ok(select!(id; I64; 1; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "integer values stored in other integer types match number literals",
+        "SELECT id FROM Task WHERE CAST(id AS INT8) NOT IN (1, 4.0)",
+        Ok(select!(id; I64; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "integer values are compared with the exact value of number literals",
+        "SELECT id FROM Task WHERE id > 2.5 OR id <= 1.0",
+        Ok(select!(id; I64; 1; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "a number compared with a string in the list fails",
+        "SELECT id FROM Task WHERE id IN ('1', '2')",
+        Err(EvaluateError::NumberComparedWithString {
+            left: "I64(1)".to_owned(),
+            op: BinaryOperator::Eq,
+            right: r#"Str("1")"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "a string compared with a non-string value in the list fails",
+        "SELECT id FROM Task WHERE status IN (TRUE)",
+        Err(EvaluateError::StringComparedWithNonString {
+            left: r#"Str("a")"#.to_owned(),
+            op: BinaryOperator::Eq,
+            right: "Bool(true)".to_owned(),
+        }
+        .into()),
+    )
+    .await;
 });
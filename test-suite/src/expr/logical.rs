@@ -0,0 +1,126 @@
+use {
+    crate::*,
+    gluesql_core::{error::EvaluateError, prelude::Value::*},
+};
+
+test_case!(logical, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Logic (id INTEGER, a BOOLEAN NULL, b BOOLEAN NULL)")
+        .await;
+    g.run(
+        "
+        INSERT INTO Logic VALUES
+            (1, TRUE, TRUE),
+            (2, TRUE, FALSE),
+            (3, TRUE, NULL),
+            (4, FALSE, TRUE),
+            (5, FALSE, FALSE),
+            (6, FALSE, NULL),
+            (7, NULL, TRUE),
+            (8, NULL, FALSE),
+            (9, NULL, NULL)
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "truth table of 'AND', 'OR' and 'NOT' with 'NULL'",
+        "SELECT id, a AND b AS and_, a OR b AS or_, NOT a AS not_ FROM Logic",
+        Ok(select_with_null!(
+            id     | and_        | or_         | not_;
+            I64(1)   Bool(true)    Bool(true)    Bool(false);
+            I64(2)   Bool(false)   Bool(true)    Bool(false);
+            I64(3)   Null          Bool(true)    Bool(false);
+            I64(4)   Bool(false)   Bool(true)    Bool(true);
+            I64(5)   Bool(false)   Bool(false)   Bool(true);
+            I64(6)   Bool(false)   Null          Bool(true);
+            I64(7)   Null          Bool(true)    Null;
+            I64(8)   Bool(false)   Null          Null;
+            I64(9)   Null          Null          Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "'NULL' literals follow the same logic",
+        "SELECT NULL AND FALSE AS a, NULL AND TRUE AS b, NULL OR TRUE AS c, NULL OR FALSE AS d",
+        Ok(select_with_null!(
+            a             | b      | c            | d;
+            Bool(false)     Null     Bool(true)     Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "'WHERE' keeps a row only when the condition is 'TRUE'",
+        "SELECT id FROM Logic WHERE a OR b",
+        Ok(select!(id; I64; 1; 2; 3; 4; 7)),
+    )
+    .await;
+
+    g.named_test(
+        "'NOT' of an unknown condition is still unknown",
+        "SELECT id FROM Logic WHERE NOT (a AND b)",
+        Ok(select!(id; I64; 2; 4; 5; 6; 8)),
+    )
+    .await;
+
+    g.named_test(
+        "comparison with 'NULL' combined by 'OR'",
+        "SELECT id FROM Logic WHERE b = NULL OR a IS NULL",
+        Ok(select!(id; I64; 7; 8; 9)),
+    )
+    .await;
+
+    g.named_test(
+        "'NOT' of 'OR' keeps only rows where both operands are 'FALSE'",
+        "SELECT id FROM Logic WHERE NOT (a = TRUE OR b = TRUE)",
+        Ok(select!(id; I64; 5)),
+    )
+    .await;
+
+    g.named_test(
+        "'JOIN' condition keeps only pairs where the condition is 'TRUE'",
+        "
+        SELECT l.id, r.id AS rid
+        FROM Logic l
+        LEFT JOIN Logic r ON r.id = l.id AND NOT (l.a AND l.b)
+        ",
+        Ok(select_with_null!(
+            id     | rid;
+            I64(1)   Null;
+            I64(2)   I64(2);
+            I64(3)   Null;
+            I64(4)   I64(4);
+            I64(5)   I64(5);
+            I64(6)   I64(6);
+            I64(7)   Null;
+            I64(8)   I64(8);
+            I64(9)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "'JOIN' condition without an equality join",
+        "
+        SELECT l.id, r.id AS rid
+        FROM Logic l
+        JOIN Logic r ON (l.a OR r.b) AND l.id = 8 AND r.id < 4
+        ",
+        Ok(select!(
+            id  | rid
+            I64 | I64;
+            8     1
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "non-boolean operands are still an error",
+        "SELECT id FROM Logic WHERE a OR id",
+        Err(EvaluateError::BooleanTypeRequired(format!("{:?}", I64(1))).into()),
+    )
+    .await;
+});
@@ -72,6 +72,31 @@ test_case!(filter, {
                 SELECT * FROM Hunter WHERE Hunter.name = Boss.name
              )",
         ),
+        (
+            5,
+            "SELECT name FROM Boss WHERE EXISTS (SELECT * FROM Hunter WHERE id = 1)",
+        ),
+        (
+            0,
+            "SELECT name FROM Boss WHERE EXISTS (SELECT * FROM Hunter WHERE id > 3)",
+        ),
+        (
+            5,
+            "SELECT name FROM Boss WHERE NOT EXISTS (SELECT * FROM Hunter WHERE id > 3)",
+        ),
+        (
+            // EXISTS stops at the first row, the second one would divide by zero
+            5,
+            "SELECT name FROM Boss WHERE EXISTS (SELECT 10 / (id - 2) FROM Hunter)",
+        ),
+        (
+            2,
+            "SELECT name
+             FROM Boss
+             WHERE id > 1 AND NOT EXISTS (
+                SELECT * FROM Hunter WHERE Hunter.name = Boss.name AND Hunter.id < 3
+             )",
+        ),
         (5, "SELECT name FROM Boss WHERE +1 = 1"),
         (3, "SELECT id FROM Hunter WHERE -1 = -1"),
         (5, "SELECT name FROM Boss WHERE -2.0 < -1.0"),
@@ -81,6 +81,18 @@ test_case!(abs, {
             }
             .into()),
         ),
+        (
+            "SELECT ABS(-9223372036854775807 - 1) AS ABS",
+            Err(EvaluateError::AbsOverflow("-9223372036854775808".to_owned()).into()),
+        ),
+        (
+            "SELECT ABS(CAST(-128 AS INT8)) AS ABS",
+            Err(EvaluateError::AbsOverflow("-128".to_owned()).into()),
+        ),
+        (
+            "SELECT ABS(-9223372036854775807) AS ABS",
+            Ok(select!(ABS; I64; 9223372036854775807)),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -441,3 +441,132 @@ test_case!(cast_value, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(cast_column, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Items (
+            id INTEGER,
+            price FLOAT NULL,
+            label TEXT NULL,
+            flag BOOLEAN NULL
+        )
+        ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Items VALUES
+            (42, 3.7, '12', TRUE),
+            (0, -1.5, '2.25', NULL),
+            (1, NULL, NULL, FALSE)
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "float to integer truncates toward zero",
+        "SELECT id, CAST(price AS INTEGER) AS price FROM Items",
+        Ok(select_with_null!(
+            id      | price;
+            I64(42)   I64(3);
+            I64(0)    I64(-1);
+            I64(1)    Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "integer to text in WHERE",
+        "SELECT id FROM Items WHERE CAST(id AS TEXT) = '42'",
+        Ok(select!(id I64; 42)),
+    )
+    .await;
+    g.named_test(
+        "text and integer to float",
+        "SELECT CAST(id AS FLOAT) AS id, CAST(label AS FLOAT) AS label FROM Items",
+        Ok(select_with_null!(
+            id       | label;
+            F64(42.0)  F64(12.0);
+            F64(0.0)   F64(2.25);
+            F64(1.0)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "float and boolean to text",
+        "SELECT CAST(price AS TEXT) AS price, CAST(flag AS TEXT) AS flag FROM Items",
+        Ok(select_with_null!(
+            price                 | flag;
+            Str("3.7".to_owned())   Str("TRUE".to_owned());
+            Str("-1.5".to_owned())  Null;
+            Null                    Str("FALSE".to_owned())
+        )),
+    )
+    .await;
+    g.named_test(
+        "text to integer",
+        "SELECT CAST(label AS INTEGER) + id AS sum FROM Items WHERE id = 42",
+        Ok(select!(sum I64; 54)),
+    )
+    .await;
+    g.named_test(
+        "boolean and integer round trip",
+        "SELECT CAST(flag AS INTEGER) AS num, CAST(CAST(flag AS INTEGER) AS BOOLEAN) AS flag FROM Items",
+        Ok(select_with_null!(
+            num    | flag;
+            I64(1)   Bool(true);
+            Null     Null;
+            I64(0)   Bool(false)
+        )),
+    )
+    .await;
+    g.named_test(
+        "integer to boolean",
+        "SELECT CAST(id AS BOOLEAN) AS flag FROM Items WHERE id < 2",
+        Ok(select!(flag Bool; false; true)),
+    )
+    .await;
+    g.named_test(
+        "text which is not an integer",
+        "SELECT CAST(label AS INTEGER) FROM Items WHERE id = 0",
+        Err(ConvertError {
+            value: Str("2.25".to_owned()),
+            data_type: DataType::Int,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "integer which is not a boolean",
+        "SELECT CAST(id AS BOOLEAN) FROM Items",
+        Err(ConvertError {
+            value: I64(42),
+            data_type: DataType::Boolean,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "literal text which is not an integer",
+        "SELECT CAST('abc' AS INTEGER) AS cast FROM Items",
+        Err(ValueError::LiteralCastFromTextToIntegerFailed("abc".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "literal text which is not a float",
+        "SELECT CAST('abc' AS FLOAT) AS cast FROM Items",
+        Err(ValueError::LiteralCastFromTextToFloatFailed("abc".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "NULL stays NULL",
+        "SELECT CAST(NULL AS INTEGER) AS a, CAST(NULL AS FLOAT) AS b, CAST(NULL AS TEXT) AS c FROM Items WHERE id = 1",
+        Ok(select_with_null!(
+            a    | b    | c;
+            Null   Null   Null
+        )),
+    )
+    .await;
+});
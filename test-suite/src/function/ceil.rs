@@ -19,8 +19,16 @@ test_case!(ceil, {
             ;",
             Ok(select!(
                 "ceil1" | "ceil2" | "ceil3" | "ceil4";
-                F64     | F64     | F64     | F64;
-                1.0       0.0       10.0      7.0
+                F64     | F64     | I64     | F64;
+                1.0       0.0       10        7.0
+            )),
+        ),
+        (
+            "SELECT CEIL(CAST(3 AS INT16)) AS ceil1, CEIL(CAST(2.5 AS FLOAT32)) AS ceil2",
+            Ok(select!(
+                ceil1 | ceil2
+                I16   | F32;
+                3       3.0
             )),
         ),
         (
@@ -1,6 +1,9 @@
 use {
     crate::*,
-    gluesql_core::{error::EvaluateError, prelude::Value::*},
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(coalesce, {
@@ -184,4 +187,38 @@ test_case!(coalesce, {
             I64(5)   Str("Universe".to_owned())
         ))
     ).await;
+
+    g.run("CREATE TABLE Users (id INTEGER, nickname TEXT NULL, name TEXT NULL, zero INTEGER);")
+        .await;
+    g.run(
+        "INSERT INTO Users VALUES (1, 'Glue', 'Alice', 0), (2, NULL, 'Bob', 0), (3, NULL, NULL, 0);",
+    )
+    .await;
+
+    g.named_test(
+        "first non-NULL argument from left to right",
+        "SELECT id, COALESCE(nickname, name, 'anonymous') AS display_name FROM Users",
+        Ok(select!(
+            id  | display_name
+            I64 | Str;
+            1     "Glue".to_owned();
+            2     "Bob".to_owned();
+            3     "anonymous".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "arguments after the first non-NULL one are not evaluated",
+        "SELECT COALESCE(nickname, id / zero) AS lazy FROM Users WHERE id = 1",
+        Ok(select!(lazy Str; "Glue".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "arguments are evaluated until a non-NULL one is found",
+        "SELECT COALESCE(nickname, id / zero) AS lazy FROM Users WHERE id = 2",
+        Err(ValueError::DivisorShouldNotBeZero.into()),
+    )
+    .await;
 });
@@ -20,8 +20,15 @@ test_case!(floor, {
             ;"#,
             Ok(select!(
                 floor1 | floor2              | floor3 | floor4
-                F64    | F64                 | F64    | F64;
-                0.0      f64::from(-1)   10.0     6.0
+                F64    | F64                 | I64    | F64;
+                0.0      f64::from(-1)   10       6.0
+            )),
+        ),
+        (
+            "SELECT FLOOR(CAST(3 AS UINT8)) AS floor1, FLOOR(CAST(-2.5 AS FLOAT32)) AS floor2",
+            Ok(select_with_null!(
+                floor1 | floor2;
+                U8(3)    F32(-3.0)
             )),
         ),
         (
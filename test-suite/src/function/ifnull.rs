@@ -1,7 +1,10 @@
 use {
     crate::*,
     chrono::{NaiveDate, NaiveTime},
-    gluesql_core::prelude::{Payload, Value::*},
+    gluesql_core::{
+        error::ValueError,
+        prelude::{Payload, Value::*},
+    },
     rust_decimal::Decimal,
 };
 
@@ -78,4 +81,18 @@ test_case!(ifnull, {
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
+
+    g.named_test(
+        "second argument is not evaluated when the first one is not NULL",
+        "SELECT IFNULL(int8, 10 / id) AS lazy FROM SingleItem WHERE id IS NOT NULL",
+        Ok(select!(lazy I8; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "second argument is evaluated when the first one is NULL",
+        "SELECT IFNULL(NULL, 10 / id) AS lazy FROM SingleItem WHERE id IS NOT NULL",
+        Err(ValueError::DivisorShouldNotBeZero.into()),
+    )
+    .await;
 });
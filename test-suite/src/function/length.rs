@@ -1,4 +1,10 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
 
 test_case!(length, {
     let g = get_tester!();
@@ -101,4 +107,30 @@ test_case!(length, {
         )),
     )
     .await;
+
+    g.named_test(
+        "test length with null",
+        "SELECT LENGTH(NULL) AS len",
+        Ok(select_with_null!(len; Null)),
+    )
+    .await;
+
+    g.named_test(
+        "test length with integer",
+        "SELECT LENGTH(10)",
+        Err(EvaluateError::FunctionRequiresStrOrListOrMapValue("LENGTH".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "test length with too many arguments",
+        "SELECT LENGTH('a', 'b')",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "LENGTH".to_owned(),
+            expected: 1,
+            found: 2,
+        }
+        .into()),
+    )
+    .await;
 });
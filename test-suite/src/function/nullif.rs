@@ -1,7 +1,11 @@
 use {
     crate::*,
     chrono::NaiveDate,
-    gluesql_core::{error::TranslateError, prelude::Value::*},
+    gluesql_core::{
+        ast::BinaryOperator,
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(nullif, {
@@ -70,4 +74,61 @@ test_case!(nullif, {
         .into()),
     )
     .await;
+
+    g.run("CREATE TABLE Ratio (id INTEGER, value INTEGER, denominator INTEGER NULL)")
+        .await;
+    g.run("INSERT INTO Ratio VALUES (1, 10, 2), (2, 10, 0), (3, 10, NULL)")
+        .await;
+
+    g.named_test(
+        "NULLIF avoids division by zero",
+        "SELECT id, value / NULLIF(denominator, 0) AS ratio FROM Ratio",
+        Ok(select_with_null!(
+            id     | ratio;
+            I64(1)   I64(5);
+            I64(2)   Null;
+            I64(3)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF compares integer and float like a filter does",
+        "SELECT id, NULLIF(denominator, 2.0) AS result FROM Ratio WHERE id = 1",
+        Ok(select_with_null!(id | result; I64(1) Null)),
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF compares strings exactly",
+        "SELECT NULLIF('glue', 'GLUE') AS result",
+        Ok(select!(result Str; "glue".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF with a NULL second argument returns the first argument",
+        "SELECT id, NULLIF(value, denominator) AS result FROM Ratio WHERE id = 3",
+        Ok(select!(id | result; I64 | I64; 3 10)),
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF of a number and a string fails",
+        "SELECT NULLIF(1, 'one') AS result",
+        Err(EvaluateError::NumberComparedWithString {
+            left: "I64(1)".to_owned(),
+            op: BinaryOperator::Eq,
+            right: r#"Str("one")"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF in WHERE",
+        "SELECT id FROM Ratio WHERE NULLIF(denominator, 0) IS NULL",
+        Ok(select!(id I64; 2; 3)),
+    )
+    .await;
 });
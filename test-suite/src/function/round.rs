@@ -1,6 +1,8 @@
 use {
     crate::*,
     gluesql_core::{
+        ast::DataType,
+        data::ConvertError,
         error::{EvaluateError, TranslateError},
         prelude::Value::*,
     },
@@ -19,8 +21,8 @@ test_case!(round, {
             ;",
             Ok(select!(
                 round1 | round2          | round3 | round4
-                F64    | F64             | F64    | F64;
-                0.0      f64::from(-1)   10.0     7.0
+                F64    | F64             | I64    | F64;
+                0.0      f64::from(-1)   10       7.0
             )),
         ),
         (
@@ -40,11 +42,99 @@ test_case!(round, {
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("ROUND")).into()),
         ),
         (
-            "SELECT ROUND('string', 'string2') AS round",
-            Err(TranslateError::FunctionArgsLengthNotMatching {
+            "SELECT
+                ROUND(-2.345, 1) AS round1,
+                ROUND(12.34567, 2) AS round2,
+                ROUND(1234.5, -2) AS round3,
+                ROUND(5, 2) AS round4
+            ;",
+            Ok(select!(
+                round1 | round2 | round3 | round4
+                F64    | F64    | F64    | I64;
+                -2.3     12.35    1200.0   5
+            )),
+        ),
+        (
+            "SELECT ROUND(1.5, 400) AS round1, ROUND(1.5, -400) AS round2",
+            Ok(select!(
+                round1 | round2
+                F64    | F64;
+                1.5      0.0
+            )),
+        ),
+        (
+            // halves are rounded away from zero
+            "SELECT ROUND(2.5) AS round1, ROUND(-2.5) AS round2, ROUND(0.125, 2) AS round3",
+            Ok(select_with_null!(
+                round1   | round2    | round3;
+                F64(3.0)   F64(-3.0)   F64(0.13)
+            )),
+        ),
+        (
+            // integers keep their type, and only change with a negative precision
+            "SELECT
+                ROUND(1250, -2) AS round1,
+                ROUND(-1250, -2) AS round2,
+                ROUND(CAST(12 AS INT8), -1) AS round3,
+                ROUND(CAST(12 AS UINT16), 3) AS round4
+            ;",
+            Ok(select_with_null!(
+                round1      | round2       | round3  | round4;
+                I64(1300)     I64(-1300)     I8(10)    U16(12)
+            )),
+        ),
+        (
+            // integers are rounded without going through a float
+            "SELECT
+                ROUND(9007199254740993, -1) AS round1,
+                ROUND(-9007199254740995, -1) AS round2,
+                ROUND(CAST(340282366920938463463374607431768211451 AS UINT128), -1) AS round3,
+                ROUND(1250, -400) AS round4
+            ;",
+            Ok(select_with_null!(
+                round1                  | round2                   | round3                                        | round4;
+                I64(9007199254740990)     I64(-9007199254741000)     U128(340282366920938463463374607431768211450)   I64(0)
+            )),
+        ),
+        (
+            "SELECT ROUND(CAST(340282366920938463463374607431768211455 AS UINT128), -1) AS round",
+            Err(ConvertError {
+                value: U128(u128::MAX),
+                data_type: DataType::Uint128,
+            }
+            .into()),
+        ),
+        (
+            "SELECT ROUND(CAST(127 AS INT8), -1) AS round",
+            Err(ConvertError {
+                value: I128(130),
+                data_type: DataType::Int8,
+            }
+            .into()),
+        ),
+        (
+            "SELECT ROUND(NULL, 2) AS round1, ROUND(2.5, NULL) AS round2",
+            Ok(select_with_null!(round1 | round2; Null Null)),
+        ),
+        (
+            "SELECT ROUND(2.5, 'string') AS round",
+            Err(EvaluateError::FunctionRequiresIntegerValue(String::from("ROUND")).into()),
+        ),
+        (
+            "SELECT ROUND(2.5, 1.5) AS round",
+            Err(EvaluateError::FunctionRequiresIntegerValue(String::from("ROUND")).into()),
+        ),
+        (
+            "SELECT ROUND('string', 2) AS round",
+            Err(EvaluateError::FunctionRequiresFloatValue(String::from("ROUND")).into()),
+        ),
+        (
+            "SELECT ROUND(1, 2, 3) AS round",
+            Err(TranslateError::FunctionArgsLengthNotWithinRange {
                 name: "ROUND".to_owned(),
-                expected: 1,
-                found: 2,
+                expected_minimum: 1,
+                expected_maximum: 2,
+                found: 3,
             }
             .into()),
         ),
@@ -59,7 +59,7 @@ test_case!(splice, {
 
     g.named_test(
         "SPLICE(CAST('[1, 2, 3]' AS List), 2, 4, 9) should return EvaluateError::ListTypeRequired",
-        "SELECT SPLICE(CAST('[1, 2, 3]' AS List), 2, 4, 9), AS actual",
+        "SELECT SPLICE(CAST('[1, 2, 3]' AS List), 2, 4, 9) AS actual",
         Err(EvaluateError::ListTypeRequired.into()),
     )
     .await;
@@ -2,7 +2,7 @@ use {
     crate::*,
     gluesql_core::{
         ast::BinaryOperator,
-        error::EvaluateError,
+        error::{EvaluateError, TranslateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -237,6 +237,26 @@ test_case!(substr, {
             "SELECT * FROM SingleItem WHERE TRUE AND SUBSTR('wine',2,3)",
             Err(EvaluateError::BooleanTypeRequired("ine".to_owned()).into()),
         ),
+        (
+            "SELECT SUBSTR('Words') AS test FROM SingleItem",
+            Err(TranslateError::FunctionArgsLengthNotWithinRange {
+                name: "SUBSTR".to_owned(),
+                expected_minimum: 2,
+                expected_maximum: 3,
+                found: 1,
+            }
+            .into()),
+        ),
+        (
+            "SELECT SUBSTR('Words', 1, 2, 3) AS test FROM SingleItem",
+            Err(TranslateError::FunctionArgsLengthNotWithinRange {
+                name: "SUBSTR".to_owned(),
+                expected_minimum: 2,
+                expected_maximum: 3,
+                found: 4,
+            }
+            .into()),
+        ),
         (
             r#"SELECT SUBSTR(1, 1) AS test FROM SingleItem"#,
             Err(EvaluateError::FunctionRequiresStringValue("SUBSTR".to_owned()).into()),
@@ -155,6 +155,53 @@ test_case!(trim, {
             "SELECT TRIM(1 FROM TRIM('t' FROM 'tartare')) AS test FROM Test",
             Err(EvaluateError::FunctionRequiresStringValue("TRIM".to_owned()).into()),
         ),
+        (
+            "CREATE TABLE Tags (id INTEGER, tag TEXT NULL)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO Tags VALUES
+                (1, '  rust '),
+                (2, 'rust'),
+                (3, 'xxsqlxx'),
+                (4, ' 日本語  '),
+                (5, NULL);",
+            Ok(Payload::Insert(5)),
+        ),
+        (
+            "SELECT TRIM(tag) AS tag, COUNT(*) AS cnt FROM Tags GROUP BY TRIM(tag)",
+            Ok(select_with_null!(
+                tag                            | cnt;
+                Value::Str("rust".to_owned())    Value::I64(2);
+                Value::Str("xxsqlxx".to_owned()) Value::I64(1);
+                Value::Str("日本語".to_owned())  Value::I64(1);
+                Value::Null                      Value::I64(1)
+            )),
+        ),
+        (
+            "SELECT id, LENGTH(TRIM(tag)) AS len FROM Tags ORDER BY LENGTH(TRIM(tag)) DESC, id",
+            Ok(select_with_null!(
+                id            | len;
+                Value::I64(5)   Value::Null;
+                Value::I64(3)   Value::U64(7);
+                Value::I64(1)   Value::U64(4);
+                Value::I64(2)   Value::U64(4);
+                Value::I64(4)   Value::U64(3)
+            )),
+        ),
+        (
+            "SELECT id FROM Tags WHERE TRIM(BOTH 'x' FROM tag) = 'sql'",
+            Ok(select!(id Value::I64; 3)),
+        ),
+        (
+            "SELECT id, LENGTH(tag) AS len FROM Tags WHERE LTRIM(tag) = 'rust ' OR RTRIM(tag) = ' 日本語'",
+            Ok(select!(
+                id         | len
+                Value::I64 | Value::U64;
+                1            7;
+                4            6
+            )),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -84,4 +84,46 @@ test_case!(upper_lower, {
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    g.run("CREATE TABLE Users (id INTEGER, name TEXT NULL, email TEXT)")
+        .await;
+    g.run("INSERT INTO Users VALUES (1, 'Alice', 'A@B.com'), (2, NULL, 'x@y.z')")
+        .await;
+
+    let test_cases = [
+        (
+            "SELECT UPPER(name) AS name FROM Users WHERE LOWER(email) = 'a@b.com'",
+            Ok(select!(name Str; "ALICE".to_owned())),
+        ),
+        (
+            "SELECT id FROM Users ORDER BY UPPER(email) DESC",
+            Ok(select!(id I64; 2; 1)),
+        ),
+        (
+            // full Unicode case mapping, so a character may change the length
+            "SELECT UPPER('straße') AS upper, LOWER('ΣΊΣΥΦΟΣ') AS lower, UPPER(LOWER('MiXeD')) AS nested FROM Users LIMIT 1",
+            Ok(select!(
+                upper                | lower                | nested
+                Str                  | Str                  | Str;
+                "STRASSE".to_owned()   "σίσυφος".to_owned()   "MIXED".to_owned()
+            )),
+        ),
+        (
+            "SELECT UPPER(id) FROM Users",
+            Err(EvaluateError::FunctionRequiresStringValue("UPPER".to_owned()).into()),
+        ),
+        (
+            "SELECT UPPER(name, email) FROM Users",
+            Err(TranslateError::FunctionArgsLengthNotMatching {
+                name: "UPPER".to_owned(),
+                expected: 1,
+                found: 2,
+            }
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
 });
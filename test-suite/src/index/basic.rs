@@ -323,6 +323,12 @@ test_case!(basic, {
     )
     .await;
 
+    g.test(
+        "CREATE INDEX idx_nulls ON Test (num NULLS FIRST)",
+        Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into()),
+    )
+    .await;
+
     g.test(
         "DROP INDEX Test.idx_id, Test.idx_id2",
         Err(TranslateError::TooManyParamsInDropIndex.into()),
@@ -358,4 +364,23 @@ test_case!(basic, {
         Err(IndexError::IndexNameDoesNotExist("idx_aaa".to_owned()).into()),
     )
     .await;
+
+    g.test("TRUNCATE TABLE Test", Ok(Payload::Truncate)).await;
+    g.test_idx(
+        "SELECT id, num FROM Test WHERE id < 20",
+        Ok(select!(id | num)),
+        idx!(idx_id, Lt, "20"),
+    )
+    .await;
+    g.run("INSERT INTO Test VALUES (3, 30, 'Again')").await;
+    g.test_idx(
+        "SELECT id, num FROM Test WHERE id < 20",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            3     30
+        )),
+        idx!(idx_id, Lt, "20"),
+    )
+    .await;
 });
@@ -96,6 +96,31 @@ CREATE TABLE Test (
         idx!(idx_num_desc, DESC),
     )
     .await;
+
+    g.test_idx(
+        "SELECT * FROM Test where id < 4 ORDER BY num DESC NULLS LAST",
+        Ok(select_with_null!(
+            id     | num    | name;
+            I64(1)   I64(9)   s!("Wild");
+            I64(1)   I64(2)   s!("Hello");
+            I64(3)   Null     s!("World")
+        )),
+        idx!(),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Test ORDER BY num NULLS FIRST",
+        Ok(select_with_null!(
+            id     | num    | name;
+            I64(3)   Null     s!("World");
+            I64(1)   I64(2)   s!("Hello");
+            I64(4)   I64(7)   s!("Monday");
+            I64(1)   I64(9)   s!("Wild")
+        )),
+        idx!(),
+    )
+    .await;
 });
 
 test_case!(order_by_multi, {
@@ -241,14 +241,18 @@ test_case!(inline_view, {
             )),
         ),
         (
-            // unsupported implicit join
+            // implicit join, the inline view can't refer to the tables listed before it
             "SELECT *
             FROM OuterTable, (
                     SELECT id
                     FROM InnerTable
                     WHERE InnerTable.id = OuterTable.id
                 ) AS InlineView",
-            Err(TranslateError::TooManyTables.into()),
+            Err(EvaluateError::CompoundIdentifierNotFound {
+                table_alias: "OuterTable".to_owned(),
+                column_name: "id".to_owned(),
+            }
+            .into()),
         ),
         (
             // select distinct
@@ -275,6 +279,47 @@ test_case!(inline_view, {
                 2     "SQL".to_owned()    2     "EXTRA".to_owned()
             )),
         ),
+        (
+            // outer WHERE and projection resolve against the inline view columns
+            "SELECT name
+            FROM (
+                SELECT * FROM InnerTable WHERE id > 1
+            ) AS InlineView
+            WHERE name LIKE 'S%' AND id < 3",
+            Ok(select!(
+                name
+                Str;
+                "SQL".to_owned()
+            )),
+        ),
+        (
+            // inline view columns are named by the inner projection labels
+            "SELECT InlineView.label, InlineView.doubled
+            FROM (
+                SELECT name AS label, id * 2 AS doubled FROM InnerTable
+            ) AS InlineView
+            WHERE doubled > 2",
+            Ok(select!(
+                label             | doubled
+                Str               | I64;
+                "SQL".to_owned()    4;
+                "SQL".to_owned()    6
+            )),
+        ),
+        (
+            // inner column names are hidden behind the labels
+            "SELECT InlineView.name FROM (SELECT name AS label FROM InnerTable) AS InlineView",
+            Err(EvaluateError::CompoundIdentifierNotFound {
+                table_alias: "InlineView".to_owned(),
+                column_name: "name".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            // an alias is required even when the inline view is joined
+            "SELECT * FROM OuterTable JOIN (SELECT * FROM InnerTable) ON TRUE",
+            Err(TranslateError::LackOfAlias.into()),
+        ),
     ];
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
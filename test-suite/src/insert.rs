@@ -1,7 +1,9 @@
 use {
     crate::*,
     gluesql_core::{
-        error::InsertError,
+        ast::DataType,
+        data::Key,
+        error::{Error, InsertError, TranslateError, UpdateError, ValidateError, ValueError},
         prelude::{Payload, Value::*},
     },
 };
@@ -100,4 +102,543 @@ CREATE TABLE Test (
         )),
     )
     .await;
+
+    g.run("CREATE TABLE Multi (id INTEGER, name TEXT NULL)")
+        .await;
+
+    g.named_test(
+        "insert multiple rows returns the number of inserted rows",
+        "INSERT INTO Multi VALUES (1, 'a'), (2, 'b'), (3, 'c')",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+
+    g.named_test(
+        "every row is validated against the target columns",
+        "INSERT INTO Multi VALUES (4, 'd'), (5, 'e', 'extra')",
+        Err(Error::Tuple {
+            index: 1,
+            error: Box::new(
+                InsertError::TooManyValues {
+                    expected: 2,
+                    found: 3,
+                }
+                .into(),
+            ),
+        }),
+    )
+    .await;
+
+    g.named_test(
+        "every row is validated against the listed columns",
+        "INSERT INTO Multi (id) VALUES (4), (5, 'e')",
+        Err(Error::Tuple {
+            index: 1,
+            error: Box::new(
+                InsertError::ColumnAndValuesNotMatched {
+                    expected: 1,
+                    found: 2,
+                }
+                .into(),
+            ),
+        }),
+    )
+    .await;
+
+    g.named_test(
+        "no row is inserted when any row is invalid",
+        "SELECT id, name FROM Multi",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "a value failing in a later row aborts the whole insert, reporting the row",
+        "INSERT INTO Multi VALUES (4, 'd'), (5, 'e'), ('six', 'f')",
+        Err(Error::Tuple {
+            index: 2,
+            error: Box::new(
+                ValidateError::TypeMismatch {
+                    table_name: "Multi".to_owned(),
+                    column_name: "id".to_owned(),
+                    expected: DataType::Int,
+                    found: Str("six".to_owned()),
+                }
+                .into(),
+            ),
+        }),
+    )
+    .await;
+
+    g.count("SELECT * FROM Multi", 3).await;
+
+    g.named_test(
+        "rows of a single insert may mix values and NULL",
+        "INSERT INTO Multi VALUES (4, NULL), (5, 'e')",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+
+    g.count("SELECT * FROM Multi WHERE name IS NULL", 1).await;
+
+    g.run("CREATE TABLE Logs (id INTEGER, msg TEXT, old BOOLEAN)")
+        .await;
+    g.run("INSERT INTO Logs VALUES (1, 'a', TRUE), (2, 'b', FALSE), (3, 'c', TRUE)")
+        .await;
+    g.run(
+        "CREATE TABLE Archive (id INTEGER NOT NULL, msg TEXT DEFAULT 'archived', note TEXT NULL)",
+    )
+    .await;
+
+    g.named_test(
+        "insert select with a filter",
+        "INSERT INTO Archive SELECT id, msg FROM Logs WHERE old = TRUE",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+
+    g.named_test(
+        "insert select maps values to the listed columns",
+        "INSERT INTO Archive (note, id) SELECT msg, id * 10 FROM Logs WHERE NOT old",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+
+    g.named_test(
+        "columns not given by the select are filled with default or NULL",
+        "SELECT id, msg, note FROM Archive",
+        Ok(select_with_null!(
+            id      | msg                          | note;
+            I64(1)    Str("a".to_owned())            Null;
+            I64(3)    Str("c".to_owned())            Null;
+            I64(20)   Str("archived".to_owned())     Str("b".to_owned())
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "insert select with more columns than the target table",
+        "INSERT INTO Archive SELECT id, msg, msg, old FROM Logs",
+        Err(InsertError::TooManyValues {
+            expected: 3,
+            found: 4,
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert select with a different number of columns than listed",
+        "INSERT INTO Archive (id) SELECT id, msg FROM Logs",
+        Err(InsertError::ColumnAndValuesNotMatched {
+            expected: 1,
+            found: 2,
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert select into a wrong column name",
+        "INSERT INTO Archive (id, memo) SELECT id, msg FROM Logs",
+        Err(InsertError::WrongColumnName("memo".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert select without a required column",
+        "INSERT INTO Archive (msg) SELECT msg FROM Logs",
+        Err(InsertError::LackOfRequiredColumn("id".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert select with a value of another type",
+        "INSERT INTO Archive (id, msg) SELECT id, old FROM Logs",
+        Err(ValidateError::TypeMismatch {
+            table_name: "Archive".to_owned(),
+            column_name: "msg".to_owned(),
+            expected: DataType::Text,
+            found: Bool(true),
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert select reading from the target table",
+        "INSERT INTO Logs SELECT id + 3, msg, NOT old FROM Logs",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+
+    g.named_test(
+        "rows inserted by the same statement are not selected again",
+        "SELECT id, old FROM Logs",
+        Ok(select!(
+            id  | old
+            I64 | Bool;
+            1     true;
+            2     false;
+            3     true;
+            4     false;
+            5     true;
+            6     false
+        )),
+    )
+    .await;
+});
+
+test_case!(insert_type_check, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Typed (i INTEGER, f FLOAT, s TEXT, t INT8)")
+        .await;
+
+    g.named_test(
+        "integer literal is coerced into a float column",
+        "INSERT INTO Typed VALUES (1, 2, 'a', 3)",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Typed",
+        Ok(select!(
+            i   | f   | s               | t
+            I64 | F64 | Str             | I8;
+            1     2.0   "a".to_owned()    3
+        )),
+    )
+    .await;
+
+    let type_mismatch = |column_name: &str, expected, found| {
+        Err(ValidateError::TypeMismatch {
+            table_name: "Typed".to_owned(),
+            column_name: column_name.to_owned(),
+            expected,
+            found,
+        }
+        .into())
+    };
+    g.named_test(
+        "text literal into an integer column",
+        "INSERT INTO Typed VALUES ('abc', 1, 'a', 1)",
+        type_mismatch("i", DataType::Int, Str("abc".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "float literal is not truncated into an integer column",
+        "INSERT INTO Typed VALUES (1.5, 1, 'a', 1)",
+        type_mismatch("i", DataType::Int, Decimal("1.5".parse().unwrap())),
+    )
+    .await;
+    g.named_test(
+        "float literal with a zero fraction into an integer column",
+        "INSERT INTO Typed VALUES (1, 1, 'a', 2.0)",
+        type_mismatch("t", DataType::Int8, Decimal("2.0".parse().unwrap())),
+    )
+    .await;
+    g.named_test(
+        "number literal into a text column",
+        "INSERT INTO Typed VALUES (1, 1, 1, 1)",
+        type_mismatch("s", DataType::Text, Decimal(1.into())),
+    )
+    .await;
+    g.named_test(
+        "integer literal out of the column range",
+        "INSERT INTO Typed VALUES (1, 1, 'a', 300)",
+        Err(ValueError::FailedToParseNumber.into()),
+    )
+    .await;
+
+    g.named_test(
+        "evaluated float into an integer column",
+        "INSERT INTO Typed VALUES (CAST(1.5 AS FLOAT), 1, 'a', 1)",
+        Err(ValidateError::TypeMismatch {
+            table_name: "Typed".to_owned(),
+            column_name: "i".to_owned(),
+            expected: DataType::Int,
+            found: F64(1.5),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "evaluated integer of another size",
+        "INSERT INTO Typed VALUES (1, 1, 'a', CAST(300 AS INT))",
+        Err(ValidateError::TypeMismatch {
+            table_name: "Typed".to_owned(),
+            column_name: "t".to_owned(),
+            expected: DataType::Int8,
+            found: I64(300),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "evaluated string into an integer column",
+        "INSERT INTO Typed VALUES (UPPER('a'), 1, 'a', 1)",
+        Err(ValidateError::TypeMismatch {
+            table_name: "Typed".to_owned(),
+            column_name: "i".to_owned(),
+            expected: DataType::Int,
+            found: Str("A".to_owned()),
+        }
+        .into()),
+    )
+    .await;
+
+    g.count("SELECT * FROM Typed", 1).await;
+});
+
+test_case!(insert_on_conflict, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT UNIQUE, stock INTEGER)")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 'apple', 10), (2, 'banana', 20)")
+        .await;
+
+    g.named_test(
+        "ON CONFLICT DO NOTHING skips rows whose primary key exists",
+        "INSERT INTO Item VALUES (1, 'avocado', 0), (3, 'cherry', 30) ON CONFLICT DO NOTHING",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 0,
+        }),
+    )
+    .await;
+    g.named_test(
+        "a key proposed twice is a conflict with the earlier row",
+        "INSERT INTO Item VALUES (4, 'durian', 40), (4, 'date', 41) ON CONFLICT (id) DO NOTHING",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 0,
+        }),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Item",
+        Ok(select!(
+            id  | name                 | stock
+            I64 | Str                  | I64;
+            1     "apple".to_owned()     10;
+            2     "banana".to_owned()    20;
+            3     "cherry".to_owned()    30;
+            4     "durian".to_owned()    40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ON CONFLICT DO UPDATE updates the existing row using excluded",
+        "INSERT INTO Item VALUES (1, 'avocado', 5), (5, 'elderberry', 50)
+        ON CONFLICT (id) DO UPDATE SET stock = stock + excluded.stock",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 1,
+        }),
+    )
+    .await;
+    g.named_test(
+        "ON CONFLICT DO UPDATE only updates the rows matching WHERE",
+        "INSERT INTO Item VALUES (2, 'blueberry', 1), (3, 'coconut', 100)
+        ON CONFLICT DO UPDATE SET name = excluded.name WHERE excluded.stock > Item.stock
+        RETURNING id, name",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "coconut".to_owned()
+        )),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Item",
+        Ok(select!(
+            id  | name                     | stock
+            I64 | Str                      | I64;
+            1     "apple".to_owned()         15;
+            2     "banana".to_owned()        20;
+            3     "coconut".to_owned()       30;
+            4     "durian".to_owned()        40;
+            5     "elderberry".to_owned()    50
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "updated rows are validated",
+        "INSERT INTO Item VALUES (1, 'banana', 0) ON CONFLICT DO UPDATE SET name = excluded.name",
+        Err(ValidateError::DuplicateEntryOnUniqueField(
+            Str("banana".to_owned()),
+            "name".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "inserted and updated rows are validated against each other",
+        "INSERT INTO Item VALUES (6, 'fig', 0), (1, 'fig', 0)
+        ON CONFLICT DO UPDATE SET name = excluded.name",
+        Err(
+            ValidateError::DuplicateEntryOnUniqueField(Str("fig".to_owned()), "name".to_owned())
+                .into(),
+        ),
+    )
+    .await;
+    g.named_test(
+        "a row cannot be updated twice by one statement",
+        "INSERT INTO Item VALUES (1, 'a', 1), (1, 'b', 2) ON CONFLICT DO UPDATE SET stock = 0",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(1)).into()),
+    )
+    .await;
+    g.named_test(
+        "primary key cannot be updated",
+        "INSERT INTO Item VALUES (1, 'a', 1) ON CONFLICT DO UPDATE SET id = 10",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "conflict target must be the primary key or a unique column",
+        "INSERT INTO Item VALUES (6, 'apple', 1) ON CONFLICT (stock) DO NOTHING",
+        Err(InsertError::ConflictTargetNotUnique("stock".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "conflict target by constraint name",
+        "INSERT INTO Item VALUES (6, 'fig', 1) ON CONFLICT ON CONSTRAINT item_pkey DO NOTHING",
+        Err(TranslateError::ConflictOnConstraintNotSupported("item_pkey".to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE Log (id INTEGER, msg TEXT)").await;
+    g.named_test(
+        "ON CONFLICT requires a primary key",
+        "INSERT INTO Log VALUES (1, 'a') ON CONFLICT DO NOTHING",
+        Err(InsertError::OnConflictWithoutPrimaryKey("Log".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "failed statements leave the table untouched",
+        "SELECT id, stock FROM Item",
+        Ok(select!(
+            id  | stock
+            I64 | I64;
+            1     15;
+            2     20;
+            3     30;
+            4     40;
+            5     50
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ON CONFLICT on a unique column",
+        "INSERT INTO Item VALUES (6, 'apple', 7), (7, 'grape', 70)
+        ON CONFLICT (name) DO UPDATE SET stock = excluded.stock",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 1,
+        }),
+    )
+    .await;
+    g.named_test(
+        "a unique value proposed twice cannot update a row twice",
+        "INSERT INTO Item VALUES (8, 'grape', 1), (9, 'grape', 2)
+        ON CONFLICT (name) DO UPDATE SET stock = 0",
+        Err(
+            ValidateError::DuplicateEntryOnUniqueField(Str("grape".to_owned()), "name".to_owned())
+                .into(),
+        ),
+    )
+    .await;
+    g.named_test(
+        "rows conflicting on another unique column than the target are validated",
+        "INSERT INTO Item VALUES (1, 'honeydew', 0) ON CONFLICT (name) DO NOTHING",
+        Err(ValidateError::DuplicateEntryOnUniqueField(I64(1), "id".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, stock FROM Item WHERE id IN (1, 7)",
+        Ok(select!(
+            id  | name                | stock
+            I64 | Str                 | I64;
+            1     "apple".to_owned()    7;
+            7     "grape".to_owned()    70
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Stock (shop INTEGER, item INTEGER, qty INTEGER, PRIMARY KEY (shop, item))")
+        .await;
+    g.run("INSERT INTO Stock VALUES (1, 1, 10), (1, 2, 20)")
+        .await;
+    g.named_test(
+        "ON CONFLICT on a composite primary key",
+        "INSERT INTO Stock VALUES (1, 2, 5), (2, 1, 30)
+        ON CONFLICT (item, shop) DO UPDATE SET qty = qty + excluded.qty",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 1,
+        }),
+    )
+    .await;
+    g.named_test(
+        "ON CONFLICT without a target uses the composite primary key",
+        "INSERT INTO Stock VALUES (1, 1, 0), (2, 2, 40) ON CONFLICT DO NOTHING",
+        Ok(Payload::Upsert {
+            inserted: 1,
+            updated: 0,
+        }),
+    )
+    .await;
+    g.named_test(
+        "a part of the composite primary key is not a conflict target",
+        "INSERT INTO Stock VALUES (1, 1, 0) ON CONFLICT (shop) DO NOTHING",
+        Err(InsertError::ConflictTargetNotUnique("shop".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "a composite key proposed twice cannot update a row twice",
+        "INSERT INTO Stock VALUES (1, 1, 1), (1, 1, 2) ON CONFLICT DO UPDATE SET qty = 0",
+        Err(ValidateError::DuplicateEntryOnCompositePrimaryKey(
+            vec![I64(1), I64(1)],
+            vec!["shop".to_owned(), "item".to_owned()],
+        )
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Stock ORDER BY shop, item",
+        Ok(select!(
+            shop | item | qty
+            I64  | I64  | I64;
+            1      1      10;
+            1      2      25;
+            2      1      30;
+            2      2      40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "RETURNING follows the order of VALUES across updated and inserted rows",
+        "INSERT INTO Stock VALUES (1, 1, 1), (3, 1, 5)
+        ON CONFLICT DO UPDATE SET qty = qty + excluded.qty
+        RETURNING shop, item, qty",
+        Ok(select!(
+            shop | item | qty
+            I64  | I64  | I64;
+            1      1      11;
+            3      1      5
+        )),
+    )
+    .await;
 });
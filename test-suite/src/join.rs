@@ -302,6 +302,21 @@ test_case!(project, {
     );
     g.test(sql, Ok(expected)).await;
 
+    let sql = "
+        SELECT Item.id, p.*, quantity
+        FROM Player p
+        JOIN Item
+        ON p.id = player_id
+    ";
+    let expected = select!(
+        id  | id  | name                   | quantity
+        I64 | I64 | Str                    | I64;
+        101   1     "Taehoon".to_owned()     1;
+        102   2     "Mike".to_owned()        4;
+        103   4     "Berry".to_owned()       9
+    );
+    g.test(sql, Ok(expected)).await;
+
     let sql = "
         SELECT *
         FROM Player p
@@ -318,6 +333,267 @@ test_case!(project, {
     );
     g.test(sql, Ok(expected)).await;
 
+    let sql = "
+        SELECT p.id, p.name
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        WHERE i.id IS NULL
+    ";
+    let expected = select!(
+        id  | name
+        I64 | Str;
+        3     "Jorno".to_owned();
+        5     "Hwan".to_owned()
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        WHERE i.id IS NOT NULL
+    ";
+    g.test(sql, Ok(select!(id; I64; 1; 2; 4))).await;
+
+    let sql = "
+        SELECT p.id
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        WHERE i.id = NULL OR NOT (i.id <> NULL)
+    ";
+    g.test(sql, Ok(select!(id))).await;
+
+    let sql = "
+        SELECT p.id, i.quantity + 1 AS q
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        WHERE p.id > 2
+    ";
+    let expected = select_with_null!(
+        id     | q;
+        I64(3)   Null;
+        I64(4)   I64(10);
+        I64(5)   Null
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT *
+        FROM Item
+        INNER JOIN Player ON Player.id = Item.player_id
+    ";
+    let expected = select_with_null!(
+        id       | quantity | player_id | id     | name;
+        I64(101)   I64(1)     I64(1)      I64(1)   Str("Taehoon".to_owned());
+        I64(102)   I64(4)     I64(2)      I64(2)   Str("Mike".to_owned());
+        I64(103)   I64(9)     I64(4)      I64(4)   Str("Berry".to_owned())
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT Player.name, Item.quantity
+        FROM Item
+        JOIN Player ON Player.id = Item.player_id AND Item.quantity > 1
+    ";
+    let expected = select!(
+        name                | quantity
+        Str                 | I64;
+        "Mike".to_owned()     4;
+        "Berry".to_owned()    9
+    );
+    g.test(sql, Ok(expected)).await;
+
+    g.run("INSERT INTO Item (id, quantity, player_id) VALUES (104, 3, 1);")
+        .await;
+
+    let sql = "
+        SELECT p.name, i.id
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        WHERE p.id IN (1, 3)
+    ";
+    let expected = select_with_null!(
+        name                        | id;
+        Str("Taehoon".to_owned())     I64(101);
+        Str("Taehoon".to_owned())     I64(104);
+        Str("Jorno".to_owned())       Null
+    );
+    g.test(sql, Ok(expected)).await;
+
+    g.run("INSERT INTO Item (id, quantity, player_id) VALUES (105, 7, 9);")
+        .await;
+
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p
+        RIGHT JOIN Item i ON p.id = i.player_id
+    ";
+    let expected = select_with_null!(
+        id     | id;
+        I64(1)   I64(101);
+        I64(1)   I64(104);
+        I64(2)   I64(102);
+        I64(4)   I64(103);
+        Null     I64(105)
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT *
+        FROM Player p
+        RIGHT OUTER JOIN Item i ON p.id = i.player_id
+        WHERE p.id IS NULL
+    ";
+    let expected = select_with_null!(
+        id   | name | id       | quantity | player_id;
+        Null   Null   I64(105)   I64(7)     I64(9)
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.name, i.id
+        FROM Player p
+        FULL OUTER JOIN Item i ON p.id = i.player_id
+    ";
+    let expected = select_with_null!(
+        name                       | id;
+        Str("Taehoon".to_owned())    I64(101);
+        Str("Taehoon".to_owned())    I64(104);
+        Str("Mike".to_owned())       I64(102);
+        Str("Jorno".to_owned())      Null;
+        Str("Berry".to_owned())      I64(103);
+        Str("Hwan".to_owned())       Null;
+        Null                         I64(105)
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p
+        FULL JOIN Item i ON p.id = i.player_id
+        WHERE p.id IS NULL OR i.id IS NULL
+    ";
+    let expected = select_with_null!(
+        id     | id;
+        I64(3)   Null;
+        I64(5)   Null;
+        Null     I64(105)
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id, i.id, q.id
+        FROM Player p
+        LEFT JOIN Item i ON p.id = i.player_id
+        FULL JOIN Player q ON q.id = i.player_id + 1
+        WHERE p.id IS NULL OR q.id IS NULL
+    ";
+    let expected = select_with_null!(
+        id     | id   | id;
+        I64(3)   Null   Null;
+        I64(5)   Null   Null;
+        Null     Null   I64(1);
+        Null     Null   I64(4)
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p, Item i
+        WHERE p.id = i.player_id
+    ";
+    let expected = select!(
+        id  | id
+        I64 | I64;
+        1     101;
+        1     104;
+        2     102;
+        4     103
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p
+        CROSS JOIN Item i
+        WHERE p.id = 5 AND i.quantity > 5
+    ";
+    let expected = select!(
+        id  | id
+        I64 | I64;
+        5     103;
+        5     105
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.name, i.id, q.name
+        FROM Player p, Item i
+        JOIN Player q ON q.id = i.player_id
+        WHERE p.id = 3 AND i.id < 103
+    ";
+    let expected = select!(
+        name                | id  | name
+        Str                 | I64 | Str;
+        "Jorno".to_owned()    101   "Taehoon".to_owned();
+        "Jorno".to_owned()    102   "Mike".to_owned()
+    );
+    g.test(sql, Ok(expected)).await;
+
+    g.count("SELECT * FROM Player CROSS JOIN Item", 25).await;
+    g.count("SELECT * FROM Player, Item, Player AS q", 125)
+        .await;
+
+    g.run("CREATE TABLE GroupA (id INTEGER);").await;
+    g.run("CREATE TABLE GroupB (id INTEGER);").await;
+    g.run("CREATE TABLE GroupC (id INTEGER);").await;
+    g.run("INSERT INTO GroupA VALUES (1), (2);").await;
+    g.run("INSERT INTO GroupB VALUES (1);").await;
+    g.run("INSERT INTO GroupC VALUES (1), (3);").await;
+    g.named_test(
+        "joins of the first table in FROM are joined before the comma separated tables",
+        "SELECT GroupA.id, GroupB.id, GroupC.id FROM GroupB RIGHT JOIN GroupC ON GroupB.id = GroupC.id, GroupA",
+        Ok(select_with_null!(
+            id     | id     | id;
+            I64(1)   I64(1)   I64(1);
+            I64(2)   I64(1)   I64(1);
+            I64(1)   Null     I64(3);
+            I64(2)   Null     I64(3)
+        )),
+    )
+    .await;
+    g.named_test(
+        "a LEFT JOIN after a comma joins the table listed before it",
+        "SELECT GroupA.id, GroupB.id, GroupC.id FROM GroupA, GroupB LEFT JOIN GroupC ON GroupB.id = GroupC.id",
+        Ok(select!(
+            id  | id  | id
+            I64 | I64 | I64;
+            1     1     1;
+            2     1     1
+        )),
+    )
+    .await;
+    g.named_test(
+        "CROSS JOIN followed by RIGHT JOIN joins from left to right",
+        "SELECT GroupA.id, GroupB.id, GroupC.id FROM GroupA CROSS JOIN GroupB RIGHT JOIN GroupC ON GroupB.id = GroupC.id",
+        Ok(select_with_null!(
+            id     | id     | id;
+            I64(1)   I64(1)   I64(1);
+            I64(2)   I64(1)   I64(1);
+            Null     Null     I64(3)
+        )),
+    )
+    .await;
+    g.named_test(
+        "a RIGHT JOIN after a comma would be grouped with the comma separated table only",
+        "SELECT * FROM GroupA, GroupB RIGHT JOIN GroupC ON GroupB.id = GroupC.id",
+        Err(TranslateError::OuterJoinAfterCommaNotSupported(
+            "GroupB RIGHT JOIN GroupC ON GroupB.id = GroupC.id".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+
     // To test `PlanError` while using `JOIN`
     g.run("CREATE TABLE Users (id INTEGER, name TEXT);").await;
     g.run("INSERT INTO Users (id, name) VALUES (1, 'Harry');")
@@ -327,15 +603,54 @@ test_case!(project, {
     g.run("INSERT INTO Testers (id, nickname) VALUES (1, 'Ron');")
         .await;
 
+    g.named_test(
+        "self join resolves columns by table alias",
+        "SELECT A.name, B.name FROM Users A JOIN Users B ON A.id = B.id",
+        Ok(select!(
+            name              | name
+            Str               | Str;
+            "Harry".to_owned()  "Harry".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "unqualified columns found in only one relation are not ambiguous",
+        "SELECT name, nickname FROM Users JOIN Testers ON Users.id = Testers.id WHERE nickname = 'Ron'",
+        Ok(select!(
+            name              | nickname
+            Str               | Str;
+            "Harry".to_owned()  "Ron".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "JOIN ON only sees the relations joined so far",
+        "SELECT U.id FROM Users U JOIN Testers T ON name = 'Harry' JOIN Users V ON V.id = U.id",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "ORDER BY may refer to a projection label",
+        "SELECT A.id AS id FROM Users A JOIN Users B ON A.id = B.id ORDER BY id",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "columns of a derived table are its projection labels",
+        "SELECT name FROM (SELECT A.name FROM Users A JOIN Users B ON A.id = B.id) AS Sub",
+        Ok(select!(name Str; "Harry".to_owned())),
+    )
+    .await;
+
     let error_cases = [
         (
             "SELECT * FROM TableA JOIN TableA USING (id);",
             TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into(),
         ),
-        (
-            "SELECT * FROM TableA CROSS JOIN TableA as A;",
-            TranslateError::UnsupportedJoinOperator("CrossJoin".to_owned()).into(),
-        ),
         (
             "SELECT id FROM Users JOIN Testers ON Users.id = Testers.id;",
             PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
@@ -354,8 +669,24 @@ test_case!(project, {
             PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
         ),
         (
-            "SELECT * FROM ProjectUser, ProjectItem",
-            TranslateError::TooManyTables.into(),
+            "SELECT Users.name FROM Users JOIN Testers ON id = Testers.id",
+            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+        ),
+        (
+            "SELECT nickname FROM Users JOIN Testers ON Users.id = Testers.id WHERE id > 0",
+            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+        ),
+        (
+            "SELECT A.id + 1 AS next FROM Users A JOIN Users B ON A.id = B.id ORDER BY name",
+            PlanError::ColumnReferenceAmbiguous("name".to_owned()).into(),
+        ),
+        (
+            "SELECT COUNT(*) FROM Users A JOIN Users B ON A.id = B.id GROUP BY name",
+            PlanError::ColumnReferenceAmbiguous("name".to_owned()).into(),
+        ),
+        (
+            "SELECT UPPER(name) FROM Users A JOIN Users B ON A.id = B.id",
+            PlanError::ColumnReferenceAmbiguous("name".to_owned()).into(),
         ),
     ];
 
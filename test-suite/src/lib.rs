@@ -37,12 +37,15 @@ pub mod order_by;
 pub mod ordering;
 pub mod primary_key;
 pub mod project;
+pub mod returning;
 pub mod schemaless;
 pub mod series;
+pub mod set_operation;
 pub mod show_columns;
 pub mod store;
 pub mod synthesize;
 pub mod transaction;
+pub mod truncate;
 pub mod type_match;
 pub mod unary_operator;
 pub mod update;
@@ -75,9 +78,18 @@ macro_rules! generate_store_tests {
             };
         }
         glue!(update, update::update);
+        glue!(update_expression, update::update_expression);
         glue!(insert, insert::insert);
+        glue!(insert_type_check, insert::insert_type_check);
+        glue!(insert_on_conflict, insert::insert_on_conflict);
         glue!(delete, delete::delete);
+        glue!(delete_with_subquery, delete::delete_with_subquery);
+        glue!(truncate, truncate::truncate);
+        glue!(returning, returning::returning);
         glue!(basic, basic::basic);
+        glue!(set_operation_union, set_operation::union);
+        glue!(set_operation_intersect, set_operation::intersect);
+        glue!(set_operation_except, set_operation::except);
         glue!(array, array::array);
         glue!(bitwise_and, bitwise_and::bitwise_and);
         glue!(aggregate_avg, aggregate::avg::avg);
@@ -121,6 +133,7 @@ macro_rules! generate_store_tests {
         glue!(function_ltrim_rtrim, function::ltrim_rtrim::ltrim_rtrim);
         glue!(function_cast_literal, function::cast::cast_literal);
         glue!(function_cast_value, function::cast::cast_value);
+        glue!(function_cast_column, function::cast::cast_column);
         glue!(function_coalesce, function::coalesce::coalesce);
         glue!(function_concat, function::concat::concat);
         glue!(function_concat_ws, function::concat_ws::concat_ws);
@@ -179,14 +192,17 @@ macro_rules! generate_store_tests {
         glue!(migrate, migrate::migrate);
         glue!(nested_select, nested_select::nested_select);
         glue!(primary_key, primary_key::primary_key);
+        glue!(primary_key_composite, primary_key::primary_key_composite);
         glue!(foreign_key, foreign_key::foreign_key);
         glue!(series, series::series);
         glue!(nullable, nullable::nullable);
         glue!(nullable_text, nullable::nullable_text);
         glue!(nullable_implicit_insert, nullable::nullable_implicit_insert);
+        glue!(nullable_round_trip, nullable::nullable_round_trip);
         glue!(ordering, ordering::ordering);
         glue!(order_by, order_by::order_by);
         glue!(sql_types, data_type::sql_types::sql_types);
+        glue!(bool, data_type::bool::bool);
         glue!(show_columns, show_columns::show_columns);
         glue!(distinct, distinct::distinct);
         glue!(int8, data_type::int8::int8);
@@ -340,6 +356,7 @@ macro_rules! generate_store_tests {
 
         glue!(expr_between, expr::between::between);
         glue!(expr_in_list, expr::in_list::in_list);
+        glue!(expr_logical, expr::logical::logical);
     };
 }
 
@@ -354,6 +371,7 @@ macro_rules! generate_alter_table_tests {
 
         glue!(alter_table_rename, alter::alter_table_rename);
         glue!(alter_table_add_drop, alter::alter_table_add_drop);
+        glue!(alter_table_drop_column, alter::alter_table_drop_column);
     };
 }
 
@@ -422,6 +440,10 @@ macro_rules! generate_alter_table_index_tests {
 
         glue!(alter_table_drop_indexed_table, alter::drop_indexed_table);
         glue!(alter_table_drop_indexed_column, alter::drop_indexed_column);
+        glue!(
+            alter_table_rename_indexed_column,
+            alter::rename_indexed_column
+        );
     };
 }
 
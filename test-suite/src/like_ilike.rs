@@ -3,8 +3,8 @@ use {
     bigdecimal::BigDecimal,
     gluesql_core::{
         data::Literal,
-        error::{LiteralError, ValueError},
-        prelude::Value::{self, Bool},
+        error::{LiteralError, StringExtError, TranslateError, ValueError},
+        prelude::Value::{self, Bool, Null},
     },
     std::{borrow::Cow, str::FromStr},
 };
@@ -26,6 +26,101 @@ test_case!(like_ilike, {
     )
     .await;
 
+    g.named_test(
+        "characters other than '%' and '_' match literally",
+        "
+            VALUES
+                ('a.c' LIKE 'a.c'),
+                ('abc' LIKE 'a.c'),
+                ('a*' LIKE 'a*'),
+                ('aaa' LIKE 'a*'),
+                ('(a)' LIKE '(_)');
+        ",
+        Ok(select!(column1 Bool; true; false; true; false; true)),
+    )
+    .await;
+
+    g.named_test(
+        "ILIKE folds the case of non-ASCII characters",
+        "
+            VALUES
+                ('ÄRGER' ILIKE 'ärger'),
+                ('ΟΔΟΣ' ILIKE 'οδος'),
+                ('Straße' ILIKE 'STRA_E'),
+                ('Éclair' NOT ILIKE 'é%'),
+                ('Éclair' ILIKE 'e%');
+        ",
+        Ok(select!(column1 Bool; true; true; true; false; false)),
+    )
+    .await;
+
+    g.named_test(
+        "backslash escapes wildcards and itself",
+        r"
+            VALUES
+                ('100%' LIKE '100\%'),
+                ('1000' LIKE '100\%'),
+                ('a_c' LIKE 'a\_c'),
+                ('abc' LIKE 'a\_c'),
+                ('a\c' LIKE 'a\\c'),
+                ('A_C' ILIKE 'a\_c');
+        ",
+        Ok(select!(column1 Bool; true; false; true; false; true; true)),
+    )
+    .await;
+
+    g.named_test(
+        "pattern ending with the escape character",
+        r"SELECT 'abc' LIKE 'abc\' AS res",
+        Err(StringExtError::PatternEndsWithEscape(r"abc\".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "ESCAPE replaces the backslash",
+        r"
+            VALUES
+                ('100%' LIKE '100\%' ESCAPE '\'),
+                ('100%' LIKE '100#%' ESCAPE '#'),
+                ('1000' LIKE '100#%' ESCAPE '#'),
+                ('a_c' LIKE 'a#_c' ESCAPE '#'),
+                ('a#c' LIKE 'a##c' ESCAPE '#'),
+                ('a\c' LIKE 'a\c' ESCAPE '#'),
+                ('A_C' ILIKE 'a#_c' ESCAPE '#'),
+                ('a_c' NOT LIKE 'a#_c' ESCAPE '#');
+        ",
+        Ok(select!(column1 Bool; true; true; false; true; true; true; true; false)),
+    )
+    .await;
+
+    g.named_test(
+        "pattern ending with the ESCAPE character",
+        "SELECT '100' LIKE '100#' ESCAPE '#' AS res",
+        Err(StringExtError::PatternEndsWithEscape("100#".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "ESCAPE with more than one character",
+        "SELECT 'abc' LIKE 'a%' ESCAPE '##' AS res",
+        Err(TranslateError::InvalidLikeEscapeChar("##".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "empty ESCAPE",
+        "SELECT 'abc' ILIKE 'a%' ESCAPE '' AS res",
+        Err(TranslateError::InvalidLikeEscapeChar(String::new()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "NULL on either side yields NULL",
+        "SELECT NULL LIKE 'a%' AS a, 'abc' LIKE NULL AS b, NULL NOT LIKE 'a%' AS c, 'abc' NOT ILIKE NULL AS d",
+        Ok(select_with_null!(a | b | c | d; Null Null Null Null)),
+    )
+    .await;
+
     g.run(
         "
         CREATE TABLE Item (
@@ -69,15 +164,23 @@ test_case!(like_ilike, {
             "SELECT name FROM Item WHERE SUBSTR(name, 1) LIKE '%' || LOWER('A')",
         ),
         (5, "SELECT name FROM Item WHERE name LIKE '%%'"),
+        (0, "SELECT name FROM Item WHERE name LIKE '%#%%' ESCAPE '#'"),
+        (2, "SELECT name FROM Item WHERE name LIKE '%!a' ESCAPE '!'"),
         (0, "SELECT name FROM Item WHERE name LIKE 'g%'"),
         (2, "SELECT name FROM Item WHERE name ILIKE '_A%'"),
         (2, "SELECT name FROM Item WHERE name ILIKE 'g%'"),
+        (1, "SELECT name FROM Item WHERE name ILIKE 'AMELIA'"),
+        (3, "SELECT name FROM Item WHERE name NOT ILIKE 'G%'"),
         (5, "SELECT name FROM Item WHERE name ILIKE '%%'"),
         (1, "SELECT name FROM Item WHERE name NOT LIKE '%a%'"),
         (1, "SELECT name FROM Item WHERE name NOT ILIKE '%A%'"),
         (5, "SELECT name FROM Item WHERE 'ABC' LIKE '_B_'"),
         (5, "SELECT name FROM Item WHERE 'abc' ILIKE '_B_'"),
         (5, "SELECT name FROM Item WHERE 'ABC' ILIKE '_B_'"),
+        (0, "SELECT name FROM Item WHERE name LIKE NULL"),
+        (0, "SELECT name FROM Item WHERE name NOT LIKE NULL"),
+        (1, "SELECT name FROM Item WHERE name LIKE 'M_r_a'"),
+        (0, "SELECT name FROM Item WHERE name LIKE 'M.r.a'"),
     ];
 
     for (num, sql) in test_cases {
@@ -1,4 +1,13 @@
-use {crate::*, Value::*, gluesql_core::prelude::*};
+use {
+    crate::*,
+    Value::*,
+    gluesql_core::{
+        ast::{DataType, Expr},
+        data::ConvertError,
+        error::EvaluateError,
+        prelude::*,
+    },
+};
 
 test_case!(limit, {
     let g = get_tester!();
@@ -112,4 +121,47 @@ test_case!(limit, {
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
+
+    g.named_test(
+        "LIMIT stops fetching rows once satisfied",
+        "SELECT id / (id - 3) AS v FROM Test LIMIT 2;",
+        Ok(select!(v; I64; 0; -2)),
+    )
+    .await;
+
+    let usize_type = if cfg!(target_pointer_width = "64") {
+        DataType::Uint64
+    } else {
+        DataType::Uint32
+    };
+
+    g.named_test(
+        "negative LIMIT is not allowed",
+        "SELECT * FROM Test LIMIT -1;",
+        Err(ConvertError {
+            value: I64(-1),
+            data_type: usize_type.clone(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "negative OFFSET is not allowed",
+        "SELECT * FROM Test OFFSET -3;",
+        Err(ConvertError {
+            value: I64(-3),
+            data_type: usize_type,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "LIMIT cannot reference columns",
+        "SELECT * FROM Test LIMIT id;",
+        Err(
+            EvaluateError::ContextRequiredForIdentEvaluation(Expr::Identifier("id".to_owned()))
+                .into(),
+        ),
+    )
+    .await;
 });
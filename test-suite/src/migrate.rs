@@ -1,8 +1,8 @@
 use {
     crate::*,
     gluesql_core::{
-        ast::Expr,
-        error::{EvaluateError, FetchError, TranslateError, ValueError},
+        ast::{DataType, Expr},
+        error::{EvaluateError, FetchError, TranslateError, ValidateError},
         prelude::Value::*,
     },
 };
@@ -33,7 +33,13 @@ test_case!(migrate, {
     let error_cases = [
         (
             "INSERT INTO Test (id, num, name) VALUES (1.1, 1, 'good');",
-            ValueError::FailedToParseNumber.into(),
+            ValidateError::TypeMismatch {
+                table_name: "Test".to_owned(),
+                column_name: "id".to_owned(),
+                expected: DataType::Int,
+                found: Decimal("1.1".parse().unwrap()),
+            }
+            .into(),
         ),
         (
             "INSERT INTO Test (id, num, name) VALUES (1, 1, a.b);",
@@ -56,9 +62,9 @@ test_case!(migrate, {
             TranslateError::UnsupportedBinaryOperator("^".to_owned()).into(),
         ),
         (
-            "SELECT * FROM Test UNION SELECT * FROM Test;",
+            "SELECT * FROM Test UNION BY NAME SELECT * FROM Test;",
             TranslateError::UnsupportedQuerySetExpr(
-                "SELECT * FROM Test UNION SELECT * FROM Test".to_owned(),
+                "SELECT * FROM Test UNION BY NAME SELECT * FROM Test".to_owned(),
             )
             .into(),
         ),
@@ -71,8 +77,8 @@ test_case!(migrate, {
             FetchError::TableNotFound("Nothing".to_owned()).into(),
         ),
         (
-            "TRUNCATE TABLE ProjectUser;",
-            TranslateError::UnsupportedStatement("TRUNCATE TABLE ProjectUser".to_owned()).into(),
+            "RELEASE SAVEPOINT sp;",
+            TranslateError::UnsupportedStatement("RELEASE SAVEPOINT sp".to_owned()).into(),
         ),
         (
             "SELECT DISTINCT ON (id) id, num, name FROM Test;",
@@ -1,6 +1,11 @@
 use {
     crate::*,
-    gluesql_core::{executor::Payload, prelude::Value},
+    gluesql_core::{
+        ast::BinaryOperator,
+        error::EvaluateError,
+        executor::Payload,
+        prelude::Value::{self, *},
+    },
 };
 
 test_case!(nested_select, {
@@ -106,6 +111,113 @@ test_case!(nested_select, {
                 Value::Null
             )),
         ),
+        (
+            "SELECT id FROM Request WHERE quantity = (SELECT MAX(quantity) FROM Request)",
+            Ok(select!(id I64; 103)),
+        ),
+        (
+            "SELECT name FROM Player WHERE id = (SELECT user_id FROM Request WHERE quantity = 9)",
+            Ok(select!(name Str; "Jorno".to_owned())),
+        ),
+        (
+            "SELECT name, (SELECT MAX(quantity) FROM Request WHERE user_id = Player.id) AS top FROM Player",
+            Ok(select_with_null!(
+                name                       | top;
+                Str("Taehoon".to_owned())    I64(8);
+                Str("Mike".to_owned())       I64(4);
+                Str("Jorno".to_owned())      I64(9);
+                Str("Berry".to_owned())      Value::Null;
+                Str("Hwan".to_owned())       I64(1)
+            )),
+        ),
+        (
+            "SELECT * FROM Player WHERE id = (SELECT user_id FROM Request)",
+            Err(EvaluateError::MoreThanOneRowReturned.into()),
+        ),
+        (
+            "SELECT * FROM Player WHERE id = (SELECT id, user_id FROM Request WHERE id = 101)",
+            Err(EvaluateError::MoreThanOneColumnReturned.into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.run("INSERT INTO Request (id, quantity, user_id) VALUES (116, 1, NULL);")
+        .await;
+
+    let test_cases = [
+        (
+            "SELECT name FROM Player WHERE id IN (SELECT user_id FROM Request WHERE quantity = 1)",
+            Ok(select!(
+                name
+                Str;
+                "Taehoon".to_owned();
+                "Jorno".to_owned();
+                "Hwan".to_owned()
+            )),
+        ),
+        (
+            // no match against a set containing NULL is NULL, not FALSE
+            "SELECT id, id IN (SELECT user_id FROM Request WHERE quantity = 1) AS found FROM Player",
+            Ok(select_with_null!(
+                id     | found;
+                I64(1)   Bool(true);
+                I64(2)   Null;
+                I64(3)   Bool(true);
+                I64(4)   Null;
+                I64(5)   Bool(true)
+            )),
+        ),
+        (
+            "SELECT * FROM Player WHERE id NOT IN (SELECT user_id FROM Request)",
+            Ok(Payload::Select {
+                labels: vec!["id".to_owned(), "name".to_owned()],
+                rows: vec![],
+            }),
+        ),
+        (
+            "SELECT name FROM Player WHERE id NOT IN (SELECT user_id FROM Request WHERE user_id IS NOT NULL)",
+            Ok(select!(name Str; "Berry".to_owned())),
+        ),
+        (
+            "SELECT NULL IN (SELECT user_id FROM Request WHERE id = 101) AS v",
+            Ok(select_with_null!(v; Null)),
+        ),
+        (
+            // membership in an empty set is FALSE even for NULL
+            "SELECT NULL IN (SELECT user_id FROM Request WHERE id > 200) AS v",
+            Ok(select!(v Value::Bool; false)),
+        ),
+        (
+            "SELECT name FROM Player WHERE 5 IN (SELECT quantity FROM Request WHERE user_id = Player.id)",
+            Ok(select!(name Str; "Taehoon".to_owned())),
+        ),
+        (
+            "SELECT name FROM Player WHERE 5 NOT IN (SELECT quantity FROM Request WHERE user_id = Player.id)",
+            Ok(select!(
+                name
+                Str;
+                "Mike".to_owned();
+                "Jorno".to_owned();
+                "Berry".to_owned();
+                "Hwan".to_owned()
+            )),
+        ),
+        (
+            "SELECT 1 IN (SELECT 'one' FROM Request) AS v",
+            Err(EvaluateError::NumberComparedWithString {
+                left: "I64(1)".to_owned(),
+                op: BinaryOperator::Eq,
+                right: r#"Str("one")"#.to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "SELECT * FROM Player WHERE id IN (SELECT id, user_id FROM Request)",
+            Err(EvaluateError::MoreThanOneColumnReturned.into()),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -1,6 +1,9 @@
 use {
     crate::*,
-    gluesql_core::{error::ValueError, prelude::Value::*},
+    gluesql_core::{
+        error::{InsertError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
 };
 
 test_case!(nullable, {
@@ -221,6 +224,28 @@ test_case!(nullable, {
                 Null       Null       Null       Null       Null       Null       Null       Null
             ),
         ),
+        // comparing with NULL is NULL, so it never filters like IS NULL
+        (
+            "SELECT id, num FROM Test WHERE id = NULL",
+            select!(id | num),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id <> NULL",
+            select!(id | num),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE NULL = NULL",
+            select!(id | num),
+        ),
+        (
+            "SELECT id = NULL AS eq, id IS NULL AS is_null, id IS NOT NULL AS is_not_null FROM Test",
+            select_with_null!(
+                eq   | is_null     | is_not_null;
+                Null   Bool(true)    Bool(false);
+                Null   Bool(false)   Bool(true);
+                Null   Bool(false)   Bool(true)
+            ),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -243,13 +268,87 @@ test_case!(nullable, {
         ),
         (
             "INSERT INTO Test VALUES (1, NULL, 'ok')",
-            Err(ValueError::NullValueOnNotNullField.into()),
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "Test".to_owned(),
+                column_name: "num".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "UPDATE Test SET num = NULL WHERE id = 2",
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "Test".to_owned(),
+                column_name: "num".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "UPDATE Test SET num = CASE WHEN num > 5 THEN num END",
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "Test".to_owned(),
+                column_name: "num".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "INSERT INTO Test (id, name) VALUES (5, 'omitted')",
+            Err(InsertError::LackOfRequiredColumn("num".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Test (id, name) SELECT id, name FROM Test",
+            Err(InsertError::LackOfRequiredColumn("num".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Test SELECT id, NULL, name FROM Test",
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "Test".to_owned(),
+                column_name: "num".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            // failed statements leave the rows untouched
+            "SELECT id, num FROM Test",
+            Ok(select!(
+                id  | num
+                I64 | I64;
+                2     2;
+                2     9;
+                2     4
+            )),
+        ),
+        ("UPDATE Test SET name = NULL", Ok(Payload::Update(3))),
+        (
+            "SELECT num FROM Test WHERE name IS NULL",
+            Ok(select!(num I64; 2; 9; 4)),
         ),
     ];
 
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    g.run("CREATE TABLE Defaulted (id INTEGER NOT NULL, num INTEGER NOT NULL DEFAULT 7)")
+        .await;
+    g.run("INSERT INTO Defaulted (id) VALUES (1)").await;
+    g.test(
+        "SELECT id, num FROM Defaulted",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            1     7
+        )),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Defaulted VALUES (2, NULL)",
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Defaulted".to_owned(),
+            column_name: "num".to_owned(),
+        }
+        .into()),
+    )
+    .await;
 });
 
 test_case!(nullable_text, {
@@ -267,6 +366,22 @@ test_case!(nullable_text, {
 
     g.run("INSERT INTO Foo (id, name) VALUES (1, 'Hello'), (2, Null);")
         .await;
+
+    g.test(
+        "SELECT id FROM Foo WHERE name IS NULL",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM Foo WHERE name IS NOT NULL",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM Foo WHERE name = NULL OR name <> NULL",
+        Ok(select!(id)),
+    )
+    .await;
 });
 
 test_case!(nullable_implicit_insert, {
@@ -291,4 +406,98 @@ test_case!(nullable_implicit_insert, {
         )),
     )
     .await;
+
+    g.run("INSERT INTO Foo VALUES (2, 'Hello'), (3, NULL)")
+        .await;
+    g.test(
+        "SELECT id FROM Foo WHERE name IS NULL",
+        Ok(select!(id I64; 1; 3)),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM Foo WHERE name IS NOT NULL",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.test("SELECT id FROM Foo WHERE name = NULL", Ok(select!(id)))
+        .await;
+});
+
+test_case!(nullable_round_trip, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Mixed (
+            id INTEGER NOT NULL,
+            num FLOAT,
+            flag BOOLEAN,
+            day DATE,
+            memo TEXT
+        )
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "NULL literals mixed with values in every column but the key",
+        "
+        INSERT INTO Mixed VALUES
+            (1, 1.5,  NULL,  '2024-01-01', NULL),
+            (2, NULL, TRUE,  NULL,         'b'),
+            (3, NULL, NULL,  NULL,         NULL);
+        ",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+
+    let day = |v: &str| Date(v.parse().unwrap());
+    g.test(
+        "SELECT * FROM Mixed",
+        Ok(select_with_null!(
+            id     | num      | flag       | day                | memo;
+            I64(1)   F64(1.5)   Null         day("2024-01-01")    Null;
+            I64(2)   Null       Bool(true)   Null                 Str("b".to_owned());
+            I64(3)   Null       Null         Null                 Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE assigns NULL and replaces NULL with values",
+        "UPDATE Mixed SET num = NULL, flag = FALSE, memo = NULL WHERE id < 3",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+
+    g.test(
+        "SELECT * FROM Mixed",
+        Ok(select_with_null!(
+            id     | num    | flag         | day                | memo;
+            I64(1)   Null     Bool(false)    day("2024-01-01")    Null;
+            I64(2)   Null     Bool(false)    Null                 Null;
+            I64(3)   Null     Null           Null                 Null
+        )),
+    )
+    .await;
+
+    g.test(
+        "INSERT INTO Mixed VALUES (NULL, 1, TRUE, NULL, 'x')",
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Mixed".to_owned(),
+            column_name: "id".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "UPDATE Mixed SET id = NULL WHERE id = 3",
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Mixed".to_owned(),
+            column_name: "id".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.count("SELECT * FROM Mixed WHERE id IS NOT NULL", 3).await;
 });
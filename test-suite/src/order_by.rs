@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{SortError, TranslateError},
+        error::{EvaluateError, SortError},
         prelude::Value::*,
     },
 };
@@ -162,9 +162,119 @@ CREATE TABLE Test (
     )
     .await;
 
-    g.test(
-        "SELECT * FROM Test ORDER BY id NULLS FIRST",
-        Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into()),
+    g.named_test(
+        "ORDER BY column which is not in the projection",
+        "SELECT name FROM Test ORDER BY num DESC",
+        Ok(select_with_null!(
+            name;
+            Null;
+            s!("Thursday");
+            s!("World");
+            s!("Hello")
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY keeps the insertion order of rows with equal keys",
+        "SELECT id, num FROM Test ORDER BY id DESC",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            4     7;
+            3     4;
+            1     2;
+            1     9
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY column which does not exist",
+        "SELECT id, num FROM Test ORDER BY unknown",
+        Err(EvaluateError::IdentifierNotFound("unknown".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY mixed NULL and non-NULL values puts NULL last",
+        "SELECT id, name FROM Test ORDER BY CASE WHEN name IS NULL THEN NULL ELSE id END",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   s!("Hello");
+            I64(3)   s!("World");
+            I64(4)   s!("Thursday");
+            I64(1)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ORDER BY NULLS FIRST puts NULL before the smallest value",
+        "SELECT id, num, rate FROM Test ORDER BY rate NULLS FIRST, id",
+        Ok(select_with_null!(
+            id     | num    | rate;
+            I64(1)   I64(9)   Null;
+            I64(4)   I64(7)   Null;
+            I64(3)   I64(4)   F64(1.0);
+            I64(1)   I64(2)   F64(3.0)
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY ASC NULLS LAST is the same as the default",
+        "SELECT id, num, rate FROM Test ORDER BY rate ASC NULLS LAST, id DESC",
+        Ok(select_with_null!(
+            id     | num    | rate;
+            I64(3)   I64(4)   F64(1.0);
+            I64(1)   I64(2)   F64(3.0);
+            I64(4)   I64(7)   Null;
+            I64(1)   I64(9)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY DESC puts NULL first by default",
+        "SELECT id, num, rate FROM Test ORDER BY rate DESC, id",
+        Ok(select_with_null!(
+            id     | num    | rate;
+            I64(1)   I64(9)   Null;
+            I64(4)   I64(7)   Null;
+            I64(1)   I64(2)   F64(3.0);
+            I64(3)   I64(4)   F64(1.0)
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY DESC NULLS LAST",
+        "SELECT id, num, rate FROM Test ORDER BY rate DESC NULLS LAST, id",
+        Ok(select_with_null!(
+            id     | num    | rate;
+            I64(1)   I64(2)   F64(3.0);
+            I64(3)   I64(4)   F64(1.0);
+            I64(1)   I64(9)   Null;
+            I64(4)   I64(7)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY multiple keys mixing directions and NULL placement",
+        "SELECT id, num, name FROM Test ORDER BY id DESC, name ASC NULLS FIRST",
+        Ok(select_with_null!(
+            id     | num    | name;
+            I64(4)   I64(7)   s!("Thursday");
+            I64(3)   I64(4)   s!("World");
+            I64(1)   I64(9)   Null;
+            I64(1)   I64(2)   s!("Hello")
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY DESC NULLS LAST on a derived table",
+        "SELECT * FROM (VALUES (1), (NULL), (2)) AS Sub ORDER BY column1 DESC NULLS LAST",
+        Ok(select_with_null!(
+            column1;
+            I64(2);
+            I64(1);
+            Null
+        )),
     )
     .await;
     g.named_test(
@@ -193,6 +303,32 @@ CREATE TABLE Test (
         )),
     )
     .await;
+    g.named_test(
+        "ORDER BY an alias of an expression",
+        "SELECT id, id * num AS total FROM Test ORDER BY total DESC",
+        Ok(select!(
+            id  | total
+            I64 | I64;
+            4     28;
+            3     12;
+            1     9;
+            1     2
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY an expression using an alias",
+        "SELECT id * num AS total FROM Test ORDER BY total % 5",
+        Ok(select!(
+            total
+            I64;
+            2;
+            12;
+            28;
+            9
+        )),
+    )
+    .await;
     g.named_test(
         "ORDER BY I64 and UnaryOperator::PLUS work as COLUMN_INDEX",
         "SELECT id, num FROM Test ORDER BY 1 ASC, +2 DESC",
@@ -219,6 +355,43 @@ CREATE TABLE Test (
         )),
     )
     .await;
+    g.named_test(
+        "ORDER BY COLUMN_INDEX refers to the columns expanded from a wildcard",
+        "SELECT * FROM Test ORDER BY 2 DESC",
+        Ok(select_with_null!(
+            id     | num    | name           | rate;
+            I64(1)   I64(9)   Null             Null;
+            I64(4)   I64(7)   s!("Thursday")   Null;
+            I64(3)   I64(4)   s!("World")      F64(1.0);
+            I64(1)   I64(2)   s!("Hello")      F64(3.0)
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY COLUMN_INDEX refers to an expression in the SELECT-list",
+        "SELECT id, id * num FROM Test ORDER BY 2 DESC",
+        Ok(select!(
+            id  | "id * num"
+            I64 | I64;
+            4     28;
+            3     12;
+            1     9;
+            1     2
+        )),
+    )
+    .await;
+    g.named_test(
+        "ORDER BY COLUMN_INDEX refers to an aggregate",
+        "SELECT id, COUNT(*) FROM Test GROUP BY id ORDER BY 2 DESC, 1 DESC",
+        Ok(select!(
+            id  | "COUNT(*)"
+            I64 | I64;
+            1     2;
+            4     1;
+            3     1
+        )),
+    )
+    .await;
     g.named_test(
         "ORDER BY COLUMN_INDEX should be larger than 0",
         "SELECT id, num FROM Test ORDER BY 0",
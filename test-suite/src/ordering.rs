@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::Bool};
+use {
+    crate::*,
+    gluesql_core::{ast::BinaryOperator, error::EvaluateError, prelude::Value::*},
+};
 
 test_case!(ordering, {
     let g = get_tester!();
@@ -63,6 +66,20 @@ test_case!(ordering, {
             "SELECT * FROM Operator WHERE (SELECT name FROM Operator LIMIT 1) < 'zz'",
         ),
         (5, "SELECT * FROM Operator WHERE NOT (1 != 1);"),
+        // integers and floats are compared by their numeric values
+        (1, "SELECT * FROM Operator WHERE id = 1.0;"),
+        (2, "SELECT * FROM Operator WHERE id < 2.5;"),
+        (3, "SELECT * FROM Operator WHERE CAST(id AS INT8) >= 2.5;"),
+        (
+            5,
+            "SELECT * FROM Operator WHERE CAST(id AS FLOAT) <= CAST(5 AS UINT8);",
+        ),
+        // strings are compared lexicographically by their bytes
+        (2, "SELECT * FROM Operator WHERE name >= 'M';"),
+        (3, "SELECT * FROM Operator WHERE name > 'Azzzz';"),
+        (1, "SELECT * FROM Operator WHERE name BETWEEN 'A' AND 'Az';"),
+        (0, "SELECT * FROM Operator WHERE name < 'Abstract';"),
+        (5, "SELECT * FROM Operator WHERE name < 'a';"),
     ];
 
     for (num, sql) in test_cases {
@@ -70,10 +87,123 @@ test_case!(ordering, {
     }
 
     // Literal comparison with BinaryOperator
-    g.test("select 1 < 'a' as test", Ok(select!(test Bool; false)))
-        .await;
-    g.test("select 1 >= 'a' as test", Ok(select!(test Bool; false)))
-        .await;
-    g.test("select 1 = 'a' as test", Ok(select!(test Bool; false)))
+    let number_with_string = |op| {
+        Err(EvaluateError::NumberComparedWithString {
+            left: "I64(1)".to_owned(),
+            op,
+            right: r#"Str("a")"#.to_owned(),
+        }
+        .into())
+    };
+    g.test(
+        "select 1 < 'a' as test",
+        number_with_string(BinaryOperator::Lt),
+    )
+    .await;
+    g.test(
+        "select 1 >= 'a' as test",
+        number_with_string(BinaryOperator::GtEq),
+    )
+    .await;
+    g.test(
+        "select 1 = 'a' as test",
+        number_with_string(BinaryOperator::Eq),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Operator WHERE id = '1'",
+        Err(EvaluateError::NumberComparedWithString {
+            left: "I64(1)".to_owned(),
+            op: BinaryOperator::Eq,
+            right: r#"Str("1")"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Operator WHERE name > 1.5",
+        Err(EvaluateError::NumberComparedWithString {
+            left: r#"Str("Abstract")"#.to_owned(),
+            op: BinaryOperator::Gt,
+            right: "F64(1.5)".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT 'B' < 'a' AS upper, 'z' < 'é' AS accent, 'ab' < 'abc' AS prefix",
+        Ok(select!(
+            upper | accent | prefix
+            Bool  | Bool   | Bool;
+            true    true     true
+        )),
+    )
+    .await;
+    g.test(
+        "SELECT name FROM Operator WHERE name <= 'July' ORDER BY name DESC",
+        Ok(select!(
+            name
+            Str;
+            "July".to_owned();
+            "Azzzz".to_owned();
+            "Abstract".to_owned()
+        )),
+    )
+    .await;
+    g.test(
+        "SELECT DATE '2020-01-01' > '2019-12-31' AS parsed",
+        Ok(select!(parsed Bool; true)),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Operator WHERE name = TRUE",
+        Err(EvaluateError::StringComparedWithNonString {
+            left: r#"Str("Abstract")"#.to_owned(),
+            op: BinaryOperator::Eq,
+            right: "Bool(true)".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT CAST('2020-01-01' AS TEXT) < DATE '2020-01-02' AS test",
+        Ok(select!(test Bool; true)),
+    )
+    .await;
+
+    g.run("CREATE TABLE DateText (id INTEGER, day DATE, text TEXT)")
         .await;
+    g.run(
+        "
+        INSERT INTO DateText VALUES
+            (1, '2020-01-01', '2020-01-01'),
+            (2, '2020-01-02', '2020-01-01'),
+            (3, '2020-01-03', 'tomorrow');
+    ",
+    )
+    .await;
+    g.named_test(
+        "a DATE column compared with a TEXT column parses the text",
+        "SELECT id FROM DateText WHERE day = text",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+    g.named_test(
+        "a TEXT column compared with a DATE column parses the text",
+        "SELECT id FROM DateText WHERE text < day",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "a TEXT column compared with a BOOLEAN still fails",
+        "SELECT id FROM DateText WHERE text = TRUE",
+        Err(EvaluateError::StringComparedWithNonString {
+            left: r#"Str("2020-01-01")"#.to_owned(),
+            op: BinaryOperator::Eq,
+            right: "Bool(true)".to_owned(),
+        }
+        .into()),
+    )
+    .await;
 });
@@ -2,7 +2,7 @@ use {
     crate::*,
     gluesql_core::{
         data::Value::*,
-        error::{UpdateError, ValidateError, ValueError},
+        error::{AlterError, FetchError, TranslateError, UpdateError, ValidateError},
         prelude::{Key, Payload},
     },
 };
@@ -142,7 +142,11 @@ test_case!(primary_key, {
     g.named_test(
         "PRIMARY KEY includes NOT NULL constraint",
         "INSERT INTO Allegro VALUES (NULL, 'hello');",
-        Err(ValueError::NullValueOnNotNullField.into()),
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Allegro".to_owned(),
+            column_name: "id".to_owned(),
+        }
+        .into()),
     )
     .await;
 
@@ -153,3 +157,143 @@ test_case!(primary_key, {
     )
     .await;
 });
+
+test_case!(primary_key_composite, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Enrollment (
+            student_id INTEGER,
+            course TEXT,
+            grade TEXT NULL,
+            PRIMARY KEY (student_id, course)
+        );
+        ",
+    )
+    .await;
+    g.test(
+        "INSERT INTO Enrollment VALUES (1, 'math', 'A'), (1, 'art', NULL), (2, 'math', 'B');",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+
+    g.named_test(
+        "duplicate tuple is rejected",
+        "INSERT INTO Enrollment VALUES (1, 'math', 'C');",
+        Err(ValidateError::DuplicateEntryOnCompositePrimaryKey(
+            vec![I64(1), Str("math".to_owned())],
+            vec!["student_id".to_owned(), "course".to_owned()],
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "duplicate tuple within a single INSERT",
+        "INSERT INTO Enrollment VALUES (3, 'math', NULL), (3, 'math', NULL);",
+        Err(ValidateError::DuplicateEntryOnCompositePrimaryKey(
+            vec![I64(3), Str("math".to_owned())],
+            vec!["student_id".to_owned(), "course".to_owned()],
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "columns of the key are NOT NULL",
+        "INSERT INTO Enrollment VALUES (NULL, 'music', NULL);",
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Enrollment".to_owned(),
+            column_name: "student_id".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "UPDATE into an existing tuple is rejected",
+        "UPDATE Enrollment SET course = 'art' WHERE student_id = 1 AND course = 'math'",
+        Err(ValidateError::DuplicateEntryOnCompositePrimaryKey(
+            vec![I64(1), Str("art".to_owned())],
+            vec!["student_id".to_owned(), "course".to_owned()],
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "each column alone may repeat",
+        "INSERT INTO Enrollment VALUES (2, 'art', NULL), (3, 'math', NULL);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "UPDATE of columns outside the key",
+        "UPDATE Enrollment SET grade = 'A'",
+        Ok(Payload::Update(5)),
+    )
+    .await;
+    g.named_test(
+        "UPDATE into a new tuple",
+        "UPDATE Enrollment SET student_id = 4 WHERE student_id = 3",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT student_id, course FROM Enrollment ORDER BY student_id, course",
+        Ok(select!(
+            student_id | course
+            I64        | Str;
+            1            "art".to_owned();
+            1            "math".to_owned();
+            2            "art".to_owned();
+            2            "math".to_owned();
+            4            "math".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "column of the key cannot be dropped",
+        "ALTER TABLE Enrollment DROP COLUMN course",
+        Err(AlterError::CannotAlterPrimaryKeyColumn {
+            table_name: "Enrollment".to_owned(),
+            column_name: "course".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "table constraint over a single column is the column's primary key",
+        "CREATE TABLE Single (id INTEGER, PRIMARY KEY (id));",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.run("INSERT INTO Single VALUES (1);").await;
+    g.test(
+        "INSERT INTO Single VALUES (1);",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(1)).into()),
+    )
+    .await;
+    g.named_test(
+        "unknown column in the key",
+        "CREATE TABLE Unknown (id INTEGER, PRIMARY KEY (id, name));",
+        Err(AlterError::PrimaryKeyColumnNotFound("name".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "primary key declared twice",
+        "CREATE TABLE Twice (id INTEGER PRIMARY KEY, name TEXT, PRIMARY KEY (id, name));",
+        Err(TranslateError::MultiplePrimaryKeysNotAllowed("Twice".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "primary key declared on two columns",
+        "CREATE TABLE TwoColumns (id INTEGER PRIMARY KEY, name TEXT PRIMARY KEY);",
+        Err(TranslateError::MultiplePrimaryKeysNotAllowed("TwoColumns".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "rejected table is not created",
+        "SELECT * FROM TwoColumns;",
+        Err(FetchError::TableNotFound("TwoColumns".to_owned()).into()),
+    )
+    .await;
+});
@@ -113,6 +113,33 @@ test_case!(project, {
                 3     "Jorno".to_owned()     105   3           1
             ),
         ),
+        (
+            "
+            SELECT ProjectItem.*, ProjectUser.name
+            FROM ProjectItem
+            JOIN ProjectUser ON ProjectUser.id = ProjectItem.player_id
+            WHERE ProjectItem.id < 103
+            ",
+            select!(
+                id  | player_id | quantity | name
+                I64 | I64       | I64      | Str;
+                101   1           1          "Taehoon".to_owned();
+                102   2           4          "Mike".to_owned()
+            ),
+        ),
+        (
+            "
+            SELECT i.*
+            FROM ProjectUser u
+            LEFT JOIN ProjectItem i ON u.id = i.player_id AND i.quantity > 5
+            ",
+            select_with_null!(
+                id        | player_id | quantity;
+                Null        Null        Null;
+                I64(103)    I64(2)      I64(9);
+                Null        Null        Null
+            ),
+        ),
         (
             "SELECT id as Ident, name FROM ProjectUser",
             select!(
@@ -133,6 +160,16 @@ test_case!(project, {
                 3     204     "Jorno".to_owned()
             ),
         ),
+        (
+            // unaliased expressions are labeled with their SQL and labels may repeat
+            "SELECT id * quantity, quantity AS q, id AS q FROM ProjectItem WHERE player_id = 2",
+            select!(
+                "id * quantity" | q   | q
+                I64             | I64 | I64;
+                408               4     102;
+                927               9     103
+            ),
+        ),
         (
             "
             SELECT id FROM ProjectUser
@@ -142,17 +179,94 @@ test_case!(project, {
             );",
             select!(id; I64; 2),
         ),
+        (
+            "
+            SELECT
+                name,
+                (SELECT COUNT(*) FROM ProjectItem WHERE player_id = ProjectUser.id) AS items
+            FROM ProjectUser
+            ",
+            select!(
+                name                 | items
+                Str                  | I64;
+                "Taehoon".to_owned()   1;
+                "Mike".to_owned()      2;
+                "Jorno".to_owned()     2
+            ),
+        ),
+        (
+            "SELECT id FROM ProjectItem WHERE quantity > (SELECT AVG(quantity) FROM ProjectItem)",
+            select!(id; I64; 102; 103),
+        ),
+        (
+            // scalar subquery returning no rows evaluates to NULL
+            "
+            SELECT id FROM ProjectUser
+            WHERE (
+                SELECT quantity FROM ProjectItem
+                WHERE player_id = ProjectUser.id AND quantity > 5
+            ) IS NULL
+            ",
+            select!(id; I64; 1; 3),
+        ),
     ];
 
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
 
+    g.named_test(
+        "columns qualified by the table name",
+        "SELECT ProjectUser.name FROM ProjectUser WHERE ProjectUser.id = 2",
+        Ok(select!(name Str; "Mike".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "columns qualified by the table alias",
+        "SELECT u.id, name FROM ProjectUser AS u WHERE u.id > 1 ORDER BY u.id DESC",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "Jorno".to_owned();
+            2     "Mike".to_owned()
+        )),
+    )
+    .await;
+
     let error_cases = [
+        (
+            "SELECT Whatever.id FROM ProjectUser",
+            EvaluateError::CompoundIdentifierNotFound {
+                table_alias: "Whatever".to_owned(),
+                column_name: "id".to_owned(),
+            }
+            .into(),
+        ),
+        (
+            "SELECT id FROM ProjectUser WHERE Whatever.id = 1",
+            EvaluateError::CompoundIdentifierNotFound {
+                table_alias: "Whatever".to_owned(),
+                column_name: "id".to_owned(),
+            }
+            .into(),
+        ),
+        (
+            "SELECT ProjectUser.id FROM ProjectUser u",
+            EvaluateError::CompoundIdentifierNotFound {
+                table_alias: "ProjectUser".to_owned(),
+                column_name: "id".to_owned(),
+            }
+            .into(),
+        ),
         (
             "SELECT Whatever.* FROM ProjectUser",
             FetchError::TableAliasNotFound("Whatever".to_owned()).into(),
         ),
+        (
+            // a table given an alias is only reachable through the alias
+            "SELECT ProjectUser.* FROM ProjectUser u",
+            FetchError::TableAliasNotFound("ProjectUser".to_owned()).into(),
+        ),
         (
             "SELECT noname FROM ProjectUser",
             EvaluateError::IdentifierNotFound("noname".to_owned()).into(),
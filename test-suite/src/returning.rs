@@ -0,0 +1,141 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, FetchError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(returning, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER, name TEXT, quantity INTEGER DEFAULT 5);")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 'apple', 1), (2, 'banana', 2);")
+        .await;
+
+    g.named_test(
+        "INSERT RETURNING * includes default values",
+        "INSERT INTO Item (id, name) VALUES (3, 'cherry'), (4, 'durian') RETURNING *",
+        Ok(select!(
+            id  | name                  | quantity
+            I64 | Str                   | I64;
+            3     "cherry".to_owned()     5;
+            4     "durian".to_owned()     5
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "INSERT RETURNING projects expressions with aliases",
+        "INSERT INTO Item VALUES (5, 'elderberry', 9) RETURNING id, quantity * 2 AS doubled",
+        Ok(select!(id | doubled; I64 | I64; 5 18)),
+    )
+    .await;
+
+    g.named_test(
+        "subqueries in RETURNING see the table before the statement",
+        "INSERT INTO Item VALUES (6, 'fig', 1) RETURNING (SELECT COUNT(*) FROM Item) AS total",
+        Ok(select!(total I64; 5)),
+    )
+    .await;
+
+    g.named_test(
+        "INSERT ... SELECT RETURNING",
+        "INSERT INTO Item SELECT id + 10, name, quantity FROM Item AS src WHERE id < 3 RETURNING id",
+        Ok(select!(id I64; 11; 12)),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE RETURNING returns rows after the update",
+        "UPDATE Item SET quantity = quantity + 10 WHERE id IN (1, 2) RETURNING id, quantity",
+        Ok(select!(
+            id  | quantity
+            I64 | I64;
+            1     11;
+            2     12
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DELETE RETURNING returns the deleted rows",
+        "DELETE FROM Item WHERE id > 10 RETURNING Item.name, id",
+        Ok(select!(
+            name                | id
+            Str                 | I64;
+            "apple".to_owned()    11;
+            "banana".to_owned()   12
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "RETURNING with no affected rows keeps the labels",
+        "DELETE FROM Item WHERE id = 100 RETURNING *",
+        Ok(select!(id | name | quantity)),
+    )
+    .await;
+
+    g.named_test(
+        "statements without RETURNING still report counts",
+        "DELETE FROM Item WHERE id IN (5, 6)",
+        Ok(Payload::Delete(2)),
+    )
+    .await;
+
+    g.named_test(
+        "RETURNING checks column names",
+        "UPDATE Item SET quantity = 0 RETURNING price",
+        Err(EvaluateError::IdentifierNotFound("price".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "RETURNING checks qualified wildcards",
+        "UPDATE Item SET quantity = 0 RETURNING Other.*",
+        Err(FetchError::TableAliasNotFound("Other".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "a failed RETURNING leaves the table untouched",
+        "SELECT id, quantity FROM Item",
+        Ok(select!(
+            id  | quantity
+            I64 | I64;
+            1     11;
+            2     12;
+            3     5;
+            4     5
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE RETURNING * returns whole updated rows",
+        "UPDATE Item SET name = UPPER(name) WHERE id > 2 RETURNING *",
+        Ok(select!(
+            id  | name                 | quantity
+            I64 | Str                  | I64;
+            3     "CHERRY".to_owned()    5;
+            4     "DURIAN".to_owned()    5
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DELETE RETURNING * returns whole deleted rows",
+        "DELETE FROM Item WHERE quantity = 5 RETURNING *",
+        Ok(select!(
+            id  | name                 | quantity
+            I64 | Str                  | I64;
+            3     "CHERRY".to_owned()    5;
+            4     "DURIAN".to_owned()    5
+        )),
+    )
+    .await;
+
+    g.count("SELECT * FROM Item", 2).await;
+});
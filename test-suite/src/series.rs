@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{FetchError, TranslateError},
+        error::{FetchError, PlanError, TranslateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -101,6 +101,59 @@ test_case!(series, {
                 1       "a".into()   true     3         "ab".into()
             )),
         ),
+        (
+            // SELECT without Table evaluates the projection once
+            "SELECT 2 * 3 + 1 AS i, 7 / 2.0 AS f, 'a' || 'b' || 'c' AS s, COUNT(*) AS c",
+            Ok(select!(
+                i   | f   | s            | c
+                I64 | F64 | Str          | I64;
+                7     3.5   "abc".into()   1
+            )),
+        ),
+        (
+            // WHERE still applies to the single row
+            "SELECT 1 AS n WHERE 1 > 2",
+            Ok(Payload::Select {
+                labels: vec!["n".to_owned()],
+                rows: vec![],
+            }),
+        ),
+        (
+            // there are no columns to refer to
+            "SELECT id",
+            Err(PlanError::ColumnWithoutFrom("id".to_owned()).into()),
+        ),
+        (
+            // nor qualified ones
+            "SELECT Series.N",
+            Err(PlanError::ColumnWithoutFrom("Series.N".to_owned()).into()),
+        ),
+        (
+            // an explicit `SERIES(1)` named `Series` is still a FROM
+            "SELECT N, Series.N AS qualified FROM SERIES(1) AS Series",
+            Ok(select!(
+                N   | qualified
+                I64 | I64;
+                1     1
+            )),
+        ),
+        (
+            "SELECT 1 AS n WHERE id > 1",
+            Err(PlanError::ColumnWithoutFrom("id".to_owned()).into()),
+        ),
+        (
+            "SELECT UPPER(name)",
+            Err(PlanError::ColumnWithoutFrom("name".to_owned()).into()),
+        ),
+        (
+            // ORDER BY may still refer to projection labels
+            "SELECT 1 AS n ORDER BY n",
+            Ok(select!(n I64; 1)),
+        ),
+        (
+            "SELECT 1 AS n ORDER BY id",
+            Err(PlanError::ColumnWithoutFrom("id".to_owned()).into()),
+        ),
         (
             // SELECT without Table in Scalar subquery
             "SELECT (SELECT 'Hello')",
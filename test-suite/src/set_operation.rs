@@ -0,0 +1,275 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::SetOperator,
+        error::SelectError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(union, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE A (id INTEGER, name TEXT);").await;
+    g.run("CREATE TABLE B (id INTEGER, name TEXT);").await;
+    g.run("INSERT INTO A VALUES (1, 'a'), (2, 'b'), (3, 'c');")
+        .await;
+    g.run("INSERT INTO B VALUES (3, 'c'), (4, 'd'), (1, 'x');")
+        .await;
+
+    g.named_test(
+        "UNION removes duplicate rows across both sides",
+        "SELECT id FROM A UNION SELECT id FROM B",
+        Ok(select!(id I64; 1; 2; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "UNION ALL keeps every row of both sides in order",
+        "SELECT id FROM A UNION ALL SELECT id FROM B",
+        Ok(select!(id I64; 1; 2; 3; 3; 4; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "UNION compares whole rows",
+        "SELECT id, name FROM A UNION SELECT id, name FROM B",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned();
+            4     "d".to_owned();
+            1     "x".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "labels come from the left side",
+        "SELECT id AS num FROM A WHERE id = 1 UNION SELECT id FROM B WHERE id = 4",
+        Ok(select!(num I64; 1; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "ORDER BY and LIMIT apply to the whole UNION",
+        "SELECT id FROM A UNION ALL SELECT id FROM B ORDER BY id DESC LIMIT 4",
+        Ok(select!(id I64; 4; 3; 3; 2)),
+    )
+    .await;
+
+    g.named_test(
+        "OFFSET applies to the whole UNION",
+        "SELECT id FROM A UNION SELECT id FROM B ORDER BY id LIMIT 2 OFFSET 1",
+        Ok(select!(id I64; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "chained set operations are evaluated from left to right",
+        "SELECT id FROM A UNION SELECT id FROM B UNION ALL SELECT 1",
+        Ok(select!(id I64; 1; 2; 3; 4; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "UNION works with VALUES",
+        "VALUES (1), (5) UNION SELECT id FROM A",
+        Ok(select!(column1 I64; 1; 5; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "UNION in a derived table",
+        "SELECT * FROM (SELECT id FROM A UNION SELECT id FROM B) AS U(x) WHERE x > 2",
+        Ok(select!(x I64; 3; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "UNION in a subquery",
+        "SELECT name FROM A WHERE id IN (SELECT id FROM B WHERE id > 2 UNION SELECT 2)",
+        Ok(select!(name Str; "b".to_owned(); "c".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "sides must have the same number of columns",
+        "SELECT id, name FROM A UNION SELECT id FROM B",
+        Err(SelectError::SetOperationColumnCountMismatch {
+            operator: SetOperator::Union,
+            left: 2,
+            right: 1,
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "INSERT INTO ... SELECT accepts a UNION",
+        "INSERT INTO A SELECT id, name FROM B UNION SELECT 9, 'z'",
+        Ok(Payload::Insert(4)),
+    )
+    .await;
+});
+
+test_case!(intersect, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE A (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("CREATE TABLE B (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("CREATE TABLE Empty (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("INSERT INTO A VALUES (1, 'a'), (2, 'b'), (2, 'b'), (3, NULL), (NULL, 'n');")
+        .await;
+    g.run("INSERT INTO B VALUES (2, 'b'), (2, 'b'), (2, 'b'), (3, NULL), (NULL, 'n'), (4, 'd');")
+        .await;
+
+    g.named_test(
+        "INTERSECT keeps distinct rows found on both sides",
+        "SELECT id FROM A INTERSECT SELECT id FROM B",
+        Ok(select_with_null!(id; I64(2); I64(3); Null)),
+    )
+    .await;
+
+    g.named_test(
+        "INTERSECT compares whole rows and NULLs are equal",
+        "SELECT id, name FROM A INTERSECT SELECT id, name FROM B",
+        Ok(select_with_null!(
+            id     | name;
+            I64(2)   Str("b".to_owned());
+            I64(3)   Null;
+            Null     Str("n".to_owned())
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "INTERSECT ALL keeps the smaller number of duplicates",
+        "SELECT id FROM B INTERSECT ALL SELECT id FROM A",
+        Ok(select_with_null!(id; I64(2); I64(2); I64(3); Null)),
+    )
+    .await;
+
+    g.named_test(
+        "INTERSECT with an empty side is empty",
+        "SELECT id FROM A INTERSECT SELECT id FROM Empty",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+
+    g.named_test(
+        "INTERSECT binds tighter than UNION",
+        "SELECT 1 UNION SELECT id FROM A INTERSECT SELECT 4",
+        Ok(select!("1" I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "sides must have the same number of columns",
+        "SELECT id FROM A INTERSECT SELECT id, name FROM B",
+        Err(SelectError::SetOperationColumnCountMismatch {
+            operator: SetOperator::Intersect,
+            left: 1,
+            right: 2,
+        }
+        .into()),
+    )
+    .await;
+});
+
+test_case!(except, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE A (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("CREATE TABLE B (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("CREATE TABLE Empty (id INTEGER NULL, name TEXT NULL);")
+        .await;
+    g.run("INSERT INTO A VALUES (1, 'a'), (1, 'a'), (2, 'b'), (2, 'b'), (3, NULL), (NULL, 'n');")
+        .await;
+    g.run("INSERT INTO B VALUES (2, 'b'), (3, 'c'), (NULL, 'n');")
+        .await;
+
+    g.named_test(
+        "EXCEPT keeps distinct rows of the left side missing on the right",
+        "SELECT id FROM A EXCEPT SELECT id FROM B",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "EXCEPT compares whole rows and NULLs are equal",
+        "SELECT id, name FROM A EXCEPT SELECT id, name FROM B",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   Str("a".to_owned());
+            I64(3)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "EXCEPT ALL removes one left row for each matching right row",
+        "SELECT id FROM A EXCEPT ALL SELECT id FROM B",
+        Ok(select!(id I64; 1; 1; 2)),
+    )
+    .await;
+
+    g.named_test(
+        "EXCEPT with an empty right side removes only duplicates",
+        "SELECT id, name FROM A EXCEPT SELECT id, name FROM Empty",
+        Ok(select_with_null!(
+            id     | name;
+            I64(1)   Str("a".to_owned());
+            I64(2)   Str("b".to_owned());
+            I64(3)   Null;
+            Null     Str("n".to_owned())
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "EXCEPT with an empty left side is empty",
+        "SELECT id FROM Empty EXCEPT SELECT id FROM B",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+
+    g.named_test(
+        "ORDER BY applies to the whole EXCEPT",
+        "SELECT id FROM A EXCEPT SELECT 2 ORDER BY id DESC",
+        Ok(select_with_null!(id; Null; I64(3); I64(1))),
+    )
+    .await;
+
+    g.named_test(
+        "chained EXCEPT is evaluated from left to right",
+        "SELECT id FROM A EXCEPT ALL SELECT id FROM B EXCEPT SELECT 2",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "sides must have the same number of columns",
+        "SELECT id, name FROM A EXCEPT SELECT id FROM B",
+        Err(SelectError::SetOperationColumnCountMismatch {
+            operator: SetOperator::Except,
+            left: 2,
+            right: 1,
+        }
+        .into()),
+    )
+    .await;
+});
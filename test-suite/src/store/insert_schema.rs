@@ -23,6 +23,7 @@ test_case!(insert_schema, {
         indexes: Vec::new(),
         engine: None,
         foreign_keys: Vec::new(),
+        composite_primary_key: None,
         comment: Some("this is comment for table".to_owned()),
     };
 
@@ -57,6 +58,7 @@ test_case!(insert_schema, {
         indexes: Vec::new(),
         engine: None,
         foreign_keys: Vec::new(),
+        composite_primary_key: None,
         comment: Some("this is comment for schemaless table".to_owned()),
     };
     storage.insert_schema(&schema).await.unwrap();
@@ -175,6 +175,30 @@ test_case!(basic, {
     )
     .await;
 
+    // TRUNCATE
+    g.test("BEGIN;", Ok(Payload::StartTransaction)).await;
+    g.test("TRUNCATE TABLE TxTest;", Ok(Payload::Truncate))
+        .await;
+    g.test(
+        "SELECT id, name FROM TxTest",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+    g.test("ROLLBACK;", Ok(Payload::Rollback)).await;
+    g.test(
+        "SELECT id, name FROM TxTest",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Sunday".to_owned();
+            2     "Phone".to_owned()
+        )),
+    )
+    .await;
+
     for query in [
         "BEGIN;",
         "SELECT * FROM TxTest;",
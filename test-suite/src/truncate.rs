@@ -0,0 +1,97 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{DeleteError, ExecuteError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(truncate, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Foo (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run("INSERT INTO Foo VALUES (1, 'a'), (2, 'b'), (3, 'c');")
+        .await;
+
+    g.named_test(
+        "TRUNCATE TABLE removes every row",
+        "TRUNCATE TABLE Foo;",
+        Ok(Payload::Truncate),
+    )
+    .await;
+    g.test("SELECT * FROM Foo", Ok(select!(id | name))).await;
+
+    g.named_test(
+        "the table is kept and accepts new rows",
+        "INSERT INTO Foo VALUES (1, 'd');",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Foo",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "d".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "truncating an empty table succeeds",
+        "TRUNCATE TABLE Foo; TRUNCATE TABLE Foo;",
+        Ok(Payload::Truncate),
+    )
+    .await;
+
+    g.named_test(
+        "truncating a missing table fails",
+        "TRUNCATE TABLE Missing;",
+        Err(ExecuteError::TableNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE Parent (id INTEGER PRIMARY KEY);").await;
+    g.run(
+        "CREATE TABLE Child (
+            id INTEGER,
+            parent_id INTEGER,
+            FOREIGN KEY (parent_id) REFERENCES Parent (id)
+        );",
+    )
+    .await;
+    g.run("INSERT INTO Parent VALUES (1), (2);").await;
+    g.run("INSERT INTO Child VALUES (1, 1), (2, 2);").await;
+
+    g.named_test(
+        "a table referenced by another table cannot be truncated alone",
+        "TRUNCATE TABLE Parent;",
+        Err(DeleteError::ReferencingColumnExists("Child.parent_id".to_owned()).into()),
+    )
+    .await;
+    g.test("SELECT * FROM Parent", Ok(select!(id; I64; 1; 2)))
+        .await;
+
+    g.named_test(
+        "nothing is removed when one of the listed tables is missing",
+        "TRUNCATE TABLE Child, Missing;",
+        Err(ExecuteError::TableNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "SELECT COUNT(*) FROM Child",
+        Ok(select!("COUNT(*)"; I64; 2)),
+    )
+    .await;
+
+    g.named_test(
+        "truncating the referencing table together with it succeeds",
+        "TRUNCATE TABLE Parent, Child;",
+        Ok(Payload::Truncate),
+    )
+    .await;
+    g.test("SELECT * FROM Parent", Ok(select!(id))).await;
+    g.test("SELECT * FROM Child", Ok(select!(id | parent_id)))
+        .await;
+});
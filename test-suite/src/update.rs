@@ -2,7 +2,7 @@ use {
     crate::*,
     Value::*,
     gluesql_core::{
-        error::{ExecuteError, TranslateError, UpdateError},
+        error::{EvaluateError, ExecuteError, TranslateError, UpdateError, ValidateError},
         prelude::*,
     },
 };
@@ -139,3 +139,114 @@ test_case!(update, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(update_expression, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Account (
+            id INTEGER PRIMARY KEY,
+            owner TEXT NOT NULL,
+            balance INTEGER NOT NULL,
+            bonus INTEGER NULL
+        )",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Account VALUES
+            (1, 'Alice', 100, 5),
+            (2, 'Bob',   50,  NULL),
+            (3, 'Carol', 0,   20);
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "assignment evaluated against the current row",
+        "UPDATE Account SET balance = balance - 10 WHERE id = 1",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.named_test(
+        "assignment referencing a column which is not assigned",
+        "UPDATE Account SET balance = balance + bonus * 2 WHERE bonus IS NOT NULL",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, balance FROM Account",
+        Ok(select!(
+            id  | balance
+            I64 | I64;
+            1     100;
+            2     50;
+            3     40
+        )),
+    )
+    .await;
+    g.named_test(
+        "every assignment sees the row as it was before the update",
+        "UPDATE Account SET owner = owner || '!', balance = CAST(LENGTH(owner) AS INTEGER) WHERE id = 2",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, owner, balance FROM Account WHERE id = 2",
+        Ok(select!(
+            id  | owner              | balance
+            I64 | Str                | I64;
+            2     "Bob!".to_owned()    3
+        )),
+    )
+    .await;
+    g.named_test(
+        "swapping two columns uses their previous values",
+        "UPDATE Account SET balance = bonus, bonus = balance WHERE id = 3",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT balance, bonus FROM Account WHERE id = 3",
+        Ok(select!(
+            balance | bonus
+            I64     | I64;
+            20        40
+        )),
+    )
+    .await;
+    g.named_test(
+        "assignment referencing an unknown column",
+        "UPDATE Account SET balance = missing + 1",
+        Err(EvaluateError::IdentifierNotFound("missing".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "assignment to an unknown column",
+        "UPDATE Account SET balance = 0, missing = balance",
+        Err(UpdateError::ColumnNotFound("missing".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "assignment evaluating to NULL on a NOT NULL column",
+        "UPDATE Account SET balance = balance + bonus",
+        Err(ValidateError::NullValueOnNotNullField {
+            table_name: "Account".to_owned(),
+            column_name: "balance".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "failed update leaves every row untouched",
+        "SELECT id, owner, balance, bonus FROM Account",
+        Ok(select_with_null!(
+            id     | owner             | balance  | bonus;
+            I64(1)   Str("Alice".to_owned())  I64(100)   I64(5);
+            I64(2)   Str("Bob!".to_owned())   I64(3)     Null;
+            I64(3)   Str("Carol".to_owned())  I64(20)    I64(40)
+        )),
+    )
+    .await;
+});
@@ -1,7 +1,6 @@
 use {
     crate::*,
-    gluesql_core::{ast::DataType, data::Literal, error::ValueError, prelude::Value},
-    std::borrow::Cow,
+    gluesql_core::{ast::DataType, error::ValidateError, prelude::Value},
 };
 
 test_case!(types, {
@@ -16,51 +15,75 @@ test_case!(types, {
     let test_cases = [
         (
             "INSERT INTO TableB SELECT uid FROM TableC;",
-            Err(ValueError::IncompatibleDataType {
-                data_type: DataType::Boolean,
-                value: Value::I64(1),
+            Err(ValidateError::TypeMismatch {
+                table_name: "TableB".to_owned(),
+                column_name: "id".to_owned(),
+                expected: DataType::Boolean,
+                found: Value::I64(1),
             }
             .into()),
         ),
         (
             "INSERT INTO TableC (uid) VALUES ('A')",
-            Err(ValueError::IncompatibleLiteralForDataType {
-                data_type: DataType::Int,
-                literal: format!("{:?}", Literal::Text(Cow::Owned("A".to_owned()))),
+            Err(ValidateError::TypeMismatch {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+                expected: DataType::Int,
+                found: Value::Str("A".to_owned()),
             }
             .into()),
         ),
         (
             "INSERT INTO TableC VALUES (NULL, 30);",
-            Err(ValueError::NullValueOnNotNullField.into()),
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+            }
+            .into()),
         ),
         (
             "INSERT INTO TableC SELECT null_val FROM TableC;",
-            Err(ValueError::NullValueOnNotNullField.into()),
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+            }
+            .into()),
         ),
         (
             "UPDATE TableC SET uid = TRUE;",
-            Err(ValueError::IncompatibleLiteralForDataType {
-                data_type: DataType::Int,
-                literal: format!("{:?}", Literal::Boolean(true)),
+            Err(ValidateError::TypeMismatch {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+                expected: DataType::Int,
+                found: Value::Bool(true),
             }
             .into()),
         ),
         (
             "UPDATE TableC SET uid = (SELECT id FROM TableB LIMIT 1) WHERE uid = 1",
-            Err(ValueError::IncompatibleDataType {
-                data_type: DataType::Int,
-                value: Value::Bool(false),
+            Err(ValidateError::TypeMismatch {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+                expected: DataType::Int,
+                found: Value::Bool(false),
             }
             .into()),
         ),
         (
             "UPDATE TableC SET uid = NULL;",
-            Err(ValueError::NullValueOnNotNullField.into()),
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+            }
+            .into()),
         ),
         (
             "UPDATE TableC SET uid = (SELECT null_val FROM TableC);",
-            Err(ValueError::NullValueOnNotNullField.into()),
+            Err(ValidateError::NullValueOnNotNullField {
+                table_name: "TableC".to_owned(),
+                column_name: "uid".to_owned(),
+            }
+            .into()),
         ),
     ];
 
@@ -1,6 +1,12 @@
 use {
     crate::*,
-    gluesql_core::{error::ValidateError, prelude::Value},
+    gluesql_core::{
+        error::ValidateError,
+        prelude::{
+            Payload,
+            Value::{self, *},
+        },
+    },
 };
 
 test_case!(unique, {
@@ -85,4 +91,62 @@ test_case!(unique, {
     for (sql, error) in error_cases {
         g.test(sql, Err(error)).await;
     }
+
+    g.named_test(
+        "failed statements insert nothing",
+        "SELECT id, num FROM TestA",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            1     1;
+            2     1;
+            3     1
+        )),
+    )
+    .await;
+    g.named_test(
+        "shifting every unique value at once",
+        "UPDATE TestA SET id = id + 1",
+        Ok(Payload::Update(3)),
+    )
+    .await;
+    g.test("SELECT id FROM TestA", Ok(select!(id I64; 2; 3; 4)))
+        .await;
+    g.named_test(
+        "insert select conflicting with existing rows",
+        "INSERT INTO TestA SELECT id, num FROM TestB WHERE id = 2",
+        Err(ValidateError::DuplicateEntryOnUniqueField(Value::I64(2), "id".to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE TestD (id INTEGER, email TEXT UNIQUE)")
+        .await;
+    g.run("INSERT INTO TestD VALUES (1, 'a@glue.sql'), (2, 'b@glue.sql')")
+        .await;
+    g.named_test(
+        "duplicate text value",
+        "UPDATE TestD SET email = 'a@glue.sql' WHERE id = 2",
+        Err(ValidateError::DuplicateEntryOnUniqueField(
+            Value::Str("a@glue.sql".to_owned()),
+            "email".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "swapping unique values between rows",
+        "UPDATE TestD SET email = CASE id WHEN 1 THEN 'b@glue.sql' ELSE 'a@glue.sql' END",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, email FROM TestD",
+        Ok(select!(
+            id  | email
+            I64 | Str;
+            1     "b@glue.sql".to_owned();
+            2     "a@glue.sql".to_owned()
+        )),
+    )
+    .await;
 });
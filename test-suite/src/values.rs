@@ -2,9 +2,12 @@ use {
     crate::*,
     bigdecimal::BigDecimal,
     gluesql_core::{
-        ast::DataType::{Boolean, Int, Text},
+        ast::{
+            DataType::{Boolean, Int, Text},
+            Expr,
+        },
         data::Literal,
-        error::{InsertError, SelectError, ValueError},
+        error::{EvaluateError, InsertError, SelectError, ValueError},
         prelude::{DataType, Payload, Value::*},
     },
     std::borrow::Cow,
@@ -140,7 +143,43 @@ test_case!(values, {
                 2         "b".to_owned()
             )),
         ),
+        (
+            "VALUES (1 + 2, UPPER('x')), (10 / 2, 'a' || 'b')",
+            Ok(select!(
+                column1 | column2;
+                I64     | Str;
+                3         "X".to_owned();
+                5         "ab".to_owned()
+            )),
+        ),
+        (
+            "SELECT x, y FROM (VALUES (1, 'a'), (2, 'b')) AS Derived(x, y) WHERE x > 1",
+            Ok(select!(
+                x   | y;
+                I64 | Str;
+                2     "b".to_owned()
+            )),
+        ),
+        (
+            "VALUES (1), (id)",
+            Err(
+                EvaluateError::ContextRequiredForIdentEvaluation(Expr::Identifier("id".to_owned()))
+                    .into(),
+            ),
+        ),
         ("INSERT INTO Items (id) VALUES (1);", Ok(Payload::Insert(1))),
+        (
+            "INSERT INTO Items (id, name) VALUES (1 + 1, UPPER('glue') || '!');",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT id, name FROM Items WHERE id = 2",
+            Ok(select!(
+                id  | name;
+                I64 | Str;
+                2     "GLUE!".to_owned()
+            )),
+        ),
         (
             "INSERT INTO Items (id2) VALUES (1);",
             Err(InsertError::WrongColumnName("id2".to_owned()).into()),
@@ -151,11 +190,19 @@ test_case!(values, {
         ),
         (
             "INSERT INTO Items (id) VALUES (3, 'sql')",
-            Err(InsertError::ColumnAndValuesNotMatched.into()),
+            Err(InsertError::ColumnAndValuesNotMatched {
+                expected: 1,
+                found: 2,
+            }
+            .into()),
         ),
         (
             "INSERT INTO Items VALUES (100, 'a', 'b', 1);",
-            Err(InsertError::TooManyValues.into()),
+            Err(InsertError::TooManyValues {
+                expected: 3,
+                found: 4,
+            }
+            .into()),
         ),
         (
             "INSERT INTO Nothing VALUES (1);",
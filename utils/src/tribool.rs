@@ -31,6 +31,30 @@ impl std::ops::Not for Tribool {
     }
 }
 
+impl std::ops::BitAnd for Tribool {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::False, _) | (_, Self::False) => Self::False,
+            (Self::Null, _) | (_, Self::Null) => Self::Null,
+            (Self::True, Self::True) => Self::True,
+        }
+    }
+}
+
+impl std::ops::BitOr for Tribool {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::True, _) | (_, Self::True) => Self::True,
+            (Self::Null, _) | (_, Self::Null) => Self::Null,
+            (Self::False, Self::False) => Self::False,
+        }
+    }
+}
+
 impl From<bool> for Tribool {
     fn from(v: bool) -> Self {
         if v { Self::True } else { Self::False }
@@ -72,4 +96,34 @@ mod tests {
         assert_eq!(!Tribool::False, Tribool::True);
         assert_eq!(!Tribool::Null, Tribool::Null);
     }
+
+    #[test]
+    fn test_and_operator() {
+        let (t, f, n) = (Tribool::True, Tribool::False, Tribool::Null);
+
+        assert_eq!(t & t, t);
+        assert_eq!(t & f, f);
+        assert_eq!(t & n, n);
+        assert_eq!(f & t, f);
+        assert_eq!(f & f, f);
+        assert_eq!(f & n, f);
+        assert_eq!(n & t, n);
+        assert_eq!(n & f, f);
+        assert_eq!(n & n, n);
+    }
+
+    #[test]
+    fn test_or_operator() {
+        let (t, f, n) = (Tribool::True, Tribool::False, Tribool::Null);
+
+        assert_eq!(t | t, t);
+        assert_eq!(t | f, t);
+        assert_eq!(t | n, t);
+        assert_eq!(f | t, t);
+        assert_eq!(f | f, f);
+        assert_eq!(f | n, n);
+        assert_eq!(n | t, t);
+        assert_eq!(n | f, n);
+        assert_eq!(n | n, n);
+    }
 }